@@ -1,5 +1,10 @@
 use std::{collections::HashMap, fmt, ops};
 
+use crate::effect::Breakdown;
+
+/// The highest an ability score can normally reach.
+pub const MAXIMUM_SCORE: usize = 20;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Identifier {
     Strength,
@@ -99,44 +104,67 @@ pub struct AbilitiesTemplate {
 }
 
 #[derive(Clone, Debug, Default)]
-pub struct Abilities(HashMap<Identifier, Ability>);
+pub struct Abilities {
+    scores: HashMap<Identifier, Ability>,
+    breakdowns: HashMap<Identifier, Breakdown>,
+}
 
 impl Abilities {
     pub fn set_score(&mut self, ability: Identifier, score: usize) {
-        self.0.insert(ability, Ability { score });
+        self.scores.insert(ability, Ability { score });
+        self.breakdowns.remove(&ability);
+    }
+
+    /// Sets the score to the breakdown's total, floored at 0, and keeps the breakdown.
+    pub fn set_breakdown(&mut self, ability: Identifier, breakdown: Breakdown) {
+        let score = usize::try_from(breakdown.get_total()).unwrap_or(0);
+
+        self.scores.insert(ability, Ability { score });
+        self.breakdowns.insert(ability, breakdown);
     }
 
     #[must_use]
     pub fn get_score(&self, ability: Identifier) -> Option<usize> {
-        self.0.get(&ability).map(|ability| ability.get_score())
+        self.scores.get(&ability).map(|ability| ability.get_score())
     }
 
     #[must_use]
     pub fn get_modifier(&self, ability: Identifier) -> Option<isize> {
-        self.0.get(&ability).map(|ability| ability.get_modifier())
+        self.scores
+            .get(&ability)
+            .map(|ability| ability.get_modifier())
+    }
+
+    /// Where the score came from, if it was set from a breakdown.
+    #[must_use]
+    pub fn get_breakdown(&self, ability: Identifier) -> Option<&Breakdown> {
+        self.breakdowns.get(&ability)
     }
 
     #[must_use]
     pub fn count_abilities(&self) -> usize {
-        self.0.len()
+        self.scores.len()
     }
 
     #[must_use]
     pub fn get_abilities(&self) -> Vec<(&Identifier, &Ability)> {
-        self.0.keys().zip(self.0.values()).collect()
+        self.scores.iter().collect()
     }
 }
 
 impl From<AbilitiesTemplate> for Abilities {
     fn from(value: AbilitiesTemplate) -> Self {
-        Self(HashMap::from([
-            (Identifier::Strength, value.strength.into()),
-            (Identifier::Dexterity, value.dexterity.into()),
-            (Identifier::Constitution, value.constitution.into()),
-            (Identifier::Intelligence, value.intelligence.into()),
-            (Identifier::Wisdom, value.wisdom.into()),
-            (Identifier::Charisma, value.charisma.into()),
-        ]))
+        Self {
+            scores: HashMap::from([
+                (Identifier::Strength, value.strength.into()),
+                (Identifier::Dexterity, value.dexterity.into()),
+                (Identifier::Constitution, value.constitution.into()),
+                (Identifier::Intelligence, value.intelligence.into()),
+                (Identifier::Wisdom, value.wisdom.into()),
+                (Identifier::Charisma, value.charisma.into()),
+            ]),
+            breakdowns: HashMap::new(),
+        }
     }
 }
 
@@ -144,7 +172,7 @@ impl ops::Add<Abilities> for Abilities {
     type Output = Abilities;
 
     fn add(self, rhs: Abilities) -> Self::Output {
-        Abilities(self.0.iter().chain(rhs.0.iter()).fold(
+        let scores = self.scores.iter().chain(rhs.scores.iter()).fold(
             HashMap::new(),
             |mut acc, (&id, &new_ability)| {
                 acc.entry(id)
@@ -153,7 +181,13 @@ impl ops::Add<Abilities> for Abilities {
 
                 acc
             },
-        ))
+        );
+
+        // A breakdown from only one side wouldn't add up to the summed score.
+        Abilities {
+            scores,
+            breakdowns: HashMap::new(),
+        }
     }
 }
 
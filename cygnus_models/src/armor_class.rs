@@ -0,0 +1,344 @@
+#![warn(clippy::pedantic)]
+
+use std::fmt;
+
+use crate::{
+    ability::{self, Abilities},
+    item::Item,
+};
+
+pub use crate::effect::{Breakdown, Component};
+
+/// An alternative base AC calculation that replaces wearing body armor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Formula {
+    /// 10 + Dex + another ability modifier, e.g. Barbarian (Con) or Monk (Wis).
+    UnarmoredDefense {
+        ability: ability::Identifier,
+        allows_shield: bool,
+    },
+    /// 13 + Dex.
+    MageArmor,
+    /// A fixed base, e.g. Lizardfolk (13 + Dex) or Tortle (17).
+    NaturalArmor { base: usize, adds_dexterity: bool },
+}
+
+impl Formula {
+    #[must_use]
+    pub fn allows_shield(&self) -> bool {
+        match self {
+            Formula::UnarmoredDefense { allows_shield, .. } => *allows_shield,
+            Formula::MageArmor | Formula::NaturalArmor { .. } => true,
+        }
+    }
+
+    fn get_components(&self, dexterity_modifier: isize, abilities: &Abilities) -> Vec<Component> {
+        match self {
+            Formula::UnarmoredDefense { ability, .. } => vec![
+                Component::new(self.to_string(), 10),
+                Component::new("Dexterity", dexterity_modifier),
+                Component::new(
+                    ability.to_string(),
+                    abilities.get_modifier(*ability).unwrap_or(0),
+                ),
+            ],
+            Formula::MageArmor => vec![
+                Component::new(self.to_string(), 13),
+                Component::new("Dexterity", dexterity_modifier),
+            ],
+            Formula::NaturalArmor {
+                base,
+                adds_dexterity,
+            } => {
+                let mut components = vec![Component::new(self.to_string(), as_isize(*base))];
+
+                if *adds_dexterity {
+                    components.push(Component::new("Dexterity", dexterity_modifier));
+                }
+
+                components
+            }
+        }
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Formula::UnarmoredDefense { .. } => "Unarmored Defense",
+            Formula::MageArmor => "Mage Armor",
+            Formula::NaturalArmor { .. } => "Natural Armor",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// Picks the highest AC among worn armor (or 10 + Dex when unarmored) and any alternative
+/// `formulas` usable with what is `equipped`, then adds a shield and every flat bonus.
+#[must_use]
+pub fn calculate(equipped: &[&Item], abilities: &Abilities, formulas: &[Formula]) -> Breakdown {
+    let dexterity_modifier = abilities
+        .get_modifier(ability::Identifier::Dexterity)
+        .unwrap_or(0);
+
+    let body_armor = equipped.iter().find(|item| item.is_body_armor());
+    let shield = equipped.iter().find(|item| item.is_shield());
+
+    let candidates: Vec<(Vec<Component>, bool)> = match body_armor {
+        Some(armor) => vec![(get_armor_components(armor, dexterity_modifier), true)],
+        None => [(
+            vec![
+                Component::new("Unarmored", 10),
+                Component::new("Dexterity", dexterity_modifier),
+            ],
+            true,
+        )]
+        .into_iter()
+        .chain(formulas.iter().map(|formula| {
+            (
+                formula.get_components(dexterity_modifier, abilities),
+                formula.allows_shield(),
+            )
+        }))
+        .collect(),
+    };
+
+    let mut breakdown = candidates
+        .into_iter()
+        .filter(|(_, allows_shield)| *allows_shield || shield.is_none())
+        .map(|(mut components, _)| {
+            if let Some(shield) = shield {
+                components.extend(get_armor_components(shield, dexterity_modifier));
+            }
+
+            Breakdown::from(components)
+        })
+        .rev()
+        .max_by_key(Breakdown::get_total)
+        .unwrap_or_default();
+
+    breakdown.extend(
+        equipped
+            .iter()
+            .filter(|item| {
+                item.is_active()
+                    && item.get_armor_class().is_none()
+                    && item.get_armor_class_bonus() != 0
+            })
+            .map(|item| Component::new(item.get_name(), item.get_armor_class_bonus())),
+    );
+
+    breakdown
+}
+
+fn get_armor_components(armor: &Item, dexterity_modifier: isize) -> Vec<Component> {
+    let Some(armor_class) = armor.get_armor_class() else {
+        return vec![];
+    };
+
+    let mut components = vec![Component::new(
+        armor.get_name(),
+        as_isize(armor_class.get_base()),
+    )];
+
+    if armor.is_active() && armor.get_armor_class_bonus() != 0 {
+        components.push(Component::new(
+            format!("{} bonus", armor.get_name()),
+            armor.get_armor_class_bonus(),
+        ));
+    }
+
+    if let Some(max_dexterity_bonus) = armor_class.get_max_dexterity_bonus() {
+        components.push(Component::new(
+            "Dexterity",
+            dexterity_modifier.min(max_dexterity_bonus),
+        ));
+    }
+
+    components
+}
+
+fn as_isize(value: usize) -> isize {
+    isize::try_from(value).unwrap_or(isize::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::{
+        ability::AbilitiesTemplate,
+        item::{self, ArmorClass},
+    };
+
+    use super::*;
+
+    fn abilities(dexterity: usize, constitution: usize, wisdom: usize) -> Abilities {
+        Abilities::from(AbilitiesTemplate {
+            strength: 10,
+            dexterity,
+            constitution,
+            intelligence: 10,
+            wisdom,
+            charisma: 10,
+        })
+    }
+
+    fn shield() -> Result<Item, Box<dyn Error>> {
+        Ok(item::Builder::new()
+            .name("Shield")?
+            .armor_class(ArmorClass::Shield(2))?
+            .build()?)
+    }
+
+    #[test]
+    fn _unarmored_should_be_10_plus_dexterity() {
+        let breakdown = calculate(&[], &abilities(14, 10, 10), &[]);
+
+        assert_eq!(breakdown.get_total(), 12);
+        assert_eq!(breakdown.to_string(), "10 (Unarmored) + 2 (Dexterity)");
+    }
+
+    #[test]
+    fn _should_cap_dexterity_for_medium_armor_and_ignore_it_for_heavy() -> Result<(), Box<dyn Error>>
+    {
+        let breastplate = item::Builder::new()
+            .name("Breastplate")?
+            .armor_class(ArmorClass::Medium(14))?
+            .build()?;
+        assert_eq!(
+            calculate(&[&breastplate], &abilities(18, 10, 10), &[]).get_total(),
+            16
+        );
+
+        let plate = item::Builder::new()
+            .name("Plate")?
+            .armor_class(ArmorClass::Heavy(18))?
+            .build()?;
+        assert_eq!(
+            calculate(&[&plate], &abilities(8, 10, 10), &[]).get_total(),
+            18
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_add_shield_and_flat_bonuses() -> Result<(), Box<dyn Error>> {
+        let plate = item::Builder::new()
+            .name("Plate")?
+            .armor_class(ArmorClass::Heavy(18))?
+            .armor_class_bonus(1)?
+            .build()?;
+        let shield = shield()?;
+        let cloak = item::Builder::new()
+            .name("Cloak of Protection")?
+            .armor_class_bonus(1)?
+            .build()?;
+
+        let breakdown = calculate(&[&plate, &shield, &cloak], &abilities(10, 10, 10), &[]);
+
+        assert_eq!(breakdown.get_total(), 22);
+        assert_eq!(
+            breakdown.to_string(),
+            "18 (Plate) + 1 (Plate bonus) + 2 (Shield) + 1 (Cloak of Protection)"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_only_add_attunement_bonuses_while_attuned() -> Result<(), Box<dyn Error>> {
+        let mut cloak = item::Builder::new()
+            .name("Cloak of Protection")?
+            .armor_class_bonus(1)?
+            .requires_attunement()?
+            .build()?;
+
+        assert_eq!(
+            calculate(&[&cloak], &abilities(10, 10, 10), &[]).get_total(),
+            10
+        );
+
+        cloak.attune()?;
+
+        assert_eq!(
+            calculate(&[&cloak], &abilities(10, 10, 10), &[]).get_total(),
+            11
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _negative_dexterity_should_lower_armor_class() {
+        let breakdown = calculate(&[], &abilities(6, 10, 10), &[]);
+
+        assert_eq!(breakdown.get_total(), 8);
+        assert_eq!(breakdown.to_string(), "10 (Unarmored) - 2 (Dexterity)");
+    }
+
+    #[test]
+    fn _should_use_the_highest_alternative_formula() {
+        let formulas = [
+            Formula::MageArmor,
+            Formula::UnarmoredDefense {
+                ability: ability::Identifier::Constitution,
+                allows_shield: true,
+            },
+        ];
+
+        assert_eq!(
+            calculate(&[], &abilities(14, 18, 10), &formulas).get_total(),
+            16
+        );
+        assert_eq!(
+            calculate(&[], &abilities(14, 12, 10), &formulas).get_total(),
+            15
+        );
+    }
+
+    #[test]
+    fn _monk_unarmored_defense_should_not_apply_with_a_shield() -> Result<(), Box<dyn Error>> {
+        let formulas = [Formula::UnarmoredDefense {
+            ability: ability::Identifier::Wisdom,
+            allows_shield: false,
+        }];
+        let shield = shield()?;
+
+        assert_eq!(
+            calculate(&[], &abilities(16, 10, 16), &formulas).get_total(),
+            16
+        );
+        assert_eq!(
+            calculate(&[&shield], &abilities(16, 10, 16), &formulas).get_total(),
+            15
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _alternative_formulas_should_not_apply_while_wearing_armor() -> Result<(), Box<dyn Error>> {
+        let leather = item::Builder::new()
+            .name("Leather")?
+            .armor_class(ArmorClass::Light(11))?
+            .build()?;
+        let formulas = [Formula::NaturalArmor {
+            base: 17,
+            adds_dexterity: false,
+        }];
+
+        assert_eq!(
+            calculate(&[&leather], &abilities(10, 10, 10), &formulas).get_total(),
+            11
+        );
+        assert_eq!(
+            calculate(&[], &abilities(10, 10, 10), &formulas).get_total(),
+            17
+        );
+
+        Ok(())
+    }
+}
@@ -1,8 +1,15 @@
 #![warn(clippy::pedantic)]
 
-use std::{error::Error, fmt};
+use std::{collections::BTreeMap, error::Error, fmt};
 
-use crate::skills;
+use crate::{
+    ability,
+    effect::{Effect, Modifier, Target},
+    language::Language,
+    modifiers::Proficiency,
+    skills,
+    tool::Tool,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Background {
@@ -10,6 +17,7 @@ pub struct Background {
     description: String,
     feature: Feature,
     proficiencies: Proficiencies,
+    ability_scores: Vec<ability::Identifier>,
 }
 
 impl Background {
@@ -32,14 +40,131 @@ impl Background {
     pub fn get_proficiencies(&self) -> &Proficiencies {
         &self.proficiencies
     }
+
+    /// Proficiency in each skill the background grants, including the player's picks, and the
+    /// chosen ability score increases.
+    #[must_use]
+    pub fn get_effects(&self, selections: &Selections) -> Vec<Effect> {
+        let increases = selections
+            .ability_score_increase
+            .and_then(|increase| self.get_ability_score_effects(increase).ok())
+            .unwrap_or_default();
+
+        self.proficiencies
+            .get_skills(selections)
+            .into_iter()
+            .map(|skill| {
+                Effect::new(
+                    self.name.clone(),
+                    Target::Skill(skill),
+                    Modifier::Proficiency(Proficiency::Proficiency),
+                )
+            })
+            .chain(increases)
+            .collect()
+    }
+
+    /// The abilities a 2024-style background lets the player increase; empty for older
+    /// backgrounds.
+    #[must_use]
+    pub fn get_ability_scores(&self) -> &[ability::Identifier] {
+        &self.ability_scores
+    }
+
+    /// The score increases the player chose, as effects sourced from the background.
+    ///
+    /// # Errors
+    ///
+    /// - `NoAbilityScores`: the background doesn't offer ability score increases
+    /// - `AbilityNotOffered`: a chosen ability isn't one of the background's
+    /// - `SameAbility`: the +2 and the +1 went to the same ability
+    ///
+    pub fn get_ability_score_effects(
+        &self,
+        increase: AbilityScoreIncrease,
+    ) -> Result<Vec<Effect>, AbilityScoreIncreaseError> {
+        if self.ability_scores.is_empty() {
+            return Err(AbilityScoreIncreaseError::NoAbilityScores);
+        }
+
+        let increases = match increase {
+            AbilityScoreIncrease::TwoAndOne(two, one) => {
+                if two == one {
+                    return Err(AbilityScoreIncreaseError::SameAbility(two));
+                }
+
+                if let Some(&ability) = [two, one]
+                    .iter()
+                    .find(|ability| !self.ability_scores.contains(ability))
+                {
+                    return Err(AbilityScoreIncreaseError::AbilityNotOffered(ability));
+                }
+
+                vec![(two, 2), (one, 1)]
+            }
+            AbilityScoreIncrease::OneEach => self
+                .ability_scores
+                .iter()
+                .map(|&ability| (ability, 1))
+                .collect(),
+        };
+
+        Ok(increases
+            .into_iter()
+            .map(|(ability, amount)| {
+                Effect::new(
+                    self.name.clone(),
+                    Target::AbilityScore(ability),
+                    Modifier::Add(amount),
+                )
+            })
+            .collect())
+    }
+}
+
+/// How the player spreads a 2024-style background's ability score increases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AbilityScoreIncrease {
+    /// +2 to the first ability and +1 to the second.
+    TwoAndOne(ability::Identifier, ability::Identifier),
+    /// +1 to each of the background's abilities.
+    OneEach,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbilityScoreIncreaseError {
+    NoAbilityScores,
+    AbilityNotOffered(ability::Identifier),
+    SameAbility(ability::Identifier),
+}
+
+impl fmt::Display for AbilityScoreIncreaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            AbilityScoreIncreaseError::NoAbilityScores => {
+                String::from("Background doesn't offer ability score increases.")
+            }
+            AbilityScoreIncreaseError::AbilityNotOffered(ability) => {
+                format!("Background doesn't offer an increase to {ability}.")
+            }
+            AbilityScoreIncreaseError::SameAbility(ability) => {
+                format!("Cannot put both increases on {ability}.")
+            }
+        };
+
+        write!(f, "{result}")
+    }
 }
 
+impl Error for AbilityScoreIncreaseError {}
+
 #[derive(Clone, Debug, Default)]
 pub struct Builder {
     name: Option<String>,
     description: Option<String>,
     feature: Option<Feature>,
     proficiencies: Option<Proficiencies>,
+    ability_scores: Vec<ability::Identifier>,
 }
 
 impl Builder {
@@ -75,6 +200,13 @@ impl Builder {
 
         self
     }
+
+    #[must_use]
+    pub fn ability_scores(mut self, ability_scores: [ability::Identifier; 3]) -> Self {
+        self.ability_scores = ability_scores.to_vec();
+
+        self
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -142,6 +274,7 @@ impl TryInto<Background> for Builder {
             description: self.description.unwrap(),
             feature: self.feature.unwrap(),
             proficiencies: self.proficiencies.unwrap(),
+            ability_scores: self.ability_scores,
         })
     }
 }
@@ -171,34 +304,134 @@ impl Feature {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
-pub enum Proficiencies {
-    TwoSkillsTwoTools {
-        skills: Vec<skills::Identifier>,
-        tools: Vec<String>,
-    },
-    TwoSkillsTwoLanguages {
-        skills: Vec<skills::Identifier>,
-        languages: Vec<String>,
+/// The kind of proficiency a background can grant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GrantKind {
+    Skill,
+    Tool,
+    Language,
+}
+
+/// A single proficiency granted by a background.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Grant {
+    Skill(skills::Identifier),
+    Tool(Tool),
+    Language(Language),
+}
+
+impl Grant {
+    pub fn tool(tool: impl Into<String>) -> Self {
+        Grant::Tool(Tool::named(tool))
+    }
+
+    pub fn language(language: impl Into<String>) -> Self {
+        Grant::Language(Language::named(language))
+    }
+
+    #[must_use]
+    pub fn get_kind(&self) -> GrantKind {
+        match self {
+            Grant::Skill(_) => GrantKind::Skill,
+            Grant::Tool(_) => GrantKind::Tool,
+            Grant::Language(_) => GrantKind::Language,
+        }
+    }
+
+    /// Whether both grant the same proficiency; tools and languages compare without case.
+    #[must_use]
+    pub fn is_same_as(&self, other: &Grant) -> bool {
+        self == other
+    }
+}
+
+impl fmt::Display for Grant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Grant::Skill(skill) => write!(f, "{skill}"),
+            Grant::Tool(tool) => write!(f, "{tool}"),
+            Grant::Language(language) => write!(f, "{language}"),
+        }
+    }
+}
+
+/// One entry of a background's proficiency list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProficiencyOption {
+    Fixed(Grant),
+    /// `count` different grants from the list.
+    Choose {
+        count: usize,
+        from: Vec<Grant>,
     },
-    TwoSkillsOneLanguageOneTool {
-        skills: Vec<skills::Identifier>,
-        lanugage: String,
-        tool: String,
+    /// `count` different grants of one kind, e.g. two languages of the player's choice.
+    ChooseAny {
+        count: usize,
+        kind: GrantKind,
     },
 }
 
+impl ProficiencyOption {
+    fn check_pick(&self, pick: &Grant) -> Result<(), GrantError> {
+        match self {
+            ProficiencyOption::Choose { from, .. }
+                if !from.iter().any(|offered| offered.is_same_as(pick)) =>
+            {
+                Err(GrantError::NotOffered(pick.clone()))
+            }
+            ProficiencyOption::ChooseAny { kind, .. } if pick.get_kind() != *kind => {
+                Err(GrantError::WrongKind(pick.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Every skill, tool and language a background grants outright or lets the player pick.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Proficiencies(Vec<ProficiencyOption>);
+
 impl Proficiencies {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn grant(mut self, grant: Grant) -> Self {
+        self.0.push(ProficiencyOption::Fixed(grant));
+
+        self
+    }
+
+    #[must_use]
+    pub fn choose(mut self, count: usize, from: impl Into<Vec<Grant>>) -> Self {
+        self.0.push(ProficiencyOption::Choose {
+            count,
+            from: from.into(),
+        });
+
+        self
+    }
+
+    #[must_use]
+    pub fn choose_any(mut self, count: usize, kind: GrantKind) -> Self {
+        self.0.push(ProficiencyOption::ChooseAny { count, kind });
+
+        self
+    }
+
     pub fn two_skills_two_tools(
         skill1: skills::Identifier,
         skill2: skills::Identifier,
         tool1: impl Into<String>,
         tool2: impl Into<String>,
     ) -> Self {
-        Proficiencies::TwoSkillsTwoTools {
-            skills: vec![skill1, skill2],
-            tools: vec![tool1.into(), tool2.into()],
-        }
+        Proficiencies::new()
+            .grant(Grant::Skill(skill1))
+            .grant(Grant::Skill(skill2))
+            .grant(Grant::tool(tool1))
+            .grant(Grant::tool(tool2))
     }
 
     pub fn two_skills_two_languages(
@@ -207,10 +440,11 @@ impl Proficiencies {
         language1: impl Into<String>,
         language2: impl Into<String>,
     ) -> Self {
-        Proficiencies::TwoSkillsTwoLanguages {
-            skills: vec![skill1, skill2],
-            languages: vec![language1.into(), language2.into()],
-        }
+        Proficiencies::new()
+            .grant(Grant::Skill(skill1))
+            .grant(Grant::Skill(skill2))
+            .grant(Grant::language(language1))
+            .grant(Grant::language(language2))
     }
 
     pub fn two_skills_one_tool_one_language(
@@ -219,18 +453,407 @@ impl Proficiencies {
         tool: impl Into<String>,
         language: impl Into<String>,
     ) -> Self {
-        Proficiencies::TwoSkillsOneLanguageOneTool {
-            skills: vec![skill1, skill2],
-            lanugage: language.into(),
-            tool: tool.into(),
+        Proficiencies::new()
+            .grant(Grant::Skill(skill1))
+            .grant(Grant::Skill(skill2))
+            .grant(Grant::tool(tool))
+            .grant(Grant::language(language))
+    }
+
+    #[must_use]
+    pub fn get_options(&self) -> &[ProficiencyOption] {
+        &self.0
+    }
+
+    /// Indices of the options still waiting for the player's picks.
+    #[must_use]
+    pub fn get_open_choices(&self, selections: &Selections) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(index, option)| {
+                !matches!(option, ProficiencyOption::Fixed(_))
+                    && !selections.picks.contains_key(index)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// # Errors
+    ///
+    /// - `NoSuchOption`: there is no option at `index`
+    /// - `NotAChoice`: the option is a fixed grant
+    /// - `WrongCount`: the option wants a different number of picks
+    /// - `NotOffered`, `WrongKind`: a pick isn't allowed by the option
+    /// - `Duplicate`: the same pick appears twice
+    ///
+    pub fn validate_picks(&self, index: usize, picks: &[Grant]) -> Result<(), GrantError> {
+        let option = self.0.get(index).ok_or(GrantError::NoSuchOption(index))?;
+        let count = match option {
+            ProficiencyOption::Fixed(_) => return Err(GrantError::NotAChoice(index)),
+            ProficiencyOption::Choose { count, .. }
+            | ProficiencyOption::ChooseAny { count, .. } => *count,
+        };
+
+        if picks.len() != count {
+            return Err(GrantError::WrongCount {
+                expected: count,
+                found: picks.len(),
+            });
+        }
+
+        for (position, pick) in picks.iter().enumerate() {
+            option.check_pick(pick)?;
+
+            if picks[..position].iter().any(|other| other.is_same_as(pick)) {
+                return Err(GrantError::Duplicate(pick.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The fixed grants and the player's picks, with replacements swapped in.
+    #[must_use]
+    pub fn get_grants(&self, selections: &Selections) -> Vec<Grant> {
+        self.0
+            .iter()
+            .enumerate()
+            .flat_map(|(index, option)| match option {
+                ProficiencyOption::Fixed(grant) => vec![grant.clone()],
+                _ => selections.picks.get(&index).cloned().unwrap_or_default(),
+            })
+            .map(|grant| {
+                selections
+                    .replacements
+                    .iter()
+                    .find(|(duplicate, _)| duplicate.is_same_as(&grant))
+                    .map_or(grant, |(_, replacement)| replacement.clone())
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn get_skills(&self, selections: &Selections) -> Vec<skills::Identifier> {
+        self.get_grants(selections)
+            .into_iter()
+            .filter_map(|grant| match grant {
+                Grant::Skill(skill) => Some(skill),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn get_tools(&self, selections: &Selections) -> Vec<Tool> {
+        self.get_grants(selections)
+            .into_iter()
+            .filter_map(|grant| match grant {
+                Grant::Tool(tool) => Some(tool),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn get_languages(&self, selections: &Selections) -> Vec<Language> {
+        self.get_grants(selections)
+            .into_iter()
+            .filter_map(|grant| match grant {
+                Grant::Language(language) => Some(language),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// The player's background picks, kept on the character so they can be changed later.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Selections {
+    /// Keyed by the index of the option in [`Proficiencies::get_options`].
+    picks: BTreeMap<usize, Vec<Grant>>,
+    /// Grants the character already had from elsewhere, as `(duplicate, replacement)`.
+    replacements: Vec<(Grant, Grant)>,
+    ability_score_increase: Option<AbilityScoreIncrease>,
+}
+
+impl Selections {
+    #[must_use]
+    pub fn get_picks(&self, option: usize) -> Option<&[Grant]> {
+        self.picks.get(&option).map(Vec::as_slice)
+    }
+
+    #[must_use]
+    pub fn get_replacements(&self) -> &[(Grant, Grant)] {
+        &self.replacements
+    }
+
+    #[must_use]
+    pub fn get_ability_score_increase(&self) -> Option<AbilityScoreIncrease> {
+        self.ability_score_increase
+    }
+
+    /// Records the picks for one option, replacing any earlier picks.
+    ///
+    /// # Errors
+    ///
+    /// - see [`Proficiencies::validate_picks`]
+    ///
+    pub fn pick(
+        &mut self,
+        background: &Background,
+        option: usize,
+        picks: Vec<Grant>,
+    ) -> Result<(), GrantError> {
+        background.proficiencies.validate_picks(option, &picks)?;
+
+        self.picks.insert(option, picks);
+
+        Ok(())
+    }
+
+    /// Swaps a grant for another of the same kind, replacing any earlier swap of that grant.
+    ///
+    /// # Errors
+    ///
+    /// - `NotGranted`: the background doesn't grant `duplicate`
+    /// - `WrongKind`: `replacement` isn't the same kind of proficiency
+    /// - `Duplicate`: the background already grants `replacement`
+    ///
+    pub fn replace(
+        &mut self,
+        background: &Background,
+        duplicate: Grant,
+        replacement: Grant,
+    ) -> Result<(), GrantError> {
+        let mut candidate = self.clone();
+        candidate
+            .replacements
+            .retain(|(replaced, _)| !replaced.is_same_as(&duplicate));
+
+        let grants = background.proficiencies.get_grants(&candidate);
+
+        if !grants.iter().any(|grant| grant.is_same_as(&duplicate)) {
+            return Err(GrantError::NotGranted(duplicate));
+        }
+
+        if replacement.get_kind() != duplicate.get_kind() {
+            return Err(GrantError::WrongKind(replacement));
         }
+
+        if grants.iter().any(|grant| grant.is_same_as(&replacement)) {
+            return Err(GrantError::Duplicate(replacement));
+        }
+
+        candidate.replacements.push((duplicate, replacement));
+        self.replacements = candidate.replacements;
+
+        Ok(())
+    }
+
+    /// # Errors
+    ///
+    /// - see [`Background::get_ability_score_effects`]
+    ///
+    pub fn choose_ability_scores(
+        &mut self,
+        background: &Background,
+        increase: AbilityScoreIncrease,
+    ) -> Result<(), AbilityScoreIncreaseError> {
+        background.get_ability_score_effects(increase)?;
+
+        self.ability_score_increase = Some(increase);
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GrantError {
+    NoSuchOption(usize),
+    NotAChoice(usize),
+    WrongCount {
+        expected: usize,
+        found: usize,
+    },
+    NotOffered(Grant),
+    WrongKind(Grant),
+    Duplicate(Grant),
+    NotGranted(Grant),
+    /// The character already has the proficiency from another source.
+    AlreadyKnown(Grant),
+    /// The character doesn't have the proficiency from another source, so it can't be swapped.
+    NotDuplicated(Grant),
+}
+
+impl fmt::Display for GrantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            GrantError::NoSuchOption(index) => format!("Background has no option #{index}."),
+            GrantError::NotAChoice(index) => format!("Background option #{index} is fixed."),
+            GrantError::WrongCount { expected, found } => {
+                format!("Expected {expected} picks, found {found}.")
+            }
+            GrantError::NotOffered(grant) => format!("{grant} is not offered."),
+            GrantError::WrongKind(grant) => format!("{grant} is the wrong kind of proficiency."),
+            GrantError::Duplicate(grant) => format!("{grant} is granted twice."),
+            GrantError::NotGranted(grant) => format!("Background doesn't grant {grant}."),
+            GrantError::AlreadyKnown(grant) => {
+                format!("Already proficient in {grant}; pick a replacement instead.")
+            }
+            GrantError::NotDuplicated(grant) => {
+                format!("{grant} isn't granted by anything else, so it can't be replaced.")
+            }
+        };
+
+        write!(f, "{result}")
     }
 }
 
+impl Error for GrantError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn _should_spread_background_ability_increases_over_offered_abilities(
+    ) -> Result<(), Box<dyn Error>> {
+        let sage: Background = Builder::new()
+            .name("Sage")
+            .description("Years spent in libraries and scriptoriums.")
+            .feature(Feature::new("Researcher", "Know where to find lore."))
+            .proficiencies(Proficiencies::two_skills_two_languages(
+                skills::Identifier::Arcana,
+                skills::Identifier::History,
+                "Elvish",
+                "Dwarvish",
+            ))
+            .ability_scores([
+                ability::Identifier::Constitution,
+                ability::Identifier::Intelligence,
+                ability::Identifier::Wisdom,
+            ])
+            .try_into()?;
+
+        let effects = sage.get_ability_score_effects(AbilityScoreIncrease::TwoAndOne(
+            ability::Identifier::Intelligence,
+            ability::Identifier::Constitution,
+        ))?;
+
+        assert_eq!(
+            effects,
+            vec![
+                Effect::new(
+                    "Sage",
+                    Target::AbilityScore(ability::Identifier::Intelligence),
+                    Modifier::Add(2)
+                ),
+                Effect::new(
+                    "Sage",
+                    Target::AbilityScore(ability::Identifier::Constitution),
+                    Modifier::Add(1)
+                ),
+            ]
+        );
+        assert_eq!(
+            sage.get_ability_score_effects(AbilityScoreIncrease::OneEach)?
+                .len(),
+            3
+        );
+        assert_eq!(
+            sage.get_ability_score_effects(AbilityScoreIncrease::TwoAndOne(
+                ability::Identifier::Strength,
+                ability::Identifier::Wisdom,
+            )),
+            Err(AbilityScoreIncreaseError::AbilityNotOffered(
+                ability::Identifier::Strength
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _picks_should_match_the_option() {
+        let proficiencies = Proficiencies::new()
+            .grant(Grant::Skill(skills::Identifier::Insight))
+            .choose(
+                1,
+                [Grant::tool("Dice Set"), Grant::tool("Playing Card Set")],
+            )
+            .choose_any(2, GrantKind::Language);
+
+        assert_eq!(
+            proficiencies.validate_picks(0, &[]),
+            Err(GrantError::NotAChoice(0))
+        );
+        assert_eq!(
+            proficiencies.validate_picks(1, &[Grant::tool("Lute")]),
+            Err(GrantError::NotOffered(Grant::tool("Lute")))
+        );
+        assert_eq!(
+            proficiencies.validate_picks(1, &[Grant::tool("dice set")]),
+            Ok(())
+        );
+        assert_eq!(
+            proficiencies.validate_picks(2, &[Grant::language("Elvish")]),
+            Err(GrantError::WrongCount {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            proficiencies
+                .validate_picks(2, &[Grant::language("Elvish"), Grant::language("elvish")]),
+            Err(GrantError::Duplicate(Grant::language("elvish")))
+        );
+        assert_eq!(
+            proficiencies.validate_picks(2, &[Grant::language("Elvish"), Grant::tool("Lute")]),
+            Err(GrantError::WrongKind(Grant::tool("Lute")))
+        );
+    }
+
+    #[test]
+    fn _failed_replacement_should_keep_the_earlier_swap() -> Result<(), Box<dyn Error>> {
+        let acolyte: Background = Builder::new()
+            .name("Acolyte")
+            .description("A life in service to a temple.")
+            .feature(Feature::new(
+                "Shelter of the Faithful",
+                "Temples of your faith take you in.",
+            ))
+            .proficiencies(
+                Proficiencies::new()
+                    .grant(Grant::Skill(skills::Identifier::Insight))
+                    .grant(Grant::Skill(skills::Identifier::Religion)),
+            )
+            .try_into()?;
+        let mut selections = Selections::default();
+        let insight = Grant::Skill(skills::Identifier::Insight);
+        let history = (insight.clone(), Grant::Skill(skills::Identifier::History));
+
+        selections.replace(&acolyte, history.0.clone(), history.1.clone())?;
+
+        assert_eq!(
+            selections.replace(
+                &acolyte,
+                insight.clone(),
+                Grant::Skill(skills::Identifier::Religion)
+            ),
+            Err(GrantError::Duplicate(Grant::Skill(
+                skills::Identifier::Religion
+            )))
+        );
+        assert_eq!(
+            selections.replace(&acolyte, insight, Grant::tool("Lute")),
+            Err(GrantError::WrongKind(Grant::tool("Lute")))
+        );
+        assert_eq!(selections.get_replacements(), [history]);
+
+        Ok(())
+    }
+
     fn _should_successfully_build_with_all_fields() -> Result<(), Box<dyn Error>> {
         let test_background: Background = Builder::new()
             .name("Test Background")
@@ -256,13 +879,13 @@ mod tests {
                     name: String::from("Test Feature"),
                     description: String::from("This is a Feature used for testing.")
                 },
-                proficiencies: Proficiencies::TwoSkillsTwoTools {
-                    skills: vec![
-                        skills::Identifier::Acrobatics,
-                        skills::Identifier::AnimalHandling
-                    ],
-                    tools: vec![String::from("Land Vehicles"), String::from("Smith Tools")]
-                }
+                proficiencies: Proficiencies(vec![
+                    ProficiencyOption::Fixed(Grant::Skill(skills::Identifier::Acrobatics)),
+                    ProficiencyOption::Fixed(Grant::Skill(skills::Identifier::AnimalHandling)),
+                    ProficiencyOption::Fixed(Grant::tool("Land Vehicles")),
+                    ProficiencyOption::Fixed(Grant::tool("Smith Tools")),
+                ]),
+                ability_scores: vec![],
             }
         );
 
@@ -0,0 +1,160 @@
+#![warn(clippy::pedantic)]
+
+use std::{error, fmt};
+
+/// Something kept in a [`Catalogue`] and looked up by name, whatever the case.
+pub trait Entry: Clone {
+    /// The entries every catalogue starts with.
+    fn built_in() -> Vec<Self>;
+
+    /// Stands in for a name the catalogue doesn't know.
+    fn unknown(name: String) -> Self;
+
+    fn get_name(&self) -> &str;
+}
+
+/// Makes entries with the same name, whatever the case, equal and hash alike.
+macro_rules! impl_entry_eq {
+    ($entry:ty) => {
+        impl PartialEq for $entry {
+            fn eq(&self, other: &Self) -> bool {
+                $crate::catalogue::Entry::get_name(self)
+                    .eq_ignore_ascii_case($crate::catalogue::Entry::get_name(other))
+            }
+        }
+
+        impl Eq for $entry {}
+
+        impl std::hash::Hash for $entry {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                $crate::catalogue::Entry::get_name(self)
+                    .to_ascii_lowercase()
+                    .hash(state);
+            }
+        }
+    };
+}
+
+pub(crate) use impl_entry_eq;
+
+/// The entries characters can pick from: the built-in ones plus any homebrew.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Catalogue<T>(Vec<T>);
+
+impl<T: Entry> Default for Catalogue<T> {
+    fn default() -> Self {
+        Catalogue(T::built_in())
+    }
+}
+
+impl<T: Entry + PartialEq> Catalogue<T> {
+    /// # Errors
+    ///
+    /// - `AlreadyDefined`: an entry with the same name is already in the catalogue
+    ///
+    pub fn add(&mut self, entry: T) -> Result<(), Error> {
+        if self.0.contains(&entry) {
+            return Err(Error::AlreadyDefined(entry.get_name().to_owned()));
+        }
+
+        self.0.push(entry);
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&T> {
+        self.0
+            .iter()
+            .find(|entry| entry.get_name().eq_ignore_ascii_case(name))
+    }
+
+    #[must_use]
+    pub fn get_entries(&self) -> &[T] {
+        &self.0
+    }
+
+    /// The entry with this name, or a stand-in if the catalogue doesn't know it.
+    pub fn named(&self, name: impl Into<String>) -> T {
+        let name: String = name.into();
+
+        self.get(&name).cloned().unwrap_or_else(|| T::unknown(name))
+    }
+
+    /// The catalogue's own entry for `entry`, e.g. to fill in homebrew details for an entry
+    /// only known by name.
+    #[must_use]
+    pub fn resolve(&self, entry: T) -> T {
+        self.get(entry.get_name()).cloned().unwrap_or(entry)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    AlreadyDefined(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Error::AlreadyDefined(name) => format!("{name} is already defined."),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        language::{Language, Rarity},
+        tool::{Category, Tool},
+    };
+
+    #[test]
+    fn _catalogue_should_accept_homebrew_once() {
+        let mut catalogue: Catalogue<Language> = Catalogue::default();
+
+        assert_eq!(
+            catalogue.add(Language::new("Haskell", Rarity::Exotic, Some("Lambda"))),
+            Ok(())
+        );
+        assert_eq!(
+            catalogue.get("haskell").and_then(Language::get_script),
+            Some("Lambda")
+        );
+        assert_eq!(
+            catalogue.add(Language::named("COMMON")),
+            Err(Error::AlreadyDefined(String::from("Common")))
+        );
+    }
+
+    #[test]
+    fn _catalogue_should_resolve_names_against_homebrew() {
+        let mut catalogue: Catalogue<Tool> = Catalogue::default();
+
+        assert_eq!(
+            catalogue.named("Spelljammer Helm").get_category(),
+            Category::Other
+        );
+
+        catalogue
+            .add(Tool::new("Spelljammer Helm", Category::Vehicles))
+            .unwrap();
+
+        assert_eq!(
+            catalogue.named("spelljammer helm").get_category(),
+            Category::Vehicles
+        );
+        assert_eq!(
+            catalogue
+                .resolve(Tool::new("SPELLJAMMER HELM", Category::Other))
+                .get_name(),
+            "Spelljammer Helm"
+        );
+        assert_eq!(catalogue.named("lute").get_name(), "Lute");
+    }
+}
@@ -1,18 +1,38 @@
-use std::{error, fmt};
+use std::{collections::BTreeSet, error, fmt};
 
 use crate::{
     ability::{self, Abilities},
+    armor_class::{self, Formula},
+    artificer::{self, Infusion, InfusionChange, InfusionError, Infusions},
+    background::{
+        self, AbilityScoreIncrease, AbilityScoreIncreaseError, Background, Grant, GrantError,
+    },
+    catalogue,
     characteristics::{self, Characteristics, Gender},
-    class::{Class, Classes},
-    feat::Feat,
-    item::{self, Item, Items},
-    modifiers::{Encumbrance, Proficiency},
+    check::{Check, CheckKind, CheckRule},
+    class::{AbilityScoreImprovement, Class, Classes, ImprovementError, Subclass, SubclassError},
+    condition::{Concentration, Condition},
+    container::{self, Mount},
+    currency::{self, Coin, Price, Purse},
+    dice::Roller,
+    effect::{Breakdown, Component, Effect, Effects, Target},
+    feat::{self, Feat, FeatError},
+    feature::{Feature, FeatureError, Rest},
+    item::{
+        self, ArmorCategory, AttunementError, Grip, GripError, Item, Items, Prerequisite, Weapon,
+        MAX_ATTUNED_ITEMS,
+    },
+    language::{self, Language},
+    modifiers::{Encumbrance, EncumbranceRule, Proficiency, Resistance},
     personality::Personality,
     proficiencies::Proficiencies,
-    race::{CreatureType, Race, Size},
+    psionics::{discipline::ResolvedAct, ActError, ActPreview, Mystic, Psionics},
+    race::{self, CreatureType, DamageType, Race, Size},
     senses::Senses,
-    skills::{self, Skills},
-    slot::{ItemSlots, Slot, SlotsError},
+    skills::{self, Advantage, Skills},
+    slot::{ItemSlots, SlotsError},
+    slot_layout::{SlotLayout, SlotRule},
+    tool::{self, Tool},
 };
 
 #[derive(Clone, Debug, Default)]
@@ -28,6 +48,16 @@ pub struct Builder {
     equipment: Option<ItemSlots>,
     senses: Option<Senses>,
     proficiencies: Option<Proficiencies>,
+    armor_class_formulas: Vec<Formula>,
+    purse: Option<Purse>,
+    mounts: Vec<Mount>,
+    check_rules: Vec<CheckRule>,
+    effects: Effects,
+    background: Option<Background>,
+    background_selections: background::Selections,
+    psionics: Option<Mystic>,
+    language_catalogue: Option<language::Catalogue>,
+    tool_catalogue: Option<tool::Catalogue>,
 }
 
 impl Builder {
@@ -131,14 +161,20 @@ impl Builder {
         Ok(self)
     }
 
+    pub fn slot_layout(mut self, layout: SlotLayout) -> Result<Self, ConstructionError> {
+        let _ = self.equipment.insert(ItemSlots::from(layout));
+
+        Ok(self)
+    }
+
     pub fn add_equipment_slot(
         mut self,
         slot_name: impl Into<String>,
-        slot: Slot<Item, fn(&Item) -> bool>,
+        rule: SlotRule,
     ) -> Result<Self, ConstructionError> {
         let equipment = self.equipment.get_or_insert_with(Default::default);
 
-        equipment.add_slot(slot_name, slot);
+        equipment.add_slot(slot_name, rule);
 
         Ok(self)
     }
@@ -151,11 +187,11 @@ impl Builder {
 
     pub fn add_armor_proficiency(
         mut self,
-        armor_class: impl Into<String>,
+        armor_category: ArmorCategory,
     ) -> Result<Self, ConstructionError> {
         let proficiencies = self.proficiencies.get_or_insert_with(Default::default);
 
-        proficiencies.add_armor_proficiency(armor_class);
+        proficiencies.add_armor_proficiency(armor_category);
 
         Ok(self)
     }
@@ -171,13 +207,96 @@ impl Builder {
         Ok(self)
     }
 
-    pub fn add_tool_proficiency(
+    pub fn add_tool_proficiency(mut self, tool: Tool) -> Result<Self, ConstructionError> {
+        let proficiencies = self.proficiencies.get_or_insert_with(Default::default);
+
+        proficiencies.add_tool_proficiency(tool, Proficiency::Proficiency);
+
+        Ok(self)
+    }
+
+    pub fn add_tool_expertise(mut self, tool: Tool) -> Result<Self, ConstructionError> {
+        let proficiencies = self.proficiencies.get_or_insert_with(Default::default);
+
+        proficiencies.add_tool_proficiency(tool, Proficiency::Expertise);
+
+        Ok(self)
+    }
+
+    pub fn add_language(mut self, language: Language) -> Result<Self, ConstructionError> {
+        let proficiencies = self.proficiencies.get_or_insert_with(Default::default);
+
+        proficiencies.add_language(language);
+
+        Ok(self)
+    }
+
+    pub fn add_armor_class_formula(mut self, formula: Formula) -> Result<Self, ConstructionError> {
+        self.armor_class_formulas.push(formula);
+
+        Ok(self)
+    }
+
+    pub fn purse(mut self, purse: Purse) -> Result<Self, ConstructionError> {
+        let _ = self.purse.insert(purse);
+
+        Ok(self)
+    }
+
+    pub fn add_mount(mut self, mount: Mount) -> Result<Self, ConstructionError> {
+        self.mounts.push(mount);
+
+        Ok(self)
+    }
+
+    pub fn add_check_rule(mut self, rule: CheckRule) -> Result<Self, ConstructionError> {
+        self.check_rules.push(rule);
+
+        Ok(self)
+    }
+
+    pub fn add_effect(mut self, effect: Effect) -> Result<Self, ConstructionError> {
+        self.effects.add(effect);
+
+        Ok(self)
+    }
+
+    pub fn background(mut self, background: Background) -> Result<Self, ConstructionError> {
+        let _ = self.background.insert(background);
+
+        Ok(self)
+    }
+
+    /// Picks made for the background at creation, validated through
+    /// [`background::Selections::pick`].
+    pub fn background_selections(
         mut self,
-        tool: impl Into<String>,
+        selections: background::Selections,
     ) -> Result<Self, ConstructionError> {
-        let proficiencies = self.proficiencies.get_or_insert_with(Default::default);
+        self.background_selections = selections;
+
+        Ok(self)
+    }
+
+    pub fn psionics(mut self, mystic: Mystic) -> Result<Self, ConstructionError> {
+        let _ = self.psionics.insert(mystic);
+
+        Ok(self)
+    }
+
+    /// The languages, homebrew included, that names are looked up in.
+    pub fn language_catalogue(
+        mut self,
+        catalogue: language::Catalogue,
+    ) -> Result<Self, ConstructionError> {
+        let _ = self.language_catalogue.insert(catalogue);
+
+        Ok(self)
+    }
 
-        proficiencies.add_tool_proficiency(tool);
+    /// The tools, homebrew included, that names are looked up in.
+    pub fn tool_catalogue(mut self, catalogue: tool::Catalogue) -> Result<Self, ConstructionError> {
+        let _ = self.tool_catalogue.insert(catalogue);
 
         Ok(self)
     }
@@ -217,20 +336,55 @@ impl Builder {
 
         let proficiencies = self.proficiencies.unwrap_or_default();
 
+        let armor_class_formulas = self.armor_class_formulas;
+
+        let purse = self.purse.unwrap_or_default();
+
+        let mounts = self.mounts;
+
+        let check_rules = self.check_rules;
+
+        let effects = self.effects;
+
+        let background = self.background;
+
+        let background_selections = self.background_selections;
+
+        let psionics = self.psionics;
+
+        let language_catalogue = self.language_catalogue.unwrap_or_default();
+
+        let tool_catalogue = self.tool_catalogue.unwrap_or_default();
+
         Ok(Character {
             name,
             characteristics,
             personality,
             race,
+            race_selections: race::Selections::default(),
+            background,
+            background_selections,
             base_ability_scores,
             classes,
             skills: skill_proficiencies,
             inventory,
             equipment,
             exhaustion_level: 0,
+            encumbrance_rule: EncumbranceRule::default(),
             damage: 0,
             senses,
             proficiencies,
+            armor_class_formulas,
+            purse,
+            mounts,
+            conditions: BTreeSet::new(),
+            check_rules,
+            effects,
+            infusions: Infusions::default(),
+            psionics,
+            concentration: None,
+            language_catalogue,
+            tool_catalogue,
         })
     }
 }
@@ -260,15 +414,30 @@ pub struct Character {
     characteristics: Characteristics,
     personality: Personality,
     race: Race,
+    race_selections: race::Selections,
+    background: Option<Background>,
+    background_selections: background::Selections,
     base_ability_scores: Abilities,
     classes: Classes,
     skills: Skills,
     inventory: Items,
     equipment: ItemSlots,
     exhaustion_level: usize,
+    encumbrance_rule: EncumbranceRule,
     damage: usize,
     senses: Senses,
     proficiencies: Proficiencies,
+    armor_class_formulas: Vec<Formula>,
+    purse: Purse,
+    mounts: Vec<Mount>,
+    conditions: BTreeSet<Condition>,
+    check_rules: Vec<CheckRule>,
+    effects: Effects,
+    infusions: Infusions,
+    psionics: Option<Mystic>,
+    concentration: Option<Concentration>,
+    language_catalogue: language::Catalogue,
+    tool_catalogue: tool::Catalogue,
 }
 
 impl Character {
@@ -314,587 +483,4505 @@ impl Character {
 
     #[must_use]
     pub fn get_hit_points_max(&self) -> usize {
-        let constitution_modifier = self.get_ability_modifier(ability::Identifier::Constitution);
+        usize::try_from(self.get_hit_points_max_breakdown().get_total()).unwrap_or(0)
+    }
 
-        self.classes.get_hit_points(constitution_modifier)
+    #[must_use]
+    pub fn get_hit_points_max_breakdown(&self) -> Breakdown {
+        let constitution_modifier = self.get_ability_modifier(ability::Identifier::Constitution);
+        let hit_points = self.classes.get_hit_points(constitution_modifier);
+        let effects = self.get_effects();
+        let level = self.get_level() as isize;
+
+        let per_level = effects
+            .get_bonuses(Target::HitPointsPerLevel)
+            .into_iter()
+            .map(|bonus| Component::new(bonus.get_source(), bonus.get_value() * level));
+
+        effects.resolve(
+            Target::HitPointsMax,
+            [Component::new("Hit dice", hit_points as isize)]
+                .into_iter()
+                .chain(per_level)
+                .collect::<Vec<_>>(),
+        )
     }
 
     #[must_use]
     pub fn get_initiative(&self) -> isize {
-        self.get_ability_modifier(ability::Identifier::Dexterity)
+        self.get_initiative_breakdown().get_total()
     }
 
     #[must_use]
-    pub fn get_armor_class(&self) -> usize {
-        let dex_mod = self.get_ability_modifier(ability::Identifier::Dexterity);
-
-        self.equipment
-            .get_equipped_items()
-            .iter()
-            .filter_map(|item| item.get_armor_class())
-            .map(|armor_class| match armor_class {
-                crate::item::ArmorClass::Light(ac) => ac as isize + dex_mod,
-                crate::item::ArmorClass::Medium(ac) => {
-                    ac as isize + if dex_mod > 2 { 2 } else { dex_mod }
-                }
-                crate::item::ArmorClass::Heavy(ac) => ac as isize,
-            } as usize)
-            .sum()
+    pub fn get_initiative_breakdown(&self) -> Breakdown {
+        self.get_effects().resolve(
+            Target::Initiative,
+            vec![Component::new(
+                ability::Identifier::Dexterity.to_string(),
+                self.get_ability_modifier(ability::Identifier::Dexterity),
+            )],
+        )
     }
 
     #[must_use]
-    pub fn get_creature_type(&self) -> &CreatureType {
-        self.race.get_creature_type()
+    pub fn get_armor_class(&self) -> usize {
+        usize::try_from(self.get_armor_class_breakdown().get_total()).unwrap_or(0)
     }
 
     #[must_use]
-    pub fn get_size(&self) -> &Size {
-        self.race.get_size()
+    pub fn get_armor_class_breakdown(&self) -> Breakdown {
+        self.get_effects().resolve(
+            Target::ArmorClass,
+            armor_class::calculate(
+                &self.equipment.get_equipped_items(),
+                &self.get_abilities(),
+                &self.armor_class_formulas,
+            ),
+        )
     }
 
+    /// Effects registered directly on the character, e.g. by class features or spells.
     #[must_use]
-    pub fn get_walking_speed(&self) -> usize {
-        let base_speed = self.race.get_walking_speed();
-        let encumbrance_modifier = match self.get_variant_encumbrance() {
-            Some(Encumbrance::Encumbered) => 10,
-            Some(Encumbrance::HeavilyEncumbered) => 20,
-            _ => 0,
-        };
-        let mut walking_speed = base_speed.saturating_sub(encumbrance_modifier);
-        let exhaustion_level = self.get_exhaustion_level();
-        if exhaustion_level >= 2 {
-            walking_speed /= 2;
-        }
-        if exhaustion_level >= 5 {
-            walking_speed = 0;
-        }
-        walking_speed
+    pub fn get_registered_effects(&self) -> &Effects {
+        &self.effects
     }
 
-    pub fn get_abilities(&self) -> Abilities {
-        self.base_ability_scores.clone() + self.race.get_abilities().clone()
+    pub fn add_effect(&mut self, effect: Effect) {
+        self.effects.add(effect);
     }
 
-    #[must_use]
-    pub fn get_ability_score(&self, ability: ability::Identifier) -> usize {
-        self.get_abilities().get_score(ability).unwrap_or(0)
+    /// Ends every effect from `source`, e.g. when a spell ends.
+    pub fn remove_effects_from(&mut self, source: &str) {
+        self.effects.remove_source(source);
     }
 
+    /// Every effect currently applying: registered ones, those of active or infused equipped
+    /// items and feats, and those of conditions.
     #[must_use]
-    pub fn get_ability_modifier(&self, ability: ability::Identifier) -> isize {
-        self.get_abilities().get_modifier(ability).unwrap_or(0)
+    pub fn get_effects(&self) -> Effects {
+        let equipped = self.equipment.get_equipped_items();
+        let items = equipped
+            .iter()
+            .filter(|item| item.is_active())
+            .flat_map(|item| item.get_effects());
+        let infusions = equipped
+            .iter()
+            .filter_map(|item| self.infusions.get_infusion_on(item))
+            .flat_map(|infusion| infusion.get_effects(self.get_artificer_level()));
+        let feats = self
+            .get_feats()
+            .into_iter()
+            .flat_map(|(_, feat)| feat.get_effects());
+
+        self.effects
+            .iter()
+            .chain(items)
+            .cloned()
+            .chain(feats)
+            .chain(infusions)
+            .chain(self.race.get_effects(&self.race_selections))
+            .chain(self.get_background_effects())
+            .chain(self.conditions.iter().flat_map(Condition::get_effects))
+            .collect()
     }
 
-    #[must_use]
-    pub fn get_level(&self) -> usize {
-        self.classes.get_level()
+    fn get_background_effects(&self) -> Vec<Effect> {
+        self.background
+            .as_ref()
+            .map(|background| background.get_effects(&self.background_selections))
+            .unwrap_or_default()
     }
 
+    /// Each resistance, immunity or vulnerability to `damage_type` and where it came from.
     #[must_use]
-    pub fn get_proficiency_bonus(&self) -> usize {
-        self.classes.get_proficiency_bonus()
+    pub fn get_damage_resistances(&self, damage_type: DamageType) -> Vec<(String, Resistance)> {
+        self.race
+            .get_damage_resistance(&damage_type)
+            .map(|&resistance| (self.race.get_name().to_owned(), resistance))
+            .into_iter()
+            .chain(
+                self.get_effects()
+                    .get_resistances(damage_type)
+                    .into_iter()
+                    .map(|(source, resistance)| (source.to_owned(), resistance)),
+            )
+            .collect()
     }
 
+    /// Immunity wins outright; otherwise resistance halves and vulnerability doubles, each
+    /// applying once no matter how many sources grant it.
     #[must_use]
-    pub fn get_saving_throw_proficiency(
-        &self,
-        ability: ability::Identifier,
-    ) -> Option<&Proficiency> {
-        self.classes.get_saving_throw_proficiency(ability)
+    pub fn get_damage_multiplier(&self, damage_type: DamageType) -> f32 {
+        let resistances: Vec<Resistance> = self
+            .get_damage_resistances(damage_type)
+            .into_iter()
+            .map(|(_, resistance)| resistance)
+            .collect();
+
+        if resistances.contains(&Resistance::Immune) {
+            return 0.0;
+        }
+
+        [Resistance::Resistant, Resistance::Vulnerable]
+            .into_iter()
+            .filter(|resistance| resistances.contains(resistance))
+            .map(|resistance| resistance.get_damage_multiplier())
+            .product()
     }
 
-    #[must_use]
-    pub fn get_saving_throw_mod(&self, ability: ability::Identifier) -> isize {
-        self.get_proficiency_bonus() as isize
-            * (self
-                .get_saving_throw_proficiency(ability)
-                .map_or(0, |&p| p as isize))
-            + self.get_ability_modifier(ability)
+    pub fn add_armor_class_formula(&mut self, formula: Formula) {
+        self.armor_class_formulas.push(formula);
     }
 
-    pub fn get_total_weight_carried(&self) -> usize {
-        self.inventory.get_total_weight() + self.equipment.get_total_weight()
+    pub fn remove_armor_class_formula(&mut self, formula: &Formula) {
+        self.armor_class_formulas.retain(|f| f != formula);
     }
 
+    /// Equipped armor and shields the character lacks proficiency with.
     #[must_use]
-    pub fn get_variant_encumbrance(&self) -> Option<Encumbrance> {
-        let total_weight_carried = self.get_total_weight_carried();
-        let strength_score = self.get_ability_score(ability::Identifier::Strength);
-
-        if total_weight_carried > (10 * strength_score) {
-            Some(Encumbrance::HeavilyEncumbered)
-        } else if total_weight_carried > (5 * strength_score) {
-            Some(Encumbrance::Encumbered)
-        } else {
-            None
-        }
+    pub fn get_untrained_armor(&self) -> Vec<&Item> {
+        self.equipment
+            .get_equipped_items()
+            .into_iter()
+            .filter(|item| {
+                item.get_armor_class().is_some_and(|armor_class| {
+                    !self
+                        .get_own_proficiencies()
+                        .is_proficient_with_armor(armor_class.get_category())
+                })
+            })
+            .collect()
     }
 
-    pub fn add_item(&mut self, item: Item) {
-        self.inventory.add_item(item);
+    #[must_use]
+    pub fn is_below_armor_strength_requirement(&self) -> bool {
+        let strength_score = self.get_ability_score(ability::Identifier::Strength);
+
+        self.equipment
+            .get_equipped_items()
+            .iter()
+            .filter_map(|item| item.get_strength_requirement())
+            .any(|requirement| strength_score < requirement)
     }
 
+    /// Untrained armor prevents spellcasting entirely.
     #[must_use]
-    pub fn get_exhaustion_level(&self) -> usize {
-        self.exhaustion_level
+    pub fn can_cast_spells(&self) -> bool {
+        self.get_untrained_armor().is_empty()
     }
 
-    pub fn set_exhaustion_level(&mut self, new_level: usize) {
-        self.exhaustion_level = new_level;
+    fn get_untrained_armor_advantage(&self, ability: ability::Identifier) -> Option<Advantage> {
+        let is_physical = matches!(
+            ability,
+            ability::Identifier::Strength | ability::Identifier::Dexterity
+        );
+
+        (is_physical && !self.get_untrained_armor().is_empty()).then_some(Advantage::Disadvantage)
     }
 
-    pub fn get_skill_proficiency(&self, skill: skills::Identifier) -> Option<Proficiency> {
-        self.skills.get_proficiency(skill)
+    /// Advantage and disadvantage from conditions and exhaustion: level 1 hinders ability
+    /// checks, level 3 saving throws as well.
+    fn get_condition_advantages(&self, kind: CheckKind) -> Vec<Advantage> {
+        let exhaustion_threshold = if kind.is_ability_check() { 1 } else { 3 };
+        let exhaustion =
+            (self.exhaustion_level >= exhaustion_threshold).then_some(Advantage::Disadvantage);
+
+        self.conditions
+            .iter()
+            .filter_map(|condition| condition.get_check_advantage(kind))
+            .chain(exhaustion)
+            .chain(self.get_effects().get_advantages(kind.into()))
+            .collect()
     }
 
     #[must_use]
-    pub fn get_skill_modifier(&self, skill: skills::Identifier) -> isize {
-        self.skills.get_modifier(
-            skill,
-            self.get_ability_modifier(skill.get_default_ability()),
-            self.get_proficiency_bonus(),
+    pub fn get_ability_check_advantage(&self, ability: ability::Identifier) -> Option<Advantage> {
+        Advantage::combine(
+            self.get_untrained_armor_advantage(ability)
+                .into_iter()
+                .chain(self.get_condition_advantages(CheckKind::Ability(ability))),
         )
     }
 
     #[must_use]
-    pub fn get_passive_perception(&self) -> usize {
-        self.senses
-            .get_passive_perception(self.get_skill_modifier(skills::Identifier::Perception))
+    pub fn get_saving_throw_advantage(&self, ability: ability::Identifier) -> Option<Advantage> {
+        Advantage::combine(
+            self.get_untrained_armor_advantage(ability)
+                .into_iter()
+                .chain(self.get_condition_advantages(CheckKind::SavingThrow(ability))),
+        )
     }
 
     #[must_use]
-    pub fn get_passive_investigation(&self) -> usize {
-        self.senses
-            .get_passive_perception(self.get_skill_modifier(skills::Identifier::Investigation))
+    pub fn get_attack_advantage(&self, ability: ability::Identifier) -> Option<Advantage> {
+        let exhaustion = (self.exhaustion_level >= 3).then_some(Advantage::Disadvantage);
+
+        Advantage::combine(
+            self.get_untrained_armor_advantage(ability)
+                .into_iter()
+                .chain(
+                    self.conditions
+                        .iter()
+                        .filter_map(Condition::get_attack_advantage),
+                )
+                .chain(exhaustion)
+                .chain(self.get_effects().get_advantages(Target::AttackRolls)),
+        )
     }
 
     #[must_use]
-    pub fn get_passive_insight(&self) -> usize {
-        self.senses
-            .get_passive_insight(self.get_skill_modifier(skills::Identifier::Insight))
+    pub fn get_skill_advantage(&self, skill: skills::Identifier) -> Option<Advantage> {
+        let stealth_disadvantage = (skill == skills::Identifier::Stealth
+            && self
+                .equipment
+                .get_equipped_items()
+                .iter()
+                .any(|item| item.has_stealth_disadvantage()))
+        .then_some(Advantage::Disadvantage);
+
+        Advantage::combine(
+            self.skills
+                .get_advantage(skill)
+                .into_iter()
+                .chain(self.get_untrained_armor_advantage(skill.get_default_ability()))
+                .chain(stealth_disadvantage)
+                .chain(self.get_condition_advantages(CheckKind::Skill(skill))),
+        )
     }
 
-    pub fn get_darkvision(&self) -> Option<usize> {
-        self.senses.get_darkvision()
+    #[must_use]
+    pub fn get_check_advantage(&self, kind: CheckKind) -> Option<Advantage> {
+        match kind {
+            CheckKind::Ability(ability) => self.get_ability_check_advantage(ability),
+            CheckKind::Skill(skill) => self.get_skill_advantage(skill),
+            CheckKind::SavingThrow(ability) => self.get_saving_throw_advantage(ability),
+        }
     }
 
-    pub fn add_class(&mut self, class: Class) {
-        self.classes.add_class(class);
+    #[must_use]
+    pub fn get_conditions(&self) -> &BTreeSet<Condition> {
+        &self.conditions
     }
 
-    pub fn add_equipment_slot(
-        &mut self,
-        slot_name: impl Into<String>,
-        slot: Slot<Item, fn(&Item) -> bool>,
-    ) {
-        self.equipment.add_slot(slot_name, slot);
+    #[must_use]
+    pub fn has_condition(&self, condition: Condition) -> bool {
+        self.conditions.contains(&condition)
     }
 
-    pub fn equip_item(&mut self, item: Item, slot_name: impl Into<String>) -> CharacterResult<()> {
-        self.equipment.equip(item, slot_name)?;
+    /// An incapacitating condition also ends concentration.
+    pub fn add_condition(&mut self, condition: Condition) {
+        if condition.is_incapacitating() {
+            self.concentration = None;
+        }
 
-        Ok(())
+        self.conditions.insert(condition);
     }
 
-    pub fn has_item_equipped_matching_criteria(&self, item_criteria: fn(&Item) -> bool) -> bool {
-        self.equipment
-            .has_item_equipped_matching_criteria(item_criteria)
+    pub fn remove_condition(&mut self, condition: Condition) {
+        self.conditions.remove(&condition);
     }
 
-    pub fn get_feats(&self) -> Vec<&Feat> {
-        [self.classes.get_feats(), self.race.get_feats()].concat()
+    #[must_use]
+    pub fn get_concentration(&self) -> Option<&Concentration> {
+        self.concentration.as_ref()
     }
 
-    pub fn get_armor_proficiencies_string(&self) -> String {
-        self.proficiencies.get_armor_proficiencies_string()
+    /// Starts concentrating, returning what the character was concentrating on before.
+    pub fn start_concentration(&mut self, concentration: Concentration) -> Option<Concentration> {
+        self.concentration.replace(concentration)
     }
 
-    pub fn get_weapon_proficiencies_string(&self) -> String {
-        self.proficiencies.get_weapon_proficiencies_string()
+    pub fn end_concentration(&mut self) -> Option<Concentration> {
+        self.concentration.take()
     }
 
-    pub fn get_tool_proficiencies_string(&self) -> String {
-        self.proficiencies.get_tool_proficiencies_string()
+    #[must_use]
+    pub fn get_check_rules(&self) -> &[CheckRule] {
+        &self.check_rules
     }
 
-    pub fn get_languages_string(&self) -> String {
-        let languages: Vec<String> = self
-            .proficiencies
-            .get_languages()
-            .iter()
-            .map(|lang| lang.to_string())
-            .chain(
-                self.race
-                    .get_languages()
-                    .iter()
-                    .map(|lang| lang.to_string()),
-            )
-            .collect();
+    pub fn add_check_rule(&mut self, rule: CheckRule) {
+        self.check_rules.push(rule);
+    }
 
-        languages.join(", ")
+    /// The better of the character's own proficiency and any granted by effects.
+    fn get_check_proficiency(&self, kind: CheckKind) -> Option<Proficiency> {
+        let own = match kind {
+            CheckKind::Ability(_) => None,
+            CheckKind::Skill(skill) => self.skills.get_proficiency(skill),
+            CheckKind::SavingThrow(ability) => {
+                self.classes.get_saving_throw_proficiency(ability).copied()
+            }
+        };
+
+        own.into_iter()
+            .chain(self.get_effects().get_proficiency(kind.into()))
+            .max_by_key(|&proficiency| proficiency as usize)
     }
-}
 
-type CharacterResult<T> = Result<T, Error>;
+    /// Everything added to the d20 for `kind`, starting with the ability modifier.
+    #[must_use]
+    pub fn get_check_components(&self, kind: CheckKind) -> Vec<Component> {
+        self.get_check_breakdown(kind).into()
+    }
 
-#[derive(Debug)]
-pub enum Error {
-    Equipment(SlotsError),
-    Inventory(item::ConstructionError),
-}
+    #[must_use]
+    pub fn get_check_breakdown(&self, kind: CheckKind) -> Breakdown {
+        self.get_check_breakdown_with(kind, self.get_check_proficiency(kind))
+    }
 
-impl From<SlotsError> for Error {
-    fn from(value: SlotsError) -> Self {
-        Error::Equipment(value)
+    fn get_check_breakdown_with(
+        &self,
+        kind: CheckKind,
+        proficiency: Option<Proficiency>,
+    ) -> Breakdown {
+        let ability = kind.get_ability();
+        let proficiency_bonus = self.get_proficiency_bonus() as isize;
+
+        let mut components = vec![Component::new(
+            ability.to_string(),
+            self.get_ability_modifier(ability),
+        )];
+
+        match proficiency {
+            Some(Proficiency::Proficiency) => {
+                components.push(Component::new("Proficiency", proficiency_bonus));
+            }
+            Some(Proficiency::Expertise) => {
+                components.push(Component::new("Expertise", 2 * proficiency_bonus));
+            }
+            None if kind.is_ability_check()
+                && self.check_rules.contains(&CheckRule::HalfProficiency) =>
+            {
+                components.push(Component::new(
+                    CheckRule::HalfProficiency.to_string(),
+                    proficiency_bonus / 2,
+                ));
+            }
+            None => {}
+        }
+
+        self.get_effects().resolve(kind.into(), components)
     }
-}
 
-impl From<item::ConstructionError> for Error {
-    fn from(value: item::ConstructionError) -> Self {
-        Error::Inventory(value)
+    /// Rolls a d20 check with every advantage, modifier and rule that applies.
+    pub fn roll_check(&self, kind: CheckKind, roller: &mut impl Roller) -> Check {
+        self.roll_check_with(kind, self.get_check_proficiency(kind), roller)
     }
-}
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let result = match self {
-            Error::Equipment(e) => format!("Equipment: {e}"),
-            Error::Inventory(e) => format!("Inventory: {e}"),
-        };
+    fn roll_check_with(
+        &self,
+        kind: CheckKind,
+        proficiency: Option<Proficiency>,
+        roller: &mut impl Roller,
+    ) -> Check {
+        let is_proficient = proficiency.is_some();
+        let minimum_roll = self
+            .check_rules
+            .iter()
+            .filter_map(|rule| match rule {
+                CheckRule::MinimumRoll(minimum) => Some(*minimum),
+                CheckRule::HalfProficiency => None,
+            })
+            .max()
+            .filter(|_| kind.is_ability_check() && is_proficient);
+
+        let check = Check::roll(
+            kind,
+            self.get_check_advantage(kind),
+            self.get_check_breakdown_with(kind, proficiency).into(),
+            minimum_roll,
+            roller,
+        );
 
-        write!(f, "{result}")
+        if self
+            .conditions
+            .iter()
+            .any(|condition| condition.fails_automatically(kind))
+        {
+            check.fail_automatically()
+        } else {
+            check
+        }
     }
-}
 
-impl error::Error for Error {}
+    #[must_use]
+    pub fn get_creature_type(&self) -> &CreatureType {
+        self.race.get_creature_type()
+    }
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        ability::AbilitiesTemplate,
-        characteristics::{Alignment, Conformity, Morality},
-        item::{self, ArmorClass},
-        units::{Duration, Weight},
-    };
+    #[must_use]
+    pub fn get_size(&self) -> &Size {
+        self.race.get_size()
+    }
 
-    use super::*;
+    #[must_use]
+    pub fn get_walking_speed(&self) -> usize {
+        usize::try_from(self.get_walking_speed_breakdown().get_total()).unwrap_or(0)
+    }
 
-    impl Character {
-        fn dummy() -> Self {
-            let characteristics: Characteristics = characteristics::Builder::new()
-                .alignment(Alignment(Conformity::Neutral, Morality::Neutral))
-                .size(Size::Medium)
-                .eye_color("--")
-                .height(vec![])
-                .hair_color("--")
-                .skin_tone("--")
-                .age(Duration::Instantaneous)
-                .weight(Weight::Pounds(100))
-                .try_into()
-                .expect("Shouldn't break");
+    /// Race speed and bonuses, then every penalty in the order the rules apply them.
+    #[must_use]
+    pub fn get_walking_speed_breakdown(&self) -> Breakdown {
+        let effects = self.get_effects();
+
+        let mut breakdown = Breakdown::from(vec![Component::new(
+            self.race.get_name(),
+            self.race.get_walking_speed() as isize,
+        )]);
+        breakdown.extend(effects.get_bonuses(Target::WalkingSpeed));
+
+        let mut reduce = |source: &str, amount: isize| {
+            let total = breakdown.get_total().max(0);
+            breakdown.limit(source, total - amount.min(total));
+        };
 
-            Self {
-                name: "Dummy".into(),
-                characteristics,
-                base_ability_scores: Abilities::from(AbilitiesTemplate {
-                    strength: 8,
-                    dexterity: 8,
-                    constitution: 8,
-                    intelligence: 8,
-                    wisdom: 8,
-                    charisma: 8,
-                }),
-                race: Race::human(),
-                classes: Classes::default(),
-                personality: Personality::default(),
-                skills: Skills::default(),
-                inventory: Items::default(),
-                exhaustion_level: 0,
-                damage: 0,
-                equipment: ItemSlots::default(),
-                senses: Senses::default(),
-                proficiencies: Proficiencies::default(),
+        if self.encumbrance_rule == EncumbranceRule::Variant {
+            match self.get_variant_encumbrance() {
+                Some(Encumbrance::Encumbered) => reduce("Encumbered", 10),
+                Some(Encumbrance::HeavilyEncumbered) => reduce("Heavily encumbered", 20),
+                None => {}
+            }
+        }
+        if self.is_below_armor_strength_requirement() {
+            reduce("Armor too heavy", 10);
+        }
+
+        if self.encumbrance_rule == EncumbranceRule::Standard {
+            let total_weight_carried = self.get_total_weight_carried();
+            if total_weight_carried > self.get_push_drag_lift_capacity() {
+                breakdown.limit("Over push, drag or lift capacity", 0);
+            } else if total_weight_carried > self.get_carrying_capacity() {
+                breakdown.limit("Over carrying capacity", 5);
+            }
+        }
+
+        let exhaustion_level = self.get_exhaustion_level();
+        if exhaustion_level >= 5 {
+            breakdown.limit("Exhaustion", 0);
+        } else if exhaustion_level >= 2 {
+            breakdown.limit("Exhaustion", breakdown.get_total() / 2);
+        }
+
+        effects.apply_overrides(Target::WalkingSpeed, breakdown)
+    }
+
+    /// Each ability score after racial bonuses and effects.
+    pub fn get_abilities(&self) -> Abilities {
+        let effects = self.get_effects();
+        let mut abilities = Abilities::default();
+
+        for ability in ability::Identifier::all() {
+            abilities.set_breakdown(ability, self.resolve_ability_score(&effects, ability));
+        }
+
+        abilities
+    }
+
+    /// Base score, racial bonus, Ability Score Improvements and other bonuses, held to the
+    /// score's maximum, followed by anything that sets the score outright.
+    fn resolve_ability_score(&self, effects: &Effects, ability: ability::Identifier) -> Breakdown {
+        let target = Target::AbilityScore(ability);
+        let base = self.base_ability_scores.get_score(ability).unwrap_or(0);
+        let mut components = vec![Component::new("Base", base as isize)];
+
+        let bonus = self.race.get_ability_bonus(ability, &self.race_selections);
+        if bonus != 0 {
+            components.push(Component::new(self.race.get_name(), bonus as isize));
+        }
+
+        let mut breakdown = Breakdown::from(components);
+        breakdown.extend(self.classes.get_ability_score_increases(ability));
+        breakdown.extend(effects.get_bonuses(target));
+        breakdown.limit(
+            "Maximum",
+            self.get_ability_score_maximum_with(effects, ability),
+        );
+
+        effects.apply_overrides(target, breakdown)
+    }
+
+    fn get_ability_score_maximum_with(
+        &self,
+        effects: &Effects,
+        ability: ability::Identifier,
+    ) -> isize {
+        effects.get_maximum(
+            Target::AbilityScore(ability),
+            ability::MAXIMUM_SCORE as isize,
+        )
+    }
+
+    /// The highest the score can reach before anything sets it outright, usually 20.
+    #[must_use]
+    pub fn get_ability_score_maximum(&self, ability: ability::Identifier) -> isize {
+        self.get_ability_score_maximum_with(&self.get_effects(), ability)
+    }
+
+    #[must_use]
+    pub fn get_ability_score_breakdown(&self, ability: ability::Identifier) -> Breakdown {
+        self.resolve_ability_score(&self.get_effects(), ability)
+    }
+
+    #[must_use]
+    pub fn get_ability_score(&self, ability: ability::Identifier) -> usize {
+        self.get_abilities().get_score(ability).unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn get_ability_modifier(&self, ability: ability::Identifier) -> isize {
+        self.get_abilities().get_modifier(ability).unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn get_level(&self) -> usize {
+        self.classes.get_level()
+    }
+
+    #[must_use]
+    pub fn get_proficiency_bonus(&self) -> usize {
+        self.classes.get_proficiency_bonus()
+    }
+
+    #[must_use]
+    pub fn get_saving_throw_proficiency(
+        &self,
+        ability: ability::Identifier,
+    ) -> Option<Proficiency> {
+        self.get_check_proficiency(CheckKind::SavingThrow(ability))
+    }
+
+    #[must_use]
+    pub fn get_saving_throw_mod(&self, ability: ability::Identifier) -> isize {
+        self.get_check_breakdown(CheckKind::SavingThrow(ability))
+            .get_total()
+    }
+
+    pub fn get_total_weight_carried(&self) -> f64 {
+        self.inventory.get_total_weight()
+            + self.equipment.get_total_weight()
+            + self.purse.get_weight()
+    }
+
+    #[must_use]
+    pub fn get_purse(&self) -> &Purse {
+        &self.purse
+    }
+
+    pub fn add_coins(&mut self, coin: Coin, amount: usize) {
+        self.purse.add(coin, amount);
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `Currency`: if the purse is worth less than `price`
+    ///
+    pub fn pay(&mut self, price: Price) -> CharacterResult<()> {
+        self.purse.pay(price)?;
+
+        Ok(())
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `Currency`: if the purse holds fewer than `amount` of `from`
+    ///
+    pub fn convert_coins(&mut self, from: Coin, to: Coin, amount: usize) -> CharacterResult<()> {
+        self.purse.convert(from, to, amount)?;
+
+        Ok(())
+    }
+
+    /// Strength score scaled by the size multiplier, the base of every carrying rule.
+    fn get_scaled_strength(&self) -> f64 {
+        self.get_ability_score(ability::Identifier::Strength) as f64
+            * self.get_size().get_carrying_capacity_multiplier()
+    }
+
+    /// Standard rule: Strength × 15 pounds, adjusted for size.
+    #[must_use]
+    pub fn get_carrying_capacity(&self) -> f64 {
+        15.0 * self.get_scaled_strength()
+    }
+
+    /// Twice the carrying capacity. Carrying more than the carrying capacity, up to this
+    /// limit, drops walking speed to 5 feet.
+    #[must_use]
+    pub fn get_push_drag_lift_capacity(&self) -> f64 {
+        2.0 * self.get_carrying_capacity()
+    }
+
+    #[must_use]
+    pub fn is_over_carrying_capacity(&self) -> bool {
+        self.get_total_weight_carried() > self.get_carrying_capacity()
+    }
+
+    #[must_use]
+    pub fn get_encumbrance_rule(&self) -> EncumbranceRule {
+        self.encumbrance_rule
+    }
+
+    pub fn set_encumbrance_rule(&mut self, rule: EncumbranceRule) {
+        self.encumbrance_rule = rule;
+    }
+
+    #[must_use]
+    pub fn get_variant_encumbrance(&self) -> Option<Encumbrance> {
+        let total_weight_carried = self.get_total_weight_carried();
+        let scaled_strength = self.get_scaled_strength();
+
+        if total_weight_carried > (10.0 * scaled_strength) {
+            Some(Encumbrance::HeavilyEncumbered)
+        } else if total_weight_carried > (5.0 * scaled_strength) {
+            Some(Encumbrance::Encumbered)
+        } else {
+            None
+        }
+    }
+
+    pub fn add_item(&mut self, item: Item) {
+        self.inventory.add_item(item);
+    }
+
+    #[must_use]
+    pub fn get_exhaustion_level(&self) -> usize {
+        self.exhaustion_level
+    }
+
+    pub fn set_exhaustion_level(&mut self, new_level: usize) {
+        self.exhaustion_level = new_level;
+    }
+
+    pub fn get_skill_proficiency(&self, skill: skills::Identifier) -> Option<Proficiency> {
+        self.get_check_proficiency(CheckKind::Skill(skill))
+    }
+
+    #[must_use]
+    pub fn get_skill_modifier(&self, skill: skills::Identifier) -> isize {
+        self.get_check_breakdown(CheckKind::Skill(skill))
+            .get_total()
+    }
+
+    #[must_use]
+    pub fn get_passive_perception(&self) -> usize {
+        self.senses
+            .get_passive_perception(self.get_skill_modifier(skills::Identifier::Perception))
+    }
+
+    #[must_use]
+    pub fn get_passive_investigation(&self) -> usize {
+        self.senses
+            .get_passive_perception(self.get_skill_modifier(skills::Identifier::Investigation))
+    }
+
+    #[must_use]
+    pub fn get_passive_insight(&self) -> usize {
+        self.senses
+            .get_passive_insight(self.get_skill_modifier(skills::Identifier::Insight))
+    }
+
+    pub fn get_darkvision(&self) -> Option<usize> {
+        self.senses.get_darkvision()
+    }
+
+    pub fn add_class(&mut self, class: Class) {
+        self.classes.add_class(class);
+    }
+
+    #[must_use]
+    pub fn get_background(&self) -> Option<&Background> {
+        self.background.as_ref()
+    }
+
+    /// Swaps the background, dropping the picks made for the old one.
+    pub fn change_background(&mut self, background: Background) {
+        self.background = Some(background);
+        self.background_selections = background::Selections::default();
+    }
+
+    #[must_use]
+    pub fn get_background_selections(&self) -> &background::Selections {
+        &self.background_selections
+    }
+
+    /// Background options still waiting for the player's picks.
+    #[must_use]
+    pub fn get_open_background_choices(&self) -> Vec<usize> {
+        self.background
+            .as_ref()
+            .map(|background| {
+                background
+                    .get_proficiencies()
+                    .get_open_choices(&self.background_selections)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Picks proficiencies for one of the background's options, replacing earlier picks.
+    ///
+    /// # Errors
+    ///
+    /// - `NoBackground`: the character has no background
+    /// - `BackgroundGrant`: the picks don't fit the option, or the character already has one
+    ///   of them from another source
+    ///
+    pub fn choose_background_proficiencies(
+        &mut self,
+        option: usize,
+        picks: Vec<Grant>,
+    ) -> CharacterResult<()> {
+        let background = self.background.as_ref().ok_or(Error::NoBackground)?;
+
+        if let Some(pick) = picks
+            .iter()
+            .find(|pick| self.has_proficiency_outside_background(pick))
+        {
+            return Err(GrantError::AlreadyKnown(pick.clone()).into());
+        }
+
+        self.background_selections.pick(background, option, picks)?;
+
+        Ok(())
+    }
+
+    /// Swaps a background grant the character already has from another source, such as a
+    /// class skill, for a different proficiency of the same kind.
+    ///
+    /// # Errors
+    ///
+    /// - `NoBackground`: the character has no background
+    /// - `BackgroundGrant`: `duplicate` isn't granted elsewhere, the replacement is already
+    ///   known, or the swap doesn't fit the background
+    ///
+    pub fn replace_background_proficiency(
+        &mut self,
+        duplicate: Grant,
+        replacement: Grant,
+    ) -> CharacterResult<()> {
+        let background = self.background.as_ref().ok_or(Error::NoBackground)?;
+
+        if !self.has_proficiency_outside_background(&duplicate) {
+            return Err(GrantError::NotDuplicated(duplicate).into());
+        }
+
+        if self.has_proficiency_outside_background(&replacement) {
+            return Err(GrantError::AlreadyKnown(replacement).into());
+        }
+
+        self.background_selections
+            .replace(background, duplicate, replacement)?;
+
+        Ok(())
+    }
+
+    fn has_proficiency_outside_background(&self, grant: &Grant) -> bool {
+        match grant {
+            Grant::Skill(skill) => {
+                self.skills.get_proficiency(*skill).is_some()
+                    || self
+                        .race
+                        .get_effects(&self.race_selections)
+                        .iter()
+                        .any(|effect| effect.get_target() == Target::Skill(*skill))
             }
+            Grant::Tool(tool) => self
+                .get_own_proficiencies()
+                .get_tool_proficiency(tool)
+                .is_some(),
+            Grant::Language(language) => self.get_languages_outside_background().contains(language),
+        }
+    }
+
+    /// Spreads the background's ability score increases, replacing any earlier choice.
+    ///
+    /// # Errors
+    ///
+    /// - `NoBackground`: the character has no background
+    /// - `BackgroundAbilityScores`: the increase doesn't fit the background
+    ///
+    pub fn choose_background_ability_scores(
+        &mut self,
+        increase: AbilityScoreIncrease,
+    ) -> CharacterResult<()> {
+        let background = self.background.as_ref().ok_or(Error::NoBackground)?;
+
+        self.background_selections
+            .choose_ability_scores(background, increase)?;
+
+        Ok(())
+    }
+
+    /// Swaps the race, dropping selections made for the old one.
+    pub fn change_race(&mut self, race: Race) {
+        self.race = race;
+        self.race_selections.clear();
+    }
+
+    #[must_use]
+    pub fn get_race_selections(&self) -> &race::Selections {
+        &self.race_selections
+    }
+
+    /// Picks an option for one of the race's choices, replacing any earlier pick.
+    ///
+    /// # Errors
+    ///
+    /// - `RaceChoice`: the selection doesn't fit the race's choice
+    ///
+    pub fn select_race_option(
+        &mut self,
+        choice: usize,
+        selection: race::Selection,
+    ) -> CharacterResult<()> {
+        self.race_selections.select(&self.race, choice, selection)?;
+
+        Ok(())
+    }
+
+    /// Moves a fixed racial ability bonus to another ability.
+    ///
+    /// # Errors
+    ///
+    /// - `RaceChoice`: the race has no bonus to move, or two bonuses would stack
+    ///
+    pub fn reassign_racial_bonus(
+        &mut self,
+        from: ability::Identifier,
+        to: ability::Identifier,
+    ) -> CharacterResult<()> {
+        self.race_selections.reassign(&self.race, from, to)?;
+
+        Ok(())
+    }
+
+    /// Cantrips picked through racial choices.
+    #[must_use]
+    pub fn get_racial_cantrips(&self) -> Vec<&str> {
+        self.race_selections
+            .get_selections()
+            .filter_map(|selection| match selection {
+                race::Selection::Cantrip(cantrip) => Some(cantrip.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// # Errors
+    ///
+    /// - `ClassNotFound`: the character has no class named `class_name`
+    /// - `Improvement`: the class can't take an improvement at `level`
+    /// - `Feat`: the character can't take the feat chosen instead
+    ///
+    pub fn add_ability_score_improvement(
+        &mut self,
+        class_name: &str,
+        level: usize,
+        improvement: AbilityScoreImprovement,
+    ) -> CharacterResult<()> {
+        if let AbilityScoreImprovement::Feat(feat) = &improvement {
+            self.can_take_feat(feat)?;
         }
+
+        self.classes
+            .get_class_mut(class_name)
+            .ok_or_else(|| Error::ClassNotFound(class_name.to_owned()))?
+            .add_ability_score_improvement(level, improvement)?;
+
+        Ok(())
+    }
+
+    /// Grants a feat through a class feature, e.g. a Variant Human's bonus feat.
+    ///
+    /// # Errors
+    ///
+    /// - `Feat`: the character doesn't meet the feat's prerequisites, or a half-feat's ability
+    ///   hasn't been picked
+    /// - `ClassNotFound`: the character has no class named `class_name`
+    ///
+    pub fn add_class_feat(&mut self, class_name: &str, feat: Feat) -> CharacterResult<()> {
+        self.can_take_feat(&feat)?;
+
+        self.classes
+            .get_class_mut(class_name)
+            .ok_or_else(|| Error::ClassNotFound(class_name.to_owned()))?
+            .add_feat(feat)?;
+
+        Ok(())
+    }
+
+    /// Grants a feat through the character's race.
+    ///
+    /// # Errors
+    ///
+    /// - `Feat`: the character doesn't meet the feat's prerequisites, or a half-feat's ability
+    ///   hasn't been picked
+    ///
+    pub fn add_race_feat(&mut self, feat: Feat) -> CharacterResult<()> {
+        self.can_take_feat(&feat)?;
+
+        self.race.add_feat(feat)?;
+
+        Ok(())
+    }
+
+    /// # Errors
+    ///
+    /// - `ClassNotFound`: the character has no class named `class_name`
+    /// - `Subclass`: the class is below its subclass level or already has a subclass
+    ///
+    pub fn set_subclass(&mut self, class_name: &str, subclass: Subclass) -> CharacterResult<()> {
+        self.classes
+            .get_class_mut(class_name)
+            .ok_or_else(|| Error::ClassNotFound(class_name.to_owned()))?
+            .set_subclass(subclass)?;
+
+        Ok(())
+    }
+
+    /// Names of the spells the character's subclasses keep prepared.
+    pub fn get_always_prepared_spells(&self) -> Vec<&str> {
+        self.classes.get_always_prepared_spells()
+    }
+
+    fn get_artificer_level(&self) -> usize {
+        self.classes
+            .get_class("Artificer")
+            .map_or(0, Class::get_level)
+    }
+
+    #[must_use]
+    pub fn get_infusions(&self) -> &Infusions {
+        &self.infusions
+    }
+
+    /// # Errors
+    ///
+    /// - `Infusion`: the artificer level is too low for it or for another infusion, or it is
+    ///   already known
+    ///
+    pub fn learn_infusion(&mut self, infusion: Infusion) -> CharacterResult<()> {
+        let level = self.get_artificer_level();
+
+        Ok(self.infusions.learn(infusion, level)?)
+    }
+
+    /// Owned items holding an infusion, wherever they are, oldest first.
+    #[must_use]
+    pub fn get_infused_items(&self) -> Vec<&Item> {
+        let mut items: Vec<&Item> = self
+            .get_owned_items()
+            .into_iter()
+            .filter(|item| item.get_infusion().is_some())
+            .collect();
+        items.sort_by_key(|item| item.get_infusion_order());
+
+        items
+    }
+
+    /// Plans to infuse an owned item when the character next finishes a long rest.
+    /// Of several items with the same name, one not yet infused is picked then. If that goes past
+    /// the number of infused items allowed, the oldest infusion ends.
+    ///
+    /// # Errors
+    ///
+    /// - `ItemNotFound`: the character has no item called `item_name`
+    /// - `Infusion`: the infusion isn't known or doesn't fit the item
+    ///
+    pub fn infuse_item(&mut self, infusion_name: &str, item_name: &str) -> CharacterResult<()> {
+        let item = self
+            .get_owned_items()
+            .into_iter()
+            .find(|item| item.get_name() == item_name)
+            .ok_or_else(|| Error::ItemNotFound(item_name.to_owned()))?;
+
+        self.infusions
+            .check(infusion_name, item, self.get_artificer_level())?;
+        self.infusions.plan(InfusionChange::Infuse {
+            infusion: infusion_name.to_owned(),
+            item: item_name.to_owned(),
+        });
+
+        Ok(())
+    }
+
+    /// Plans to end the infusion on `item_name` when the character next finishes a long rest.
+    ///
+    /// # Errors
+    ///
+    /// - `Infusion`: no owned item called `item_name` is infused
+    ///
+    pub fn end_infusion(&mut self, item_name: &str) -> CharacterResult<()> {
+        if !self
+            .get_infused_items()
+            .iter()
+            .any(|item| item.get_name() == item_name)
+        {
+            return Err(InfusionError::NotInfused(item_name.to_owned()).into());
+        }
+
+        self.infusions.plan(InfusionChange::End {
+            item: item_name.to_owned(),
+        });
+
+        Ok(())
+    }
+
+    /// Drops the infusion changes planned for the next long rest.
+    pub fn cancel_planned_infusions(&mut self) {
+        self.infusions.cancel_planned();
+    }
+
+    fn put_infusion(&mut self, infusion_name: &str, item_name: &str) -> CharacterResult<()> {
+        let level = self.get_artificer_level();
+        let infused = !self
+            .get_owned_items()
+            .iter()
+            .any(|item| item.get_name() == item_name && item.get_infusion().is_none());
+        // Held apart while the item is borrowed from the rest of the character.
+        let mut infusions = std::mem::take(&mut self.infusions);
+        let result = self
+            .find_owned_item_mut(|item| {
+                item.get_name() == item_name && item.get_infusion().is_some() == infused
+            })
+            .ok_or_else(|| Error::ItemNotFound(item_name.to_owned()))
+            .and_then(|item| {
+                infusions
+                    .infuse(infusion_name, item, level)
+                    .map_err(Error::from)
+            });
+
+        self.infusions = infusions;
+        result?;
+
+        let infused_items = self.get_infused_items();
+
+        if infused_items.len() > artificer::infused_items(level) {
+            let oldest = infused_items[0].get_infusion_order();
+
+            if let Some(item) = self.find_owned_item_mut(|item| item.get_infusion_order() == oldest)
+            {
+                item.end_infusion();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn take_infusion(&mut self, item_name: &str) -> CharacterResult<String> {
+        self.find_owned_item_mut(|item| {
+            item.get_name() == item_name && item.get_infusion().is_some()
+        })
+        .and_then(Item::end_infusion)
+        .ok_or_else(|| InfusionError::NotInfused(item_name.to_owned()).into())
+    }
+
+    /// Makes the infusion changes planned for the long rest, in order. A change the items no
+    /// longer allow, e.g. for an item dropped since, is skipped.
+    fn change_infusions(&mut self) {
+        for change in self.infusions.take_planned() {
+            let _ = match change {
+                InfusionChange::Infuse { infusion, item } => self.put_infusion(&infusion, &item),
+                InfusionChange::End { item } => self.take_infusion(&item).map(|_| ()),
+            };
+        }
+    }
+
+    /// Every class feature the character has reached the level for, with its class.
+    pub fn get_features(&self) -> Vec<(&Class, &Feature)> {
+        self.classes.get_features()
+    }
+
+    /// How many times `feature` from `class` can be used between rests, or `None` if it isn't
+    /// limited.
+    pub fn get_feature_maximum_uses(&self, class: &Class, feature: &Feature) -> Option<usize> {
+        feature.get_uses().map(|uses| {
+            uses.get_count().get_maximum(
+                class.get_level(),
+                self.get_proficiency_bonus(),
+                |ability| self.get_ability_modifier(ability),
+            )
+        })
+    }
+
+    /// Uses of `feature` from `class` left until it recharges.
+    pub fn get_feature_remaining_uses(&self, class: &Class, feature: &Feature) -> Option<usize> {
+        let spent = feature.get_uses()?.get_spent();
+
+        self.get_feature_maximum_uses(class, feature)
+            .map(|maximum| maximum.saturating_sub(spent))
+    }
+
+    /// Spends a use of the feature named `name`, returning the uses left.
+    ///
+    /// # Errors
+    ///
+    /// - `Feature`: no unlocked feature has that name, it isn't limited or it has no uses left
+    ///
+    pub fn spend_feature_use(&mut self, name: &str) -> CharacterResult<usize> {
+        let (class_name, maximum) = self
+            .get_features()
+            .into_iter()
+            .find(|(_, feature)| feature.get_name().eq_ignore_ascii_case(name))
+            .map(|(class, feature)| {
+                (
+                    class.get_name().to_owned(),
+                    self.get_feature_maximum_uses(class, feature),
+                )
+            })
+            .ok_or_else(|| FeatureError::NotFound(name.to_owned()))?;
+
+        let feature = self
+            .classes
+            .get_class_mut(&class_name)
+            .and_then(|class| class.get_feature_mut(name))
+            .ok_or_else(|| FeatureError::NotFound(name.to_owned()))?;
+
+        Ok(feature.spend(maximum.unwrap_or(0))?)
+    }
+
+    /// Regains the uses of every feature that recharges on `rest`. A long rest also restores
+    /// every psi point and makes the planned infusion changes.
+    pub fn finish_rest(&mut self, rest: Rest) {
+        self.classes.recharge_features(rest);
+
+        if let (Rest::Long, Some(mystic)) = (rest, self.psionics.as_mut()) {
+            mystic.reset_psi_points();
+        }
+
+        if rest == Rest::Long {
+            self.change_infusions();
+        }
+    }
+
+    #[must_use]
+    pub fn get_psionics(&self) -> Option<&Mystic> {
+        self.psionics.as_ref()
+    }
+
+    /// # Errors
+    ///
+    /// - `NoPsionics`: the character has no psionic powers
+    /// - `DisciplineNotFound`: no known discipline has that name
+    ///
+    pub fn focus_on(&mut self, discipline_name: &str) -> CharacterResult<()> {
+        let mystic = self.psionics.as_mut().ok_or(Error::NoPsionics)?;
+        let discipline = mystic
+            .get_discipline(discipline_name)
+            .cloned()
+            .ok_or_else(|| Error::DisciplineNotFound(discipline_name.to_owned()))?;
+
+        mystic.focus_on(&discipline);
+
+        Ok(())
+    }
+
+    /// What performing an act with `charge_mod` extra psi points would cost, without spending
+    /// anything.
+    ///
+    /// # Errors
+    ///
+    /// - `NoPsionics`: the character has no psionic powers
+    /// - `Act`: no known discipline has the act, or it can't take that many points
+    ///
+    pub fn preview_act(&self, act_name: &str, charge_mod: usize) -> CharacterResult<ActPreview> {
+        let mystic = self.psionics.as_ref().ok_or(Error::NoPsionics)?;
+        let act = mystic.get_act(act_name).ok_or(ActError::ActNotPracticed)?;
+
+        Ok(mystic.preview_act(act, charge_mod)?)
+    }
+
+    /// Performs an act from a known discipline, spending its cost plus `charge_mod` extra psi
+    /// points. Returns the act's damage, save and duration; an act that lasts starts
+    /// concentration.
+    ///
+    /// # Errors
+    ///
+    /// - `NoPsionics`: the character has no psionic powers
+    /// - `Act`: no known discipline has the act, or the psi points don't cover it
+    ///
+    pub fn perform_act(
+        &mut self,
+        act_name: &str,
+        charge_mod: usize,
+    ) -> CharacterResult<ResolvedAct> {
+        let mystic = self.psionics.as_mut().ok_or(Error::NoPsionics)?;
+        let act = mystic
+            .get_act(act_name)
+            .cloned()
+            .ok_or(ActError::ActNotPracticed)?;
+
+        let resolved = mystic.perform_act(&act, charge_mod)?;
+
+        if let Some(duration) = resolved.get_duration() {
+            if resolved.needs_concentration() {
+                self.start_concentration(Concentration::new(resolved.get_name(), duration));
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    pub fn add_equipment_slot(&mut self, slot_name: impl Into<String>, rule: SlotRule) {
+        self.equipment.add_slot(slot_name, rule);
+    }
+
+    #[must_use]
+    pub fn get_slot_layout(&self) -> &SlotLayout {
+        self.equipment.get_layout()
+    }
+
+    /// Swaps in a new set of equipment slots. Anything equipped goes back into the inventory.
+    pub fn change_slot_layout(&mut self, layout: SlotLayout) {
+        for item in self.equipment.unequip_all() {
+            self.inventory.add_item(item);
+        }
+
+        self.equipment = ItemSlots::from(layout);
+    }
+
+    fn check_not_already_wearing(&self, item: &Item) -> CharacterResult<()> {
+        let worn = self
+            .equipment
+            .get_equipped_items()
+            .into_iter()
+            .find(|worn| {
+                (item.is_body_armor() && worn.is_body_armor())
+                    || (item.is_shield() && worn.is_shield())
+            });
+
+        match worn {
+            Some(worn) => Err(Error::AlreadyWearing(worn.get_name().to_owned())),
+            None => Ok(()),
+        }
+    }
+
+    pub fn equip_item(&mut self, item: Item, slot_name: impl Into<String>) -> CharacterResult<()> {
+        self.check_not_already_wearing(&item)?;
+
+        self.equipment.equip(item, slot_name)?;
+
+        Ok(())
+    }
+
+    /// Equips an item that takes up several slots at once, e.g. a greatsword in both hands.
+    ///
+    /// # Errors
+    ///
+    /// - `AlreadyWearing`: if the item is body armor or a shield and one is already worn
+    /// - `Equipment`: naming the slot that is missing, occupied or can't hold the item
+    ///
+    pub fn equip_item_across(&mut self, item: Item, slot_names: &[&str]) -> CharacterResult<()> {
+        self.check_not_already_wearing(&item)?;
+
+        self.equipment.equip_across(item, slot_names)?;
+
+        Ok(())
+    }
+
+    /// Switches a versatile weapon between one and two hands, claiming or releasing a second
+    /// slot as needed. The weapon stays as it was if the switch fails.
+    ///
+    /// # Errors
+    ///
+    /// - `Equipment`: if `slot_name` is empty, or naming the slot blocking a two-handed grip
+    /// - `Grip`: if the item isn't a versatile weapon
+    ///
+    pub fn set_grip(&mut self, slot_name: &str, grip: Grip) -> CharacterResult<()> {
+        let held: Vec<String> = self
+            .equipment
+            .get_occupied_slots(slot_name)
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect();
+        let held: Vec<&str> = held.iter().map(String::as_str).collect();
+
+        let mut item = self.equipment.unequip(slot_name)?;
+        let previous_grip = item.get_weapon().map_or(Grip::OneHanded, Weapon::get_grip);
+
+        let result = item.set_grip(grip).map_err(Error::from).and_then(|()| {
+            let holder = held.first().copied().unwrap_or(slot_name);
+            let slot_names: Vec<&str> = [holder]
+                .into_iter()
+                .chain(
+                    self.equipment
+                        .get_slots_accepting(&item)
+                        .into_iter()
+                        .filter(|&name| name != holder)
+                        .take(item.get_slot_count().saturating_sub(1)),
+                )
+                .collect();
+
+            self.equipment
+                .check_can_equip(&item, &slot_names)
+                .map(|()| {
+                    slot_names
+                        .into_iter()
+                        .map(ToOwned::to_owned)
+                        .collect::<Vec<String>>()
+                })
+                .map_err(Error::from)
+        });
+
+        match result {
+            Ok(slot_names) => {
+                let slot_names: Vec<&str> = slot_names.iter().map(String::as_str).collect();
+                self.equipment.equip_across(item, &slot_names)?;
+
+                Ok(())
+            }
+            Err(error) => {
+                let _ = item.set_grip(previous_grip);
+                self.equipment.equip_across(item, &held)?;
+
+                Err(error)
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn get_inventory(&self) -> &Items {
+        &self.inventory
+    }
+
+    #[must_use]
+    pub fn get_equipment(&self) -> &ItemSlots {
+        &self.equipment
+    }
+
+    /// Moves an item from the inventory into the first free slots that accept it.
+    ///
+    /// # Errors
+    ///
+    /// - `ItemNotFound`: if the inventory has no item called `name`
+    /// - `AlreadyWearing`: if the item is body armor or a shield and one is already worn
+    /// - `Equipment`: naming the occupied slot, if only some of the slots the item needs are free
+    /// - `NoFreeSlot`: if no empty slot accepts the item
+    ///
+    pub fn equip_from_inventory(&mut self, name: &str) -> CharacterResult<()> {
+        let item = self
+            .inventory
+            .find(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+
+        self.check_not_already_wearing(item)?;
+
+        let Some(slot_names) = self.equipment.find_free_slots(item) else {
+            let accepting = self.equipment.get_slots_accepting(item);
+            let any_free = accepting
+                .iter()
+                .any(|&slot_name| self.equipment.get_item_in(slot_name).is_none());
+
+            if any_free && accepting.len() >= item.get_slot_count() {
+                self.equipment
+                    .check_can_equip(item, &accepting[..item.get_slot_count()])?;
+            }
+
+            return Err(Error::NoFreeSlot(name.to_owned()));
+        };
+        let slot_names: Vec<String> = slot_names.into_iter().map(ToOwned::to_owned).collect();
+        let slot_names: Vec<&str> = slot_names.iter().map(String::as_str).collect();
+
+        let item = self
+            .inventory
+            .remove_item(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+        self.equipment.equip_across(item, &slot_names)?;
+
+        Ok(())
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `Equipment`: if the slot doesn't exist or is empty
+    ///
+    pub fn unequip_to_inventory(&mut self, slot_name: &str) -> CharacterResult<()> {
+        let item = self.equipment.unequip(slot_name)?;
+        self.inventory.add_item(item);
+
+        Ok(())
+    }
+
+    /// Removes an item from the character entirely, ending any attunement to it or infusion in
+    /// it. Carried items are dropped before equipped ones of the same name.
+    ///
+    /// # Errors
+    ///
+    /// - `ItemNotFound`: if the character has no item called `name`
+    ///
+    pub fn drop_item(&mut self, name: &str) -> CharacterResult<Item> {
+        let Some(mut item) = self.inventory.remove_item(name) else {
+            let slot_name = self
+                .equipment
+                .get_equipped_items_by_slot()
+                .into_iter()
+                .find(|(_, item)| item.get_name() == name)
+                .map(|(slot_name, _)| slot_name.to_owned())
+                .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+
+            return self.drop_equipped(&slot_name);
+        };
+
+        if item.is_attuned() {
+            item.unattune()?;
+        }
+
+        item.end_infusion();
+
+        Ok(item)
+    }
+
+    /// Removes the item equipped in `slot_name` from the character entirely, ending any
+    /// attunement to it or infusion in it.
+    ///
+    /// # Errors
+    ///
+    /// - `Equipment`: if there is no such slot or nothing is equipped in it
+    ///
+    pub fn drop_equipped(&mut self, slot_name: &str) -> CharacterResult<Item> {
+        let mut item = self.equipment.unequip(slot_name)?;
+
+        if item.is_attuned() {
+            item.unattune()?;
+        }
+
+        item.end_infusion();
+
+        Ok(item)
+    }
+
+    /// Looks through both the inventory and equipped items.
+    #[must_use]
+    pub fn find_item(&self, name: &str) -> Option<&Item> {
+        self.inventory
+            .iter()
+            .chain(self.equipment.get_equipped_items())
+            .find(|item| item.get_name() == name)
+    }
+
+    fn find_item_mut(&mut self, name: &str) -> Option<&mut Item> {
+        self.inventory
+            .iter_mut()
+            .chain(self.equipment.get_equipped_items_mut())
+            .find(|item| item.get_name() == name)
+    }
+
+    /// Every item the character owns: carried, equipped or loaded onto a mount, each followed
+    /// by whatever it holds, however deep.
+    #[must_use]
+    pub fn get_owned_items(&self) -> Vec<&Item> {
+        with_contents(
+            self.inventory
+                .iter()
+                .chain(self.equipment.get_equipped_items())
+                .chain(
+                    self.mounts
+                        .iter()
+                        .flat_map(|mount| mount.get_inventory().iter()),
+                ),
+        )
+    }
+
+    /// The first owned item matching `predicate`, in the order of `get_owned_items`.
+    fn find_owned_item_mut(&mut self, predicate: impl Fn(&Item) -> bool) -> Option<&mut Item> {
+        let mut index = self.get_owned_items().into_iter().position(predicate)?;
+
+        nth_with_contents_mut(
+            self.inventory
+                .iter_mut()
+                .chain(self.equipment.get_equipped_items_mut())
+                .chain(
+                    self.mounts
+                        .iter_mut()
+                        .flat_map(|mount| mount.get_inventory_mut().iter_mut()),
+                ),
+            &mut index,
+        )
+    }
+
+    /// Carried and equipped items that can hold other items, ordered by name.
+    #[must_use]
+    pub fn get_containers(&self) -> Vec<&Item> {
+        let mut containers: Vec<&Item> = self
+            .inventory
+            .iter()
+            .chain(self.equipment.get_equipped_items())
+            .filter(|item| item.is_container())
+            .collect();
+        containers.sort_by_key(|item| item.get_name());
+
+        containers
+    }
+
+    /// Moves an item from the inventory into a carried or equipped container.
+    ///
+    /// # Errors
+    ///
+    /// - `ItemNotFound`: if there is no such item in the inventory, or no such container
+    /// - `Container`: if `container_name` can't hold items or has no room for the item
+    ///
+    pub fn store_item(&mut self, name: &str, container_name: &str) -> CharacterResult<()> {
+        if name == container_name {
+            return Err(container::Error::CannotContainItself.into());
+        }
+
+        let item = self
+            .inventory
+            .find(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+
+        self.find_item(container_name)
+            .ok_or_else(|| Error::ItemNotFound(container_name.to_owned()))?
+            .get_container()
+            .ok_or_else(|| container::Error::NotAContainer(container_name.to_owned()))?
+            .check_can_hold(item)?;
+
+        let item = self
+            .inventory
+            .remove_item(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+
+        self.find_item_mut(container_name)
+            .and_then(Item::get_container_mut)
+            .ok_or_else(|| container::Error::NotAContainer(container_name.to_owned()))?
+            .store(item)?;
+
+        Ok(())
+    }
+
+    /// Moves an item out of a container and back into the inventory.
+    ///
+    /// # Errors
+    ///
+    /// - `ItemNotFound`: if there is no such container, or it doesn't hold the item
+    /// - `Container`: if `container_name` can't hold items
+    ///
+    pub fn retrieve_item(&mut self, name: &str, container_name: &str) -> CharacterResult<()> {
+        let item = self.take_from_container(name, container_name)?;
+
+        self.inventory.add_item(item);
+
+        Ok(())
+    }
+
+    fn take_from_container(&mut self, name: &str, container_name: &str) -> CharacterResult<Item> {
+        self.find_item_mut(container_name)
+            .ok_or_else(|| Error::ItemNotFound(container_name.to_owned()))?
+            .get_container_mut()
+            .ok_or_else(|| container::Error::NotAContainer(container_name.to_owned()))?
+            .remove_item(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))
+    }
+
+    /// Moves an item straight from one container into another, leaving anything of the same
+    /// name in the inventory alone.
+    ///
+    /// # Errors
+    ///
+    /// - `ItemNotFound`: if either container is missing, or `from` doesn't hold the item
+    /// - `Container`: if either can't hold items, or `to` has no room for the item
+    ///
+    pub fn transfer_item(&mut self, name: &str, from: &str, to: &str) -> CharacterResult<()> {
+        if name == to {
+            return Err(container::Error::CannotContainItself.into());
+        }
+
+        let item = self
+            .find_item(from)
+            .ok_or_else(|| Error::ItemNotFound(from.to_owned()))?
+            .get_container()
+            .ok_or_else(|| container::Error::NotAContainer(from.to_owned()))?
+            .get_contents()
+            .find(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+
+        self.find_item(to)
+            .ok_or_else(|| Error::ItemNotFound(to.to_owned()))?
+            .get_container()
+            .ok_or_else(|| container::Error::NotAContainer(to.to_owned()))?
+            .check_can_hold(item)?;
+
+        let item = self.take_from_container(name, from)?;
+
+        self.find_item_mut(to)
+            .and_then(Item::get_container_mut)
+            .ok_or_else(|| container::Error::NotAContainer(to.to_owned()))?
+            .store(item)?;
+
+        Ok(())
+    }
+
+    /// Removes an item held in a container from the character entirely, leaving anything of the
+    /// same name in the inventory alone.
+    ///
+    /// # Errors
+    ///
+    /// - `ItemNotFound`: if there is no such container, or it doesn't hold the item
+    /// - `Container`: if `container_name` can't hold items
+    ///
+    pub fn drop_from_container(
+        &mut self,
+        name: &str,
+        container_name: &str,
+    ) -> CharacterResult<Item> {
+        let mut item = self.take_from_container(name, container_name)?;
+
+        if item.is_attuned() {
+            item.unattune()?;
+        }
+
+        item.end_infusion();
+
+        Ok(item)
+    }
+
+    #[must_use]
+    pub fn get_mounts(&self) -> &[Mount] {
+        &self.mounts
+    }
+
+    pub fn add_mount(&mut self, mount: Mount) {
+        self.mounts.push(mount);
+    }
+
+    fn find_mount_mut(&mut self, mount_name: &str) -> CharacterResult<&mut Mount> {
+        self.mounts
+            .iter_mut()
+            .find(|mount| mount.get_name() == mount_name)
+            .ok_or_else(|| Error::MountNotFound(mount_name.to_owned()))
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `MountNotFound`: if the character has no mount called `mount_name`
+    /// - `ItemNotFound`: if there is no such item in the inventory
+    ///
+    pub fn load_onto_mount(&mut self, name: &str, mount_name: &str) -> CharacterResult<()> {
+        self.find_mount_mut(mount_name)?;
+
+        let item = self
+            .inventory
+            .remove_item(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+
+        self.find_mount_mut(mount_name)?.add_item(item);
+
+        Ok(())
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `MountNotFound`: if the character has no mount called `mount_name`
+    /// - `ItemNotFound`: if the mount isn't carrying the item
+    ///
+    pub fn unload_from_mount(&mut self, name: &str, mount_name: &str) -> CharacterResult<()> {
+        let item = self
+            .find_mount_mut(mount_name)?
+            .remove_item(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+
+        self.inventory.add_item(item);
+
+        Ok(())
+    }
+
+    /// Removes an item a mount carries from the character entirely, ending any attunement to
+    /// it or infusion in it.
+    ///
+    /// # Errors
+    ///
+    /// - `MountNotFound`: if the character has no mount called `mount_name`
+    /// - `ItemNotFound`: if the mount isn't carrying the item
+    ///
+    pub fn drop_from_mount(&mut self, name: &str, mount_name: &str) -> CharacterResult<Item> {
+        let mut item = self
+            .find_mount_mut(mount_name)?
+            .remove_item(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+
+        if item.is_attuned() {
+            item.unattune()?;
+        }
+
+        item.end_infusion();
+
+        Ok(item)
+    }
+
+    #[must_use]
+    pub fn get_attuned_items(&self) -> Vec<&Item> {
+        self.get_owned_items()
+            .into_iter()
+            .filter(|item| item.is_attuned())
+            .collect()
+    }
+
+    fn meets_prerequisite(&self, prerequisite: &Prerequisite) -> bool {
+        match prerequisite {
+            Prerequisite::Class(name) => self.classes.has_class(name),
+            Prerequisite::Race(name) => self.race.get_name().eq_ignore_ascii_case(name),
+        }
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `ItemNotFound`: if the character is not carrying an item called `name`
+    /// - `Attunement`: if the item doesn't need attunement, is already attuned, its prerequisites
+    ///   aren't met, or the character is already attuned to three items
+    ///
+    pub fn attune_item(&mut self, name: &str) -> CharacterResult<()> {
+        let item = self
+            .find_item(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?;
+
+        if let Some(attunement) = item.get_attunement().filter(|_| !item.is_attuned()) {
+            let prerequisites = attunement.get_prerequisites();
+
+            if !prerequisites.is_empty()
+                && !prerequisites
+                    .iter()
+                    .any(|prerequisite| self.meets_prerequisite(prerequisite))
+            {
+                return Err(AttunementError::PrerequisiteNotMet(attunement.clone()).into());
+            }
+
+            if self.get_attuned_items().len() >= MAX_ATTUNED_ITEMS {
+                return Err(AttunementError::LimitReached.into());
+            }
+        }
+
+        self.find_item_mut(name)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?
+            .attune()?;
+
+        Ok(())
+    }
+
+    /// Ends attunement to an item called `name` wherever it is, e.g. stored in a container.
+    ///
+    /// # Errors
+    ///
+    /// - `ItemNotFound`: if the character owns no item called `name`
+    /// - `Attunement`: if the character is not attuned to the item
+    ///
+    pub fn unattune_item(&mut self, name: &str) -> CharacterResult<()> {
+        let attuned = self
+            .get_attuned_items()
+            .iter()
+            .any(|item| item.get_name() == name);
+
+        self.find_owned_item_mut(|item| item.get_name() == name && item.is_attuned() == attuned)
+            .ok_or_else(|| Error::ItemNotFound(name.to_owned()))?
+            .unattune()?;
+
+        Ok(())
+    }
+
+    pub fn has_item_equipped_matching_criteria(&self, item_criteria: fn(&Item) -> bool) -> bool {
+        self.equipment
+            .has_item_equipped_matching_criteria(item_criteria)
+    }
+
+    /// Every feat with where it came from: classes, Ability Score Improvements, then race.
+    pub fn get_feats(&self) -> Vec<(feat::Source, &Feat)> {
+        self.classes
+            .get_feats()
+            .into_iter()
+            .chain(
+                self.race
+                    .get_feats()
+                    .into_iter()
+                    .map(|feat| (feat::Source::Race(self.race.get_name().to_owned()), feat)),
+            )
+            .collect()
+    }
+
+    pub fn meets_feat_prerequisite(&self, prerequisite: &feat::Prerequisite) -> bool {
+        match prerequisite {
+            feat::Prerequisite::AbilityScore(ability, minimum) => {
+                self.get_ability_score(*ability) >= *minimum
+            }
+            feat::Prerequisite::Race(race) => {
+                [Some(self.race.get_name()), self.race.get_parent_name()]
+                    .into_iter()
+                    .flatten()
+                    .any(|name| name.eq_ignore_ascii_case(race))
+            }
+            feat::Prerequisite::Spellcasting => {
+                self.classes.is_spellcaster() || !self.get_racial_cantrips().is_empty()
+            }
+            feat::Prerequisite::ArmorProficiency(armor_category) => self
+                .get_own_proficiencies()
+                .is_proficient_with_armor(*armor_category),
+            feat::Prerequisite::AnyOf(prerequisites) => prerequisites
+                .iter()
+                .any(|prerequisite| self.meets_feat_prerequisite(prerequisite)),
+        }
+    }
+
+    /// # Errors
+    ///
+    /// - `AbilityNotChosen`: a half-feat's ability hasn't been picked
+    /// - `PrerequisiteNotMet`: the character doesn't meet one of the feat's prerequisites
+    ///
+    pub fn can_take_feat(&self, feat: &Feat) -> Result<(), FeatError> {
+        feat.validate()?;
+
+        match feat
+            .get_prerequisites()
+            .iter()
+            .find(|prerequisite| !self.meets_feat_prerequisite(prerequisite))
+        {
+            Some(prerequisite) => Err(FeatError::PrerequisiteNotMet(
+                feat.get_name().to_owned(),
+                prerequisite.clone(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// The character's own proficiencies together with those granted by subclasses.
+    fn get_own_proficiencies(&self) -> Proficiencies {
+        let mut proficiencies = self.proficiencies.clone();
+
+        for subclass_proficiencies in self.classes.get_subclass_proficiencies() {
+            proficiencies.extend(subclass_proficiencies);
+        }
+
+        proficiencies
+    }
+
+    pub fn get_armor_proficiencies_string(&self) -> String {
+        self.get_own_proficiencies()
+            .get_armor_proficiencies_string()
+    }
+
+    pub fn get_weapon_proficiencies_string(&self) -> String {
+        self.get_own_proficiencies()
+            .get_weapon_proficiencies_string()
+    }
+
+    /// Tool proficiencies from every source, without repeats.
+    #[must_use]
+    pub fn get_tool_proficiencies(&self) -> Vec<Tool> {
+        let mut tools: Vec<Tool> = vec![];
+        let background_tools = self.background.iter().flat_map(|background| {
+            background
+                .get_proficiencies()
+                .get_tools(&self.background_selections)
+        });
+
+        for tool in self
+            .get_own_proficiencies()
+            .get_tool_proficiencies()
+            .iter()
+            .map(|(tool, _)| tool.clone())
+            .chain(background_tools)
+        {
+            if !tools.contains(&tool) {
+                tools.push(self.tool_catalogue.resolve(tool));
+            }
+        }
+
+        tools
+    }
+
+    pub fn get_tool_proficiencies_string(&self) -> String {
+        self.get_tool_proficiencies()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The best proficiency the character has with `tool` from any source.
+    pub fn get_tool_proficiency(&self, tool: &Tool) -> Option<Proficiency> {
+        self.get_own_proficiencies()
+            .get_tool_proficiency(tool)
+            .or_else(|| {
+                self.get_tool_proficiencies()
+                    .contains(tool)
+                    .then_some(Proficiency::Proficiency)
+            })
+    }
+
+    /// An ability check made with `tool`, adding the proficiency bonus if proficient with it.
+    pub fn get_tool_check_breakdown(&self, tool: &Tool, ability: ability::Identifier) -> Breakdown {
+        let kind = CheckKind::Ability(ability);
+
+        self.get_check_breakdown_with(kind, self.get_tool_check_proficiency(tool, kind))
+    }
+
+    pub fn roll_tool_check(
+        &self,
+        tool: &Tool,
+        ability: ability::Identifier,
+        roller: &mut impl Roller,
+    ) -> Check {
+        let kind = CheckKind::Ability(ability);
+
+        self.roll_check_with(kind, self.get_tool_check_proficiency(tool, kind), roller)
+    }
+
+    fn get_tool_check_proficiency(&self, tool: &Tool, kind: CheckKind) -> Option<Proficiency> {
+        self.get_tool_proficiency(tool)
+            .into_iter()
+            .chain(self.get_check_proficiency(kind))
+            .max_by_key(|&proficiency| proficiency as usize)
+    }
+
+    fn get_languages_outside_background(&self) -> Vec<Language> {
+        self.get_own_proficiencies()
+            .get_languages()
+            .iter()
+            .chain(self.race.get_languages())
+            .cloned()
+            .chain(
+                self.race_selections
+                    .get_selections()
+                    .filter_map(|selection| match selection {
+                        race::Selection::Language(language) => Some(language.clone()),
+                        _ => None,
+                    }),
+            )
+            .collect()
+    }
+
+    /// Every language the character speaks, once each.
+    pub fn get_languages(&self) -> Vec<Language> {
+        let background_languages = self.background.iter().flat_map(|background| {
+            background
+                .get_proficiencies()
+                .get_languages(&self.background_selections)
+        });
+
+        language::unique(
+            self.get_languages_outside_background()
+                .into_iter()
+                .chain(background_languages),
+        )
+        .into_iter()
+        .map(|language| self.language_catalogue.resolve(language))
+        .collect()
+    }
+
+    pub fn get_languages_string(&self) -> String {
+        self.get_languages()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    #[must_use]
+    pub fn get_language_catalogue(&self) -> &language::Catalogue {
+        &self.language_catalogue
+    }
+
+    #[must_use]
+    pub fn get_tool_catalogue(&self) -> &tool::Catalogue {
+        &self.tool_catalogue
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `Catalogue`: a language with the same name is already in the catalogue
+    ///
+    pub fn add_homebrew_language(&mut self, language: Language) -> CharacterResult<()> {
+        self.language_catalogue.add(language)?;
+
+        Ok(())
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `Catalogue`: a tool with the same name is already in the catalogue
+    ///
+    pub fn add_homebrew_tool(&mut self, tool: Tool) -> CharacterResult<()> {
+        self.tool_catalogue.add(tool)?;
+
+        Ok(())
+    }
+
+    /// The language with this name in the character's catalogue, homebrew included.
+    #[must_use]
+    pub fn language_named(&self, name: &str) -> Language {
+        self.language_catalogue.named(name)
+    }
+
+    /// The tool with this name in the character's catalogue, homebrew included.
+    #[must_use]
+    pub fn tool_named(&self, name: &str) -> Tool {
+        self.tool_catalogue.named(name)
+    }
+}
+
+/// `items`, each followed by whatever it holds, however deep.
+fn with_contents<'a>(items: impl IntoIterator<Item = &'a Item>) -> Vec<&'a Item> {
+    let mut all = vec![];
+
+    for item in items {
+        all.push(item);
+
+        if let Some(container) = item.get_container() {
+            all.extend(with_contents(container.get_contents().iter()));
+        }
+    }
+
+    all
+}
+
+/// The item `index` places along the order of `with_contents`.
+fn nth_with_contents_mut<'a>(
+    items: impl IntoIterator<Item = &'a mut Item>,
+    index: &mut usize,
+) -> Option<&'a mut Item> {
+    for item in items {
+        if *index == 0 {
+            return Some(item);
+        }
+
+        *index -= 1;
+
+        if let Some(container) = item.get_container_mut() {
+            if let Some(found) =
+                nth_with_contents_mut(container.get_contents_mut().iter_mut(), index)
+            {
+                return Some(found);
+            }
+        }
+    }
+
+    None
+}
+
+type CharacterResult<T> = Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Equipment(SlotsError),
+    Inventory(item::ConstructionError),
+    AlreadyWearing(String),
+    ItemNotFound(String),
+    NoFreeSlot(String),
+    Attunement(AttunementError),
+    Grip(GripError),
+    Currency(currency::Error),
+    Container(container::Error),
+    MountNotFound(String),
+    ClassNotFound(String),
+    Improvement(ImprovementError),
+    RaceChoice(race::ChoiceError),
+    NoBackground,
+    BackgroundAbilityScores(AbilityScoreIncreaseError),
+    BackgroundGrant(GrantError),
+    Feature(FeatureError),
+    Subclass(SubclassError),
+    Feat(FeatError),
+    Infusion(InfusionError),
+    NoPsionics,
+    DisciplineNotFound(String),
+    Act(ActError),
+    Catalogue(catalogue::Error),
+}
+
+impl From<catalogue::Error> for Error {
+    fn from(value: catalogue::Error) -> Self {
+        Error::Catalogue(value)
+    }
+}
+
+impl From<ActError> for Error {
+    fn from(value: ActError) -> Self {
+        Error::Act(value)
+    }
+}
+
+impl From<InfusionError> for Error {
+    fn from(value: InfusionError) -> Self {
+        Error::Infusion(value)
+    }
+}
+
+impl From<FeatError> for Error {
+    fn from(value: FeatError) -> Self {
+        Error::Feat(value)
+    }
+}
+
+impl From<SubclassError> for Error {
+    fn from(value: SubclassError) -> Self {
+        Error::Subclass(value)
+    }
+}
+
+impl From<FeatureError> for Error {
+    fn from(value: FeatureError) -> Self {
+        Error::Feature(value)
+    }
+}
+
+impl From<GrantError> for Error {
+    fn from(value: GrantError) -> Self {
+        Error::BackgroundGrant(value)
+    }
+}
+
+impl From<AbilityScoreIncreaseError> for Error {
+    fn from(value: AbilityScoreIncreaseError) -> Self {
+        Error::BackgroundAbilityScores(value)
+    }
+}
+
+impl From<race::ChoiceError> for Error {
+    fn from(value: race::ChoiceError) -> Self {
+        Error::RaceChoice(value)
+    }
+}
+
+impl From<ImprovementError> for Error {
+    fn from(value: ImprovementError) -> Self {
+        Error::Improvement(value)
+    }
+}
+
+impl From<SlotsError> for Error {
+    fn from(value: SlotsError) -> Self {
+        Error::Equipment(value)
+    }
+}
+
+impl From<AttunementError> for Error {
+    fn from(value: AttunementError) -> Self {
+        Error::Attunement(value)
+    }
+}
+
+impl From<GripError> for Error {
+    fn from(value: GripError) -> Self {
+        Error::Grip(value)
+    }
+}
+
+impl From<container::Error> for Error {
+    fn from(value: container::Error) -> Self {
+        Error::Container(value)
+    }
+}
+
+impl From<currency::Error> for Error {
+    fn from(value: currency::Error) -> Self {
+        Error::Currency(value)
+    }
+}
+
+impl From<item::ConstructionError> for Error {
+    fn from(value: item::ConstructionError) -> Self {
+        Error::Inventory(value)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Error::Equipment(e) => format!("Equipment: {e}"),
+            Error::Inventory(e) => format!("Inventory: {e}"),
+            Error::AlreadyWearing(item) => format!("Equipment: already wearing {item}."),
+            Error::ItemNotFound(item) => format!("Inventory: no item named {item}."),
+            Error::NoFreeSlot(item) => format!("Equipment: no free slot for {item}."),
+            Error::Attunement(e) => format!("Attunement: {e}"),
+            Error::Grip(e) => format!("Equipment: {e}"),
+            Error::Currency(e) => format!("Currency: {e}"),
+            Error::Container(e) => format!("Container: {e}"),
+            Error::MountNotFound(mount) => format!("Mounts: no mount named {mount}."),
+            Error::ClassNotFound(class) => format!("Classes: no class named {class}."),
+            Error::Improvement(e) => format!("Classes: {e}"),
+            Error::RaceChoice(e) => format!("Race: {e}"),
+            Error::NoBackground => String::from("Background: character has no background."),
+            Error::BackgroundAbilityScores(e) => format!("Background: {e}"),
+            Error::BackgroundGrant(e) => format!("Background: {e}"),
+            Error::Feature(e) => format!("Features: {e}"),
+            Error::Subclass(e) => format!("Classes: {e}"),
+            Error::Feat(e) => format!("Feats: {e}"),
+            Error::Infusion(e) => format!("Infusions: {e}"),
+            Error::NoPsionics => String::from("Psionics: character has no psionic powers."),
+            Error::DisciplineNotFound(discipline) => {
+                format!("Psionics: no discipline named {discipline}.")
+            }
+            Error::Act(e) => format!("Psionics: {e}"),
+            Error::Catalogue(e) => format!("Catalogue: {e}"),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ability::AbilitiesTemplate,
+        artificer::{ArcaneArmor, ArmorModel, Piece},
+        background,
+        characteristics::{Alignment, Conformity, Morality},
+        dice::{Loaded, Roll},
+        effect::Modifier,
+        feature::UseCount,
+        item::{self, ArmorClass, WeaponCategory, WeaponProperty},
+        psionics::discipline::{self, Act},
+        race::{self, DamageType},
+        units::{Duration, Weight},
+    };
+
+    use super::*;
+
+    impl Character {
+        fn dummy() -> Self {
+            let characteristics: Characteristics = characteristics::Builder::new()
+                .alignment(Alignment(Conformity::Neutral, Morality::Neutral))
+                .size(Size::Medium)
+                .eye_color("--")
+                .height(vec![])
+                .hair_color("--")
+                .skin_tone("--")
+                .age(Duration::Instantaneous)
+                .weight(Weight::Pounds(100.0))
+                .try_into()
+                .expect("Shouldn't break");
+
+            Self {
+                name: "Dummy".into(),
+                characteristics,
+                base_ability_scores: Abilities::from(AbilitiesTemplate {
+                    strength: 8,
+                    dexterity: 8,
+                    constitution: 8,
+                    intelligence: 8,
+                    wisdom: 8,
+                    charisma: 8,
+                }),
+                race: Race::human(),
+                race_selections: race::Selections::default(),
+                background: None,
+                background_selections: background::Selections::default(),
+                classes: Classes::default(),
+                personality: Personality::default(),
+                skills: Skills::default(),
+                inventory: Items::default(),
+                exhaustion_level: 0,
+                encumbrance_rule: EncumbranceRule::default(),
+                damage: 0,
+                equipment: ItemSlots::default(),
+                senses: Senses::default(),
+                proficiencies: Proficiencies::default(),
+                armor_class_formulas: vec![],
+                purse: Purse::default(),
+                mounts: vec![],
+                conditions: BTreeSet::new(),
+                check_rules: vec![],
+                effects: Effects::default(),
+                infusions: Infusions::default(),
+                psionics: None,
+                concentration: None,
+                language_catalogue: language::Catalogue::default(),
+                tool_catalogue: tool::Catalogue::default(),
+            }
+        }
+    }
+
+    #[test]
+    fn _should_default_character_creature_type_to_race_creature_type() {
+        let character = Character::dummy();
+
+        assert_eq!(character.get_creature_type(), &CreatureType::Humanoid);
+    }
+
+    #[test]
+    fn _should_get_saving_throw_mod_without_proficiency() {
+        let character = Character::dummy();
+
+        assert_eq!(
+            character.get_saving_throw_mod(ability::Identifier::Strength),
+            -1
+        );
+    }
+
+    #[test]
+    fn _should_get_saving_throw_mod_including_proficiency_bonus() {
+        let mut character = Character::dummy();
+        character.add_class(Class::artificer());
+
+        assert_eq!(
+            character.get_saving_throw_mod(ability::Identifier::Constitution),
+            1
+        );
+    }
+
+    #[test]
+    fn _should_get_initial_size_from_race() {
+        let character = Character::dummy();
+
+        assert_eq!(character.get_size(), &Size::Medium);
+    }
+
+    #[test]
+    fn _should_get_initial_walking_speed_from_race() {
+        let character = Character::dummy();
+
+        assert_eq!(character.get_walking_speed(), 30);
+    }
+
+    #[test]
+    fn _characters_with_strength_and_no_items_should_not_be_encumbered() {
+        let character = Character::dummy();
+
+        assert_eq!(character.get_variant_encumbrance(), None);
+    }
+
+    #[test]
+    fn _characters_with_more_than_5_times_strength_score_in_item_weight_should_be_encumbered(
+    ) -> CharacterResult<()> {
+        let mut character = Character::dummy();
+
+        let item = item::Builder::new().name("test")?.weight(46.0)?.build()?;
+        character.add_item(item);
+
+        assert_eq!(
+            character.get_variant_encumbrance(),
+            Some(Encumbrance::Encumbered)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _characters_with_more_than_10_times_str_score_in_item_weight_should_be_heavily_encumbered(
+    ) -> CharacterResult<()> {
+        let mut character = Character::dummy();
+
+        let item = item::Builder::new().name("test")?.weight(91.0)?.build()?;
+        character.add_item(item);
+
+        assert_eq!(
+            character.get_variant_encumbrance(),
+            Some(Encumbrance::HeavilyEncumbered)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_include_inventory_and_equipment_in_total_carried_weight() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+
+        let rapier = item::Builder::new()
+            .name("Rapier")?
+            .weight(2.0)?
+            .add_type("weapon")?
+            .build()?;
+        character.add_item(rapier);
+
+        character.add_equipment_slot("armor", SlotRule::Any);
+        let chain_mail = item::Builder::new()
+            .name("Chain Mail")?
+            .weight(55.0)?
+            .add_type("armor")?
+            .armor_class(ArmorClass::Heavy(16))?
+            .build()?;
+        let _ = character.equip_item(chain_mail, "armor");
+
+        assert_eq!(character.get_total_weight_carried(), 57.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _encumbered_characters_should_reduce_their_speed_by_10() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+
+        let item = item::Builder::new().name("test")?.weight(46.0)?.build()?;
+        character.add_item(item);
+
+        assert_eq!(character.get_walking_speed(), 20);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _heavily_encumbered_characters_should_reduce_their_speed_by_20() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+
+        let item = item::Builder::new().name("test")?.weight(91.0)?.build()?;
+        character.add_item(item);
+
+        assert_eq!(character.get_walking_speed(), 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _carrying_capacity_should_be_15_times_strength_score() {
+        let character = Character::dummy();
+        let strength_score = character.get_ability_score(ability::Identifier::Strength) as f64;
+
+        assert_eq!(character.get_carrying_capacity(), 15.0 * strength_score);
+        assert_eq!(
+            character.get_push_drag_lift_capacity(),
+            30.0 * strength_score
+        );
+    }
+
+    #[test]
+    fn _over_carrying_capacity_should_drop_speed_to_5_until_push_drag_lift() -> CharacterResult<()>
+    {
+        let mut character = Character::dummy();
+        character.set_encumbrance_rule(EncumbranceRule::Standard);
+        let carrying_capacity = character.get_carrying_capacity();
+
+        character.add_item(
+            item::Builder::new()
+                .name("Anvil")?
+                .weight(carrying_capacity + 0.5)?
+                .build()?,
+        );
+
+        assert!(character.is_over_carrying_capacity());
+        assert_eq!(character.get_walking_speed(), 5);
+
+        character.add_item(
+            item::Builder::new()
+                .name("Boulder")?
+                .weight(carrying_capacity)?
+                .build()?,
+        );
+
+        assert_eq!(character.get_walking_speed(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_only_apply_the_speed_penalties_of_the_active_rule() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        let carrying_capacity = character.get_carrying_capacity();
+
+        character.add_item(
+            item::Builder::new()
+                .name("Anvil")?
+                .weight(carrying_capacity + 0.5)?
+                .build()?,
+        );
+
+        assert_eq!(character.get_encumbrance_rule(), EncumbranceRule::Variant);
+        assert_eq!(character.get_walking_speed(), 10);
+        assert_eq!(
+            character.get_walking_speed_breakdown().to_string(),
+            "30 (Human) - 20 (Heavily encumbered)"
+        );
+
+        character.set_encumbrance_rule(EncumbranceRule::Standard);
+
+        assert_eq!(character.get_walking_speed(), 5);
+        assert_eq!(
+            character.get_variant_encumbrance(),
+            Some(Encumbrance::HeavilyEncumbered)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _size_should_scale_carrying_capacity_and_encumbrance() -> Result<(), Box<dyn error::Error>> {
+        let mut character = Character::dummy();
+        let medium_capacity = character.get_carrying_capacity();
+
+        character.race = race::Builder::new()
+            .name("Giant")
+            .size(Size::Large)
+            .build()?;
+        let strength_score = character.get_ability_score(ability::Identifier::Strength) as f64;
+
+        assert_eq!(character.get_carrying_capacity(), 30.0 * strength_score);
+
+        character.add_item(
+            item::Builder::new()
+                .name("test")?
+                .weight(6.0 * strength_score)?
+                .build()?,
+        );
+
+        assert_eq!(character.get_variant_encumbrance(), None);
+
+        character.race = race::Builder::new()
+            .name("Pixie")
+            .size(Size::Tiny)
+            .build()?;
+        let strength_score = character.get_ability_score(ability::Identifier::Strength) as f64;
+
+        assert_eq!(character.get_carrying_capacity(), 7.5 * strength_score);
+        assert!(character.get_carrying_capacity() < medium_capacity);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _characters_with_2_or_more_exhaustion_should_half_their_movement_speed() {
+        let mut character = Character::dummy();
+
+        character.set_exhaustion_level(2);
+        assert_eq!(character.get_walking_speed(), 15);
+
+        character.set_exhaustion_level(3);
+        assert_eq!(character.get_walking_speed(), 15);
+
+        character.set_exhaustion_level(4);
+        assert_eq!(character.get_walking_speed(), 15);
+    }
+
+    #[test]
+    fn _characters_with_5_levels_of_exhaustion_should_have_0_movement_speed() {
+        let mut character = Character::dummy();
+
+        character.set_exhaustion_level(5);
+        assert_eq!(character.get_walking_speed(), 0);
+    }
+
+    #[test]
+    fn _skill_modifier_should_default_to_related_ability_modifier() {
+        let character = Character::dummy();
+
+        assert_eq!(character.get_skill_modifier(skills::Identifier::Arcana), -1);
+    }
+
+    #[test]
+    fn _proficiency_should_affect_skill_modifier() {
+        let mut character = Character::dummy();
+
+        let mut class = Class::wizard();
+        class.set_level(1).unwrap();
+        character.add_class(class);
+
+        character
+            .skills
+            .set_proficiency(skills::Identifier::Arcana, Some(Proficiency::Proficiency));
+
+        assert_eq!(character.get_skill_modifier(skills::Identifier::Arcana), 1);
+    }
+
+    #[test]
+    fn _expertise_should_affect_skill_modifier() {
+        let mut character = Character::dummy();
+
+        let mut class = Class::wizard();
+        class.set_level(1).unwrap();
+
+        character.add_class(class);
+        character
+            .skills
+            .set_proficiency(skills::Identifier::Arcana, Some(Proficiency::Expertise));
+
+        assert_eq!(character.get_skill_modifier(skills::Identifier::Arcana), 3);
+    }
+
+    #[test]
+    fn _passive_perception_should_be_10_plus_perception_modifier() {
+        let mut character = Character::dummy();
+        character.add_class(Class::wizard());
+
+        assert_eq!(character.get_passive_perception(), 9);
+
+        character.skills.set_proficiency(
+            skills::Identifier::Perception,
+            Some(Proficiency::Proficiency),
+        );
+        assert_eq!(character.get_passive_perception(), 11);
+    }
+
+    #[test]
+    fn _passive_investigation_should_be_10_plus_investigation_modifier() {
+        let mut character = Character::dummy();
+        character.add_class(Class::wizard());
+
+        assert_eq!(character.get_passive_investigation(), 9);
+
+        character.skills.set_proficiency(
+            skills::Identifier::Investigation,
+            Some(Proficiency::Proficiency),
+        );
+        assert_eq!(character.get_passive_investigation(), 11);
+    }
+
+    #[test]
+    fn _passive_insight_should_be_10_plus_insight_modifier() {
+        let mut character = Character::dummy();
+        character.add_class(Class::wizard());
+
+        assert_eq!(character.get_passive_insight(), 9);
+
+        character
+            .skills
+            .set_proficiency(skills::Identifier::Insight, Some(Proficiency::Proficiency));
+        assert_eq!(character.get_passive_insight(), 11);
+    }
+
+    #[test]
+    fn _should_obtain_feats_from_classes_and_race() {
+        let mut character = Character::dummy();
+
+        let spell_sniper = Feat::new(
+            "Spell Sniper",
+            "Doubles casting distance and ignores half cover.",
+        );
+        let mut wizard = Class::wizard();
+        wizard.add_feat(spell_sniper.clone()).unwrap();
+
+        character.add_class(wizard);
+
+        let elven_accuracy = Feat::new(
+            "Elven Accuracy",
+            "When rolling advantage on ranged checks, roll a third die.",
+        );
+        let mut shadar_kai = Race::shadar_kai();
+        shadar_kai.add_feat(elven_accuracy.clone()).unwrap();
+        character.race = shadar_kai;
+
+        assert_eq!(
+            character.get_feats(),
+            vec![
+                (feat::Source::Class(String::from("Wizard")), &spell_sniper),
+                (
+                    feat::Source::Race(String::from("Shadar-kai")),
+                    &elven_accuracy
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn _should_derive_armor_class_from_armor_shield_and_dex_mod() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("chestplate", SlotRule::Any);
+        character.add_equipment_slot("left hand", SlotRule::Any);
+
+        let breastplate = item::Builder::new()
+            .name("Breastplate")?
+            .weight(25.0)?
+            .add_type("armor")?
+            .armor_class(ArmorClass::Medium(14))?
+            .build()?;
+        character.equip_item(breastplate, "chestplate")?;
+
+        let shield = item::Builder::new()
+            .name("Shield")?
+            .weight(6.0)?
+            .armor_class(ArmorClass::Shield(2))?
+            .build()?;
+        character.equip_item(shield, "left hand")?;
+
+        assert_eq!(character.get_armor_class(), 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _unarmored_characters_should_have_10_plus_dex_mod() {
+        let character = Character::dummy();
+
+        assert_eq!(character.get_armor_class(), 9);
+    }
+
+    #[test]
+    fn _should_use_alternative_formula_when_higher() {
+        let mut character = Character::dummy();
+        character.add_armor_class_formula(Formula::MageArmor);
+
+        assert_eq!(character.get_armor_class(), 12);
+
+        character.remove_armor_class_formula(&Formula::MageArmor);
+
+        assert_eq!(character.get_armor_class(), 9);
+    }
+
+    #[test]
+    fn _untrained_armor_should_disadvantage_str_and_dex_and_prevent_spellcasting(
+    ) -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("armor", SlotRule::Any);
+
+        let chain_mail = item::Builder::new()
+            .name("Chain Mail")?
+            .armor_class(ArmorClass::Heavy(16))?
+            .build()?;
+        character.equip_item(chain_mail, "armor")?;
+
+        assert!(!character.can_cast_spells());
+        assert_eq!(
+            character.get_saving_throw_advantage(ability::Identifier::Dexterity),
+            Some(Advantage::Disadvantage)
+        );
+        assert_eq!(
+            character.get_ability_check_advantage(ability::Identifier::Strength),
+            Some(Advantage::Disadvantage)
+        );
+        assert_eq!(
+            character.get_attack_advantage(ability::Identifier::Strength),
+            Some(Advantage::Disadvantage)
+        );
+        assert_eq!(
+            character.get_skill_advantage(skills::Identifier::Acrobatics),
+            Some(Advantage::Disadvantage)
+        );
+        assert_eq!(
+            character.get_saving_throw_advantage(ability::Identifier::Wisdom),
+            None
+        );
+
+        character
+            .proficiencies
+            .add_armor_proficiency(ArmorCategory::Heavy);
+
+        assert!(character.can_cast_spells());
+        assert_eq!(
+            character.get_saving_throw_advantage(ability::Identifier::Dexterity),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _heavy_armor_below_strength_requirement_should_reduce_speed_by_10() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("armor", SlotRule::Any);
+        character
+            .proficiencies
+            .add_armor_proficiency(ArmorCategory::Heavy);
+
+        let splint = item::Builder::new()
+            .name("Splint")?
+            .armor_class(ArmorClass::Heavy(17))?
+            .strength_requirement(15)?
+            .build()?;
+        character.equip_item(splint, "armor")?;
+
+        assert!(character.is_below_armor_strength_requirement());
+        assert_eq!(character.get_walking_speed(), 20);
+
+        character
+            .base_ability_scores
+            .set_score(ability::Identifier::Strength, 14);
+
+        assert!(!character.is_below_armor_strength_requirement());
+        assert_eq!(character.get_walking_speed(), 30);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _stealth_disadvantage_armor_should_mark_stealth() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("armor", SlotRule::Any);
+        character
+            .proficiencies
+            .add_armor_proficiency(ArmorCategory::Medium);
+
+        assert_eq!(
+            character.get_skill_advantage(skills::Identifier::Stealth),
+            None
+        );
+
+        let half_plate = item::Builder::new()
+            .name("Half Plate")?
+            .armor_class(ArmorClass::Medium(15))?
+            .stealth_disadvantage()?
+            .build()?;
+        character.equip_item(half_plate, "armor")?;
+
+        assert_eq!(
+            character.get_skill_advantage(skills::Identifier::Stealth),
+            Some(Advantage::Disadvantage)
+        );
+
+        character
+            .skills
+            .set_advantage(skills::Identifier::Stealth, Some(Advantage::Advantage));
+
+        assert_eq!(
+            character.get_skill_advantage(skills::Identifier::Stealth),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _coins_should_count_towards_weight_carried() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_item(
+            item::Builder::new()
+                .name("Arrow")?
+                .weight(1.0)?
+                .stackable()?
+                .quantity(20)?
+                .build()?,
+        );
+        character.add_coins(Coin::Gold, 100);
+
+        assert_eq!(character.get_total_weight_carried(), 22.0);
+
+        character.pay(Price::new(50, Coin::Gold))?;
+
+        assert_eq!(character.get_total_weight_carried(), 21.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_store_and_retrieve_items_from_containers() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_item(
+            item::Builder::new()
+                .name("Backpack")?
+                .weight(5.0)?
+                .container(container::Container::new().weight_capacity(30.0))?
+                .build()?,
+        );
+        character.add_item(item::Builder::new().name("Rope")?.weight(10.0)?.build()?);
+        character.add_item(item::Builder::new().name("Anvil")?.weight(40.0)?.build()?);
+
+        character.store_item("Rope", "Backpack")?;
+
+        assert!(character.get_inventory().find("Rope").is_none());
+        assert_eq!(character.get_total_weight_carried(), 55.0);
+        assert!(matches!(
+            character.store_item("Anvil", "Backpack"),
+            Err(Error::Container(container::Error::OverWeightCapacity(30.0)))
+        ));
+        assert!(matches!(
+            character.store_item("Anvil", "Rope"),
+            Err(Error::ItemNotFound(_))
+        ));
+        assert!(character.get_inventory().find("Anvil").is_some());
+
+        character.retrieve_item("Rope", "Backpack")?;
+
+        assert!(character.get_inventory().find("Rope").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_drop_and_move_only_what_a_container_holds() -> CharacterResult<()> {
+        let torches = |quantity| -> CharacterResult<Item> {
+            Ok(item::Builder::new()
+                .name("Torch")?
+                .weight(1.0)?
+                .stackable()?
+                .quantity(quantity)?
+                .build()?)
+        };
+        let mut character = Character::dummy();
+        character.add_mount(Mount::new("Mule", Some(420.0)));
+        for container in ["Backpack", "Sack"] {
+            character.add_item(
+                item::Builder::new()
+                    .name(container)?
+                    .weight(1.0)?
+                    .container(container::Container::new().weight_capacity(30.0))?
+                    .build()?,
+            );
+        }
+        character.add_item(torches(1)?);
+        character.store_item("Torch", "Backpack")?;
+        character.add_item(torches(1)?);
+        character.load_onto_mount("Torch", "Mule")?;
+        character.add_item(torches(5)?);
+
+        let dropped = character.drop_from_container("Torch", "Backpack")?;
+
+        assert_eq!(dropped.get_quantity(), 1);
+        assert_eq!(
+            character
+                .get_inventory()
+                .find("Torch")
+                .map(Item::get_quantity),
+            Some(5)
+        );
+
+        character.store_item("Torch", "Backpack")?;
+        character.transfer_item("Torch", "Backpack", "Sack")?;
+
+        assert!(character.get_inventory().find("Torch").is_none());
+        assert!(matches!(
+            character.transfer_item("Torch", "Backpack", "Sack"),
+            Err(Error::ItemNotFound(_))
+        ));
+
+        character.add_item(torches(5)?);
+
+        assert_eq!(
+            character.drop_from_mount("Torch", "Mule")?.get_quantity(),
+            1
+        );
+        assert!(character.get_mounts()[0].get_inventory().is_empty());
+        assert_eq!(
+            character
+                .get_inventory()
+                .find("Torch")
+                .map(Item::get_quantity),
+            Some(5)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _mount_inventory_should_not_encumber_the_character() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_mount(Mount::new("Mule", Some(420.0)));
+        character.add_item(item::Builder::new().name("Tent")?.weight(60.0)?.build()?);
+
+        assert_eq!(
+            character.get_variant_encumbrance(),
+            Some(Encumbrance::Encumbered)
+        );
+
+        character.load_onto_mount("Tent", "Mule")?;
+
+        assert_eq!(character.get_variant_encumbrance(), None);
+        assert_eq!(character.get_mounts()[0].get_total_weight(), 60.0);
+
+        character.unload_from_mount("Tent", "Mule")?;
+
+        assert_eq!(character.get_total_weight_carried(), 60.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_move_items_between_inventory_and_equipment() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("right hand", SlotRule::Weapon);
+
+        let dagger = item::Builder::new()
+            .name("Dagger")?
+            .weapon(Weapon::new(
+                WeaponCategory::Simple,
+                Roll::new(1, 4, 0),
+                DamageType::Piercing,
+            ))?
+            .build()?;
+        character.add_item(dagger.clone());
+        character.add_item(dagger);
+
+        character.equip_from_inventory("Dagger")?;
+
+        assert_eq!(character.get_inventory().len(), 1);
+        assert!(matches!(
+            character.equip_from_inventory("Dagger"),
+            Err(Error::NoFreeSlot(_))
+        ));
+        assert_eq!(character.get_inventory().len(), 1);
+
+        character.unequip_to_inventory("right hand")?;
+
+        assert_eq!(character.get_inventory().len(), 2);
+        assert!(character.get_equipment().get_equipped_items().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn _dropping_an_item_should_end_attunement() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("cloak", SlotRule::Any);
+
+        let cloak = item::Builder::new()
+            .name("Cloak of Protection")?
+            .requires_attunement()?
+            .build()?;
+        character.equip_item(cloak, "cloak")?;
+        character.attune_item("Cloak of Protection")?;
+
+        let cloak = character.drop_item("Cloak of Protection")?;
+
+        assert!(!cloak.is_attuned());
+        assert!(character.get_attuned_items().is_empty());
+        assert!(character.find_item("Cloak of Protection").is_none());
+        assert!(matches!(
+            character.drop_item("Cloak of Protection"),
+            Err(Error::ItemNotFound(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_not_attune_to_more_than_three_items() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+
+        for name in ["Ring", "Amulet", "Cloak", "Boots"] {
+            character.add_item(
+                item::Builder::new()
+                    .name(name)?
+                    .requires_attunement()?
+                    .build()?,
+            );
+        }
+
+        character.attune_item("Ring")?;
+        character.attune_item("Amulet")?;
+        character.attune_item("Cloak")?;
+
+        assert!(matches!(
+            character.attune_item("Boots"),
+            Err(Error::Attunement(AttunementError::LimitReached))
+        ));
+
+        character.unattune_item("Amulet")?;
+        character.attune_item("Boots")?;
+
+        assert_eq!(character.get_attuned_items().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _stored_attuned_items_should_count_towards_the_limit() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+
+        for name in ["Ring", "Amulet", "Cloak", "Boots"] {
+            character.add_item(
+                item::Builder::new()
+                    .name(name)?
+                    .requires_attunement()?
+                    .build()?,
+            );
+        }
+
+        for name in ["Backpack", "Pouch"] {
+            character.add_item(
+                item::Builder::new()
+                    .name(name)?
+                    .container(container::Container::new())?
+                    .build()?,
+            );
+        }
+
+        character.add_mount(Mount::new("Pony", None));
+
+        character.attune_item("Ring")?;
+        character.attune_item("Amulet")?;
+        character.attune_item("Cloak")?;
+        character.store_item("Cloak", "Pouch")?;
+        character.store_item("Pouch", "Backpack")?;
+        character.load_onto_mount("Amulet", "Pony")?;
+
+        assert_eq!(character.get_attuned_items().len(), 3);
+        assert!(matches!(
+            character.attune_item("Boots"),
+            Err(Error::Attunement(AttunementError::LimitReached))
+        ));
+
+        character.unattune_item("Cloak")?;
+        character.attune_item("Boots")?;
+
+        assert_eq!(character.get_attuned_items().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _dropping_from_a_slot_should_leave_carried_items_of_the_same_name() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.change_slot_layout(SlotLayout::humanoid());
+
+        let shield = item::Builder::new()
+            .name("Shield")?
+            .armor_class(ArmorClass::Shield(2))?
+            .build()?;
+        character.add_item(shield.clone());
+        character.equip_item(shield, "left hand")?;
+
+        let dropped = character.drop_equipped("left hand")?;
+
+        assert_eq!(dropped.get_name(), "Shield");
+        assert!(character.get_equipment().get_item_in("left hand").is_none());
+        assert!(character.get_inventory().find("Shield").is_some());
+        assert!(character.drop_equipped("left hand").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn _dropping_an_item_from_a_mount_should_end_attunement() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_item(
+            item::Builder::new()
+                .name("Cloak of Protection")?
+                .requires_attunement()?
+                .build()?,
+        );
+        character.add_mount(Mount::new("Pony", None));
+        character.attune_item("Cloak of Protection")?;
+        character.load_onto_mount("Cloak of Protection", "Pony")?;
+
+        let cloak = character.drop_from_mount("Cloak of Protection", "Pony")?;
+
+        assert!(!cloak.is_attuned());
+        assert!(character.get_attuned_items().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_enforce_attunement_prerequisites() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_item(
+            item::Builder::new()
+                .name("Staff of Power")?
+                .requires_attunement_by(Prerequisite::Class("Sorcerer".into()))?
+                .requires_attunement_by(Prerequisite::Class("Wizard".into()))?
+                .build()?,
+        );
+
+        assert!(matches!(
+            character.attune_item("Staff of Power"),
+            Err(Error::Attunement(AttunementError::PrerequisiteNotMet(_)))
+        ));
+
+        character.add_class(Class::wizard());
+        character.attune_item("Staff of Power")?;
+
+        assert!(character
+            .find_item("Staff of Power")
+            .is_some_and(Item::is_attuned));
+
+        Ok(())
+    }
+
+    #[test]
+    fn _attunement_bonuses_should_only_apply_while_attuned() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("cloak", SlotRule::Any);
+
+        let cloak = item::Builder::new()
+            .name("Cloak of Protection")?
+            .armor_class_bonus(1)?
+            .requires_attunement()?
+            .build()?;
+        character.equip_item(cloak, "cloak")?;
+
+        assert_eq!(character.get_armor_class(), 9);
+
+        character.attune_item("Cloak of Protection")?;
+
+        assert_eq!(character.get_armor_class(), 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_not_allow_wearing_two_body_armors() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("chestplate", SlotRule::Any);
+        character.add_equipment_slot("helmet", SlotRule::Any);
+
+        let breastplate = item::Builder::new()
+            .name("Breastplate")?
+            .armor_class(ArmorClass::Medium(14))?
+            .build()?;
+        character.equip_item(breastplate, "chestplate")?;
+
+        let pickelbonnet = item::Builder::new()
+            .name("Pickelbonnet")?
+            .armor_class(ArmorClass::Heavy(3))?
+            .build()?;
+        let result = character.equip_item(pickelbonnet, "helmet");
+
+        assert!(matches!(result, Err(Error::AlreadyWearing(name)) if name == "Breastplate"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn _changing_slot_layout_should_return_equipped_items_to_inventory() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.change_slot_layout(SlotLayout::humanoid());
+
+        let breastplate = item::Builder::new()
+            .name("Breastplate")?
+            .add_type("armor")?
+            .armor_class(ArmorClass::Medium(14))?
+            .build()?;
+        character.equip_item(breastplate, "armor")?;
+
+        character.change_slot_layout(SlotLayout::armorer());
+
+        assert_eq!(character.get_slot_layout().get_name(), "armorer");
+        assert!(character.get_equipment().get_equipped_items().is_empty());
+        assert!(character.get_inventory().find("Breastplate").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn _versatile_weapons_should_claim_the_other_hand_when_gripped_with_both() -> CharacterResult<()>
+    {
+        let mut character = Character::dummy();
+        character.change_slot_layout(SlotLayout::humanoid());
+
+        let longsword = item::Builder::new()
+            .name("Longsword")?
+            .weapon(
+                Weapon::new(
+                    WeaponCategory::Martial,
+                    Roll::new(1, 8, 0),
+                    DamageType::Slashing,
+                )
+                .add_property(WeaponProperty::Versatile(Roll::new(1, 10, 0))),
+            )?
+            .build()?;
+        character.equip_item(longsword, "right hand")?;
+        let shield = item::Builder::new()
+            .name("Shield")?
+            .armor_class(ArmorClass::Shield(2))?
+            .build()?;
+        character.equip_item(shield, "left hand")?;
+
+        let result = character.set_grip("right hand", Grip::TwoHanded);
+
+        assert!(matches!(
+            result,
+            Err(Error::Equipment(SlotsError::Occupied { slot, .. })) if slot == "left hand"
+        ));
+        assert_eq!(
+            character.get_equipment().get_occupied_slots("right hand"),
+            vec!["right hand"]
+        );
+
+        character.unequip_to_inventory("left hand")?;
+        character.set_grip("right hand", Grip::TwoHanded)?;
+
+        assert_eq!(
+            character.get_equipment().get_occupied_slots("left hand"),
+            vec!["right hand", "left hand"]
+        );
+
+        character.set_grip("left hand", Grip::OneHanded)?;
+
+        assert!(character.get_equipment().get_item_in("left hand").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn _conditions_and_exhaustion_should_cancel_against_advantage() {
+        let mut character = Character::dummy();
+        let stealth = CheckKind::Skill(skills::Identifier::Stealth);
+        character
+            .skills
+            .set_advantage(skills::Identifier::Stealth, Some(Advantage::Advantage));
+
+        character.add_condition(Condition::Poisoned);
+        assert_eq!(character.get_check_advantage(stealth), None);
+
+        let check = character.roll_check(stealth, &mut Loaded::new([20]));
+        assert_eq!(check.get_rolls(), &[20]);
+        assert!(check.is_natural_20());
+
+        character.remove_condition(Condition::Poisoned);
+        character.set_exhaustion_level(1);
+        assert_eq!(character.get_check_advantage(stealth), None);
+        assert_eq!(
+            character.get_saving_throw_advantage(ability::Identifier::Dexterity),
+            None
+        );
+
+        character.set_exhaustion_level(3);
+        assert_eq!(
+            character.get_saving_throw_advantage(ability::Identifier::Dexterity),
+            Some(Advantage::Disadvantage)
+        );
+    }
+
+    #[test]
+    fn _ability_check_effects_should_reach_skills_of_that_ability() {
+        let mut character = Character::dummy();
+        let stealth = CheckKind::Skill(skills::Identifier::Stealth);
+        let athletics = CheckKind::Skill(skills::Identifier::Athletics);
+        let stealth_total = character
+            .roll_check(stealth, &mut Loaded::new([12]))
+            .get_total();
+
+        character.add_effect(Effect::new(
+            "Enhance Ability",
+            Target::AbilityCheck(ability::Identifier::Dexterity),
+            Modifier::Advantage(Advantage::Advantage),
+        ));
+        character.add_effect(Effect::new(
+            "Guidance",
+            Target::AbilityCheck(ability::Identifier::Dexterity),
+            Modifier::Add(1),
+        ));
+
+        assert_eq!(
+            character.get_check_advantage(stealth),
+            Some(Advantage::Advantage)
+        );
+        assert_eq!(character.get_check_advantage(athletics), None);
+
+        let check = character.roll_check(stealth, &mut Loaded::new([12, 5]));
+        assert_eq!(check.get_rolls(), &[12, 5]);
+        assert_eq!(check.get_total(), stealth_total + 1);
+    }
+
+    #[test]
+    fn _jack_of_all_trades_should_only_add_to_unproficient_ability_checks() {
+        let mut character = Character::dummy();
+        let mut class = Class::wizard();
+        class.set_level(1).unwrap();
+        character.add_class(class);
+        character.add_check_rule(CheckRule::HalfProficiency);
+        character
+            .skills
+            .set_proficiency(skills::Identifier::Arcana, Some(Proficiency::Proficiency));
+
+        let athletics = character.roll_check(
+            CheckKind::Skill(skills::Identifier::Athletics),
+            &mut Loaded::new([12]),
+        );
+        assert_eq!(athletics.get_total(), 12);
+        assert_eq!(
+            athletics.to_string(),
+            "Athletics check: [12] -> 12 - 1 (Strength) + 1 (Jack of All Trades) = 12"
+        );
+
+        let arcana = character.roll_check(
+            CheckKind::Skill(skills::Identifier::Arcana),
+            &mut Loaded::new([12]),
+        );
+        assert_eq!(arcana.get_total(), 13);
+
+        let save = character.roll_check(
+            CheckKind::SavingThrow(ability::Identifier::Strength),
+            &mut Loaded::new([12]),
+        );
+        assert_eq!(save.get_total(), 11);
+    }
+
+    #[test]
+    fn _reliable_talent_should_raise_low_rolls_on_proficient_checks() {
+        let mut character = Character::dummy();
+        character.add_check_rule(CheckRule::MinimumRoll(10));
+        character
+            .skills
+            .set_proficiency(skills::Identifier::Arcana, Some(Proficiency::Expertise));
+
+        let arcana = character.roll_check(
+            CheckKind::Skill(skills::Identifier::Arcana),
+            &mut Loaded::new([3]),
+        );
+        assert_eq!(arcana.get_natural_roll(), 3);
+        assert_eq!(arcana.get_die(), 10);
+
+        let history = character.roll_check(
+            CheckKind::Skill(skills::Identifier::History),
+            &mut Loaded::new([3]),
+        );
+        assert_eq!(history.get_die(), 3);
+    }
+
+    #[test]
+    fn _tool_expertise_should_double_the_proficiency_bonus_on_tool_checks() {
+        let mut character = Character::dummy();
+        let mut class = Class::wizard();
+        class.set_level(1).unwrap();
+        character.add_class(class);
+        character
+            .proficiencies
+            .add_tool_proficiency(Tool::named("Thieves' Tools"), Proficiency::Expertise);
+        character
+            .proficiencies
+            .add_tool_proficiency(Tool::named("thieves' tools"), Proficiency::Proficiency);
+
+        let lockpicking = character.roll_tool_check(
+            &Tool::named("Thieves' Tools"),
+            ability::Identifier::Dexterity,
+            &mut Loaded::new([12]),
+        );
+        assert_eq!(
+            lockpicking.to_string(),
+            "Dexterity check: [12] -> 12 - 1 (Dexterity) + 4 (Expertise) = 15"
+        );
+        assert_eq!(
+            character
+                .get_tool_check_breakdown(&Tool::named("Lute"), ability::Identifier::Charisma)
+                .get_total(),
+            -1
+        );
+    }
+
+    #[test]
+    fn _languages_should_be_listed_once_whatever_their_source() {
+        let mut character = Character::dummy();
+        character
+            .proficiencies
+            .add_language(Language::named("common"));
+        character
+            .proficiencies
+            .add_language(Language::named("Thieves' Cant"));
+
+        assert_eq!(character.get_languages_string(), "Common, Thieves' Cant");
+        assert_eq!(
+            character.get_languages()[1].get_rarity(),
+            language::Rarity::Secret
+        );
+    }
+
+    #[test]
+    fn _homebrew_languages_and_tools_should_resolve_against_the_catalogue() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_homebrew_language(Language::new(
+            "Haskell",
+            language::Rarity::Exotic,
+            Some("Lambda"),
+        ))?;
+        character.add_homebrew_tool(Tool::new("Spelljammer Helm", tool::Category::Vehicles))?;
+
+        assert!(matches!(
+            character.add_homebrew_language(Language::named("elvish")),
+            Err(Error::Catalogue(catalogue::Error::AlreadyDefined(_)))
+        ));
+        assert_eq!(
+            character.language_named("haskell").get_script(),
+            Some("Lambda")
+        );
+
+        character
+            .proficiencies
+            .add_language(Language::named("HASKELL"));
+        character
+            .proficiencies
+            .add_tool_proficiency(Tool::named("spelljammer helm"), Proficiency::Proficiency);
+
+        assert_eq!(
+            character.get_languages()[0].get_rarity(),
+            language::Rarity::Exotic
+        );
+        assert_eq!(character.get_languages_string(), "Haskell, Common");
+        assert_eq!(
+            character.get_tool_proficiencies()[0].get_category(),
+            tool::Category::Vehicles
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _limited_features_should_be_spent_and_regained_on_a_rest() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        let mut artificer = Class::artificer();
+        artificer.set_level(6).unwrap();
+        artificer
+            .add_feature(
+                Feature::new("Flash of Genius", "Add your Intelligence modifier.", 7).limited(
+                    UseCount::AbilityModifier(ability::Identifier::Intelligence),
+                    Rest::Long,
+                ),
+            )
+            .unwrap();
+        artificer
+            .add_feature(
+                Feature::new("Arcane Jolt", "Heal or harm through an infusion.", 5)
+                    .limited(UseCount::ProficiencyBonus, Rest::Short),
+            )
+            .unwrap();
+        character.add_class(artificer);
+
+        assert!(matches!(
+            character.spend_feature_use("Flash of Genius"),
+            Err(Error::Feature(FeatureError::NotFound(_)))
+        ));
+        assert_eq!(character.spend_feature_use("arcane jolt")?, 2);
+        assert_eq!(character.spend_feature_use("Arcane Jolt")?, 1);
+
+        character.finish_rest(Rest::Short);
+
+        let (class, feature) = character.get_features()[0];
+        assert_eq!(feature.get_name(), "Arcane Jolt");
+        assert_eq!(
+            character.get_feature_remaining_uses(class, feature),
+            Some(3)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _subclass_proficiencies_should_count_as_the_characters_own() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("armor", SlotRule::Any);
+        let mut artificer = Class::artificer();
+        artificer.set_level(3).unwrap();
+        character.add_class(artificer);
+
+        let chain_mail = item::Builder::new()
+            .name("Chain Mail")?
+            .armor_class(ArmorClass::Heavy(16))?
+            .build()?;
+        character.equip_item(chain_mail, "armor")?;
+
+        assert!(!character.can_cast_spells());
+
+        character.set_subclass(
+            "Artificer",
+            Subclass::new("Armorer")
+                .add_armor_proficiency(ArmorCategory::Heavy)
+                .add_tool_proficiency(Tool::named("Smith's Tools"))
+                .add_spells(3, ["Magic Missile", "Thunderwave"]),
+        )?;
+
+        assert!(character.can_cast_spells());
+        assert_eq!(character.get_armor_proficiencies_string(), "Heavy Armor");
+        assert_eq!(
+            character.get_tool_proficiency(&Tool::named("smith's tools")),
+            Some(Proficiency::Proficiency)
+        );
+        assert_eq!(
+            character.get_always_prepared_spells(),
+            vec!["Magic Missile", "Thunderwave"]
+        );
+        assert!(matches!(
+            character.set_subclass("Wizard", Subclass::new("Evoker")),
+            Err(Error::ClassNotFound(_))
+        ));
+
+        Ok(())
     }
 
     #[test]
-    fn _should_default_character_creature_type_to_race_creature_type() {
-        let character = Character::dummy();
+    fn _paralyzed_characters_should_fail_strength_and_dexterity_saves() {
+        let mut character = Character::dummy();
+        character.add_condition(Condition::Paralyzed);
 
-        assert_eq!(character.get_creature_type(), &CreatureType::Humanoid);
+        let dexterity = character.roll_check(
+            CheckKind::SavingThrow(ability::Identifier::Dexterity),
+            &mut Loaded::new([20]),
+        );
+        assert!(dexterity.is_automatic_failure());
+
+        let wisdom = character.roll_check(
+            CheckKind::SavingThrow(ability::Identifier::Wisdom),
+            &mut Loaded::new([20]),
+        );
+        assert!(!wisdom.is_automatic_failure());
     }
 
     #[test]
-    fn _should_get_saving_throw_mod_without_proficiency() {
-        let character = Character::dummy();
+    fn _item_effects_should_apply_while_equipped_and_attuned() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.add_equipment_slot("cloak", SlotRule::Any);
+        character.add_equipment_slot("neck", SlotRule::Any);
+        character.add_class(Class::wizard());
+        let wisdom_save = character.get_saving_throw_mod(ability::Identifier::Wisdom);
+        let hit_points = character.get_hit_points_max();
+
+        let cloak = item::Builder::new()
+            .name("Cloak of Protection")?
+            .armor_class_bonus(1)?
+            .add_effect(Target::SavingThrows, Modifier::Add(1))?
+            .requires_attunement()?
+            .build()?;
+        character.add_item(cloak);
+        character.attune_item("Cloak of Protection")?;
 
         assert_eq!(
-            character.get_saving_throw_mod(ability::Identifier::Strength),
-            -1
+            character.get_saving_throw_mod(ability::Identifier::Wisdom),
+            wisdom_save
+        );
+
+        character.equip_from_inventory("Cloak of Protection")?;
+
+        assert_eq!(
+            character.get_saving_throw_mod(ability::Identifier::Wisdom),
+            wisdom_save + 1
+        );
+
+        let amulet = item::Builder::new()
+            .name("Amulet of Health")?
+            .add_effect(
+                Target::AbilityScore(ability::Identifier::Constitution),
+                Modifier::Minimum(19),
+            )?
+            .build()?;
+        character.equip_item(amulet, "neck")?;
+
+        assert_eq!(
+            character.get_ability_score(ability::Identifier::Constitution),
+            19
+        );
+        assert_eq!(
+            character
+                .get_ability_score_breakdown(ability::Identifier::Constitution)
+                .to_string(),
+            "19 (Amulet of Health)"
         );
+        assert!(character.get_hit_points_max() > hit_points);
+
+        Ok(())
     }
 
     #[test]
-    fn _should_get_saving_throw_mod_including_proficiency_bonus() {
+    fn _registered_effects_should_report_their_source() {
         let mut character = Character::dummy();
-        character.add_class(Class::artificer());
+        let stealth = CheckKind::Skill(skills::Identifier::Stealth);
+
+        character.add_effect(Effect::new(
+            "Pass without Trace",
+            Target::Skill(skills::Identifier::Stealth),
+            Modifier::Add(10),
+        ));
+        character.add_effect(Effect::new(
+            "Skulker",
+            Target::Skill(skills::Identifier::Stealth),
+            Modifier::Proficiency(Proficiency::Proficiency),
+        ));
 
         assert_eq!(
-            character.get_saving_throw_mod(ability::Identifier::Constitution),
-            1
+            character.get_skill_proficiency(skills::Identifier::Stealth),
+            Some(Proficiency::Proficiency)
         );
-    }
+        assert!(character
+            .get_check_components(stealth)
+            .iter()
+            .any(|component| component.get_source() == "Pass without Trace"
+                && component.get_value() == 10));
 
-    #[test]
-    fn _should_get_initial_size_from_race() {
-        let character = Character::dummy();
+        character.remove_effects_from("Pass without Trace");
 
-        assert_eq!(character.get_size(), &Size::Medium);
+        assert!(character
+            .get_check_components(stealth)
+            .iter()
+            .all(|component| component.get_source() != "Pass without Trace"));
     }
 
     #[test]
-    fn _should_get_initial_walking_speed_from_race() {
-        let character = Character::dummy();
+    fn _conditions_should_apply_their_effects() {
+        let mut character = Character::dummy();
+        character.add_effect(Effect::new(
+            "Longstrider",
+            Target::WalkingSpeed,
+            Modifier::Add(10),
+        ));
 
-        assert_eq!(character.get_walking_speed(), 30);
-    }
+        assert_eq!(character.get_walking_speed(), 40);
 
-    #[test]
-    fn _characters_with_strength_and_no_items_should_not_be_encumbered() {
-        let character = Character::dummy();
+        character.add_condition(Condition::Grappled);
 
-        assert_eq!(character.get_variant_encumbrance(), None);
+        assert_eq!(character.get_walking_speed(), 0);
+        assert_eq!(
+            character.get_walking_speed_breakdown().to_string(),
+            "0 (Grappled)"
+        );
+
+        character.remove_condition(Condition::Grappled);
+        character.add_condition(Condition::Petrified);
+
+        assert_eq!(character.get_damage_multiplier(DamageType::Fire), 0.5);
+        assert_eq!(character.get_damage_multiplier(DamageType::Poison), 0.0);
     }
 
     #[test]
-    fn _characters_with_more_than_5_times_strength_score_in_item_weight_should_be_encumbered(
-    ) -> CharacterResult<()> {
+    fn _feat_effects_should_apply() {
         let mut character = Character::dummy();
+        let initiative = character.get_initiative();
 
-        let item = item::Builder::new().name("test")?.weight(46)?.build()?;
-        character.add_item(item);
+        let mut class = Class::wizard();
+        class
+            .add_feat(
+                Feat::new("Alert", "Always on the lookout for danger.")
+                    .add_effect(Target::Initiative, Modifier::Add(5)),
+            )
+            .unwrap();
+        character.add_class(class);
 
+        assert_eq!(character.get_initiative(), initiative + 5);
         assert_eq!(
-            character.get_variant_encumbrance(),
-            Some(Encumbrance::Encumbered)
+            character.get_initiative_breakdown().to_string(),
+            format!("{initiative} (Dexterity) + 5 (Alert)")
         );
-
-        Ok(())
     }
 
     #[test]
-    fn _characters_with_more_than_10_times_str_score_in_item_weight_should_be_heavily_encumbered(
-    ) -> CharacterResult<()> {
+    fn _feats_should_check_prerequisites_when_taken_as_improvements() -> CharacterResult<()> {
         let mut character = Character::dummy();
+        let mut wizard = Class::wizard();
+        wizard.set_level(8).unwrap();
+        character.add_class(wizard);
 
-        let item = item::Builder::new().name("test")?.weight(91)?.build()?;
-        character.add_item(item);
+        let elven_accuracy = Feat::new("Elven Accuracy", "Roll a third die with advantage.")
+            .add_prerequisite(feat::Prerequisite::AnyOf(vec![
+                feat::Prerequisite::Race(String::from("Elf")),
+                feat::Prerequisite::Race(String::from("Half-Elf")),
+            ]))
+            .ability_increase([
+                ability::Identifier::Dexterity,
+                ability::Identifier::Intelligence,
+            ]);
+        let war_caster = Feat::new("War Caster", "Cast a spell as an opportunity attack.")
+            .add_prerequisite(feat::Prerequisite::Spellcasting);
+
+        assert!(matches!(
+            character.add_ability_score_improvement(
+                "Wizard",
+                4,
+                AbilityScoreImprovement::Feat(elven_accuracy.clone())
+            ),
+            Err(Error::Feat(FeatError::AbilityNotChosen(_)))
+        ));
+        assert!(matches!(
+            elven_accuracy
+                .clone()
+                .choose_ability(ability::Identifier::Strength),
+            Err(FeatError::AbilityNotOffered(ability::Identifier::Strength))
+        ));
+        assert!(matches!(
+            character.add_ability_score_improvement(
+                "Wizard",
+                4,
+                AbilityScoreImprovement::Feat(
+                    elven_accuracy.choose_ability(ability::Identifier::Intelligence)?
+                )
+            ),
+            Err(Error::Feat(FeatError::PrerequisiteNotMet(_, _)))
+        ));
+
+        character.add_ability_score_improvement(
+            "Wizard",
+            4,
+            AbilityScoreImprovement::Feat(war_caster),
+        )?;
+        character.add_ability_score_improvement(
+            "Wizard",
+            8,
+            AbilityScoreImprovement::Feat(
+                Feat::new("Fey Touched", "Learn Misty Step and a 1st-level spell.")
+                    .ability_increase([
+                        ability::Identifier::Intelligence,
+                        ability::Identifier::Wisdom,
+                        ability::Identifier::Charisma,
+                    ])
+                    .choose_ability(ability::Identifier::Intelligence)?,
+            ),
+        )?;
 
         assert_eq!(
-            character.get_variant_encumbrance(),
-            Some(Encumbrance::HeavilyEncumbered)
+            character.get_ability_score(ability::Identifier::Intelligence),
+            10
+        );
+        assert_eq!(
+            character
+                .get_feats()
+                .into_iter()
+                .map(|(source, feat)| format!("{} ({source})", feat.get_name()))
+                .collect::<Vec<_>>(),
+            vec![
+                "War Caster (Ability Score Improvement (Wizard 4))",
+                "Fey Touched (Ability Score Improvement (Wizard 8))"
+            ]
         );
 
         Ok(())
     }
 
     #[test]
-    fn _should_include_inventory_and_equipment_in_total_carried_weight() -> CharacterResult<()> {
+    fn _feats_should_check_prerequisites_when_granted_by_a_class_or_race() -> CharacterResult<()> {
         let mut character = Character::dummy();
+        character.add_class(Class::wizard());
 
-        let rapier = item::Builder::new()
-            .name("Rapier")?
-            .weight(2)?
-            .add_type("weapon")?
-            .build()?;
-        character.add_item(rapier);
-
-        character.add_equipment_slot("armor", Slot::new(|_| true));
-        let chain_mail = item::Builder::new()
-            .name("Chain Mail")?
-            .weight(55)?
-            .add_type("armor")?
-            .armor_class(ArmorClass::Heavy(16))?
-            .build()?;
-        let _ = character.equip_item(chain_mail, "armor");
-
-        assert_eq!(character.get_total_weight_carried(), 57);
+        let grappler = Feat::new(
+            "Grappler",
+            "Advantage on attacks against grappled creatures.",
+        )
+        .add_prerequisite(feat::Prerequisite::AbilityScore(
+            ability::Identifier::Strength,
+            13,
+        ));
+        let skill_expert = Feat::new("Skill Expert", "Gain a proficiency and an expertise.")
+            .ability_increase(ability::Identifier::all());
+
+        assert!(matches!(
+            character.add_class_feat("Wizard", grappler.clone()),
+            Err(Error::Feat(FeatError::PrerequisiteNotMet(_, _)))
+        ));
+        assert!(matches!(
+            character.add_race_feat(grappler),
+            Err(Error::Feat(FeatError::PrerequisiteNotMet(_, _)))
+        ));
+        assert!(matches!(
+            character.add_race_feat(skill_expert.clone()),
+            Err(Error::Feat(FeatError::AbilityNotChosen(_)))
+        ));
+        assert_eq!(
+            character.race.add_feat(skill_expert.clone()),
+            Err(FeatError::AbilityNotChosen(String::from("Skill Expert")))
+        );
+        assert!(matches!(
+            character.add_class_feat(
+                "Fighter",
+                skill_expert
+                    .clone()
+                    .choose_ability(ability::Identifier::Wisdom)?
+            ),
+            Err(Error::ClassNotFound(_))
+        ));
+
+        character.add_class_feat(
+            "Wizard",
+            skill_expert.choose_ability(ability::Identifier::Wisdom)?,
+        )?;
+
+        assert_eq!(character.get_feats().len(), 1);
+        assert_eq!(character.get_ability_score(ability::Identifier::Wisdom), 10);
 
         Ok(())
     }
 
     #[test]
-    fn _encumbered_characters_should_reduce_their_speed_by_10() -> CharacterResult<()> {
+    fn _infused_arcane_armor_pieces_should_apply_when_equipped() -> CharacterResult<()> {
         let mut character = Character::dummy();
+        character.change_slot_layout(SlotLayout::armorer());
+
+        assert!(matches!(
+            character.learn_infusion(Infusion::enhanced_defense()),
+            Err(Error::Infusion(InfusionError::LevelNotReached(_, 2)))
+        ));
+
+        let mut artificer = Class::artificer();
+        artificer.set_level(3).unwrap();
+        character.add_class(artificer);
+        character.learn_infusion(Infusion::enhanced_defense())?;
+
+        let plate = item::Builder::new()
+            .name("Plate")?
+            .armor_class(ArmorClass::Heavy(18))?
+            .strength_requirement(15)?
+            .build()?;
+        let mut arcane_armor = ArcaneArmor::new(plate).unwrap();
+        arcane_armor.set_model(ArmorModel::Infiltrator);
 
-        let item = item::Builder::new().name("test")?.weight(46)?.build()?;
-        character.add_item(item);
+        for (piece, item) in Piece::ALL.into_iter().zip(arcane_armor.get_pieces()?) {
+            character.add_item(item);
 
-        assert_eq!(character.get_walking_speed(), 20);
+            if piece != Piece::Chestplate {
+                character.equip_from_inventory(&format!("Plate {piece}"))?;
+            }
+        }
+
+        assert!(matches!(
+            character.infuse_item("Enhanced Defense", "Plate"),
+            Err(Error::ItemNotFound(_))
+        ));
+        character.infuse_item("Enhanced Defense", "Plate Chestplate")?;
+        character.finish_rest(Rest::Long);
+        assert_eq!(character.get_armor_class(), 9);
+
+        character.equip_from_inventory("Plate Chestplate")?;
+        assert_eq!(character.get_armor_class(), 19);
+        assert_eq!(character.get_walking_speed(), 35);
+        assert!(character
+            .get_equipment()
+            .get_item_in("gauntlets")
+            .and_then(Item::get_weapon)
+            .is_some_and(|weapon| weapon.get_range().is_some()));
+
+        character.end_infusion("Plate Chestplate")?;
+        character.finish_rest(Rest::Long);
+        assert_eq!(character.get_armor_class(), 18);
 
         Ok(())
     }
 
     #[test]
-    fn _heavily_encumbered_characters_should_reduce_their_speed_by_20() -> CharacterResult<()> {
+    fn _infusions_should_stay_with_their_items() -> CharacterResult<()> {
         let mut character = Character::dummy();
+        let mut artificer = Class::artificer();
+        artificer.set_level(3).unwrap();
+        character.add_class(artificer);
+        character.learn_infusion(Infusion::enhanced_defense())?;
+
+        let shield = item::Builder::new()
+            .name("Shield")?
+            .armor_class(ArmorClass::Shield(2))?
+            .build()?;
+        character.add_item(shield.clone());
+        character.add_item(shield);
+        character.add_item(
+            item::Builder::new()
+                .name("Plate")?
+                .armor_class(ArmorClass::Heavy(18))?
+                .build()?,
+        );
 
-        let item = item::Builder::new().name("test")?.weight(91)?.build()?;
-        character.add_item(item);
+        character.infuse_item("Enhanced Defense", "Shield")?;
+        character.infuse_item("Enhanced Defense", "Shield")?;
+        character.infuse_item("Enhanced Defense", "Shield")?;
+        character.finish_rest(Rest::Long);
+        assert_eq!(
+            character
+                .get_infused_items()
+                .iter()
+                .map(|item| item.get_name())
+                .collect::<Vec<_>>(),
+            vec!["Shield", "Shield"]
+        );
 
-        assert_eq!(character.get_walking_speed(), 10);
+        character.infuse_item("Enhanced Defense", "Plate")?;
+        character.finish_rest(Rest::Long);
+        assert_eq!(
+            character
+                .get_infused_items()
+                .iter()
+                .map(|item| item.get_name())
+                .collect::<Vec<_>>(),
+            vec!["Shield", "Plate"]
+        );
+
+        let plate = character.drop_item("Plate")?;
+        assert!(plate.get_infusion().is_none());
+        assert_eq!(character.get_infused_items().len(), 1);
+        assert!(matches!(
+            character.end_infusion("Plate"),
+            Err(Error::Infusion(InfusionError::NotInfused(_)))
+        ));
 
         Ok(())
     }
 
     #[test]
-    fn _characters_with_2_or_more_exhaustion_should_half_their_movement_speed() {
+    fn _stored_infused_items_should_count_towards_the_limit() -> CharacterResult<()> {
         let mut character = Character::dummy();
+        let mut artificer = Class::artificer();
+        artificer.set_level(3).unwrap();
+        character.add_class(artificer);
+        character.learn_infusion(Infusion::enhanced_defense())?;
+
+        for (name, armor_class) in [
+            ("Shield", ArmorClass::Shield(2)),
+            ("Buckler", ArmorClass::Shield(1)),
+            ("Plate", ArmorClass::Heavy(18)),
+        ] {
+            character.add_item(
+                item::Builder::new()
+                    .name(name)?
+                    .armor_class(armor_class)?
+                    .build()?,
+            );
+        }
 
-        character.set_exhaustion_level(2);
-        assert_eq!(character.get_walking_speed(), 15);
+        character.add_item(
+            item::Builder::new()
+                .name("Backpack")?
+                .container(container::Container::new())?
+                .build()?,
+        );
 
-        character.set_exhaustion_level(3);
-        assert_eq!(character.get_walking_speed(), 15);
+        character.infuse_item("Enhanced Defense", "Shield")?;
+        character.infuse_item("Enhanced Defense", "Plate")?;
+        character.finish_rest(Rest::Long);
+        character.store_item("Shield", "Backpack")?;
+        assert_eq!(character.get_infused_items().len(), 2);
 
-        character.set_exhaustion_level(4);
-        assert_eq!(character.get_walking_speed(), 15);
-    }
+        character.infuse_item("Enhanced Defense", "Buckler")?;
+        character.finish_rest(Rest::Long);
+        assert_eq!(
+            character
+                .get_infused_items()
+                .iter()
+                .map(|item| item.get_name())
+                .collect::<Vec<_>>(),
+            vec!["Plate", "Buckler"]
+        );
 
-    #[test]
-    fn _characters_with_5_levels_of_exhaustion_should_have_0_movement_speed() {
-        let mut character = Character::dummy();
+        character.store_item("Plate", "Backpack")?;
+        character.end_infusion("Plate")?;
+        character.finish_rest(Rest::Long);
+        assert_eq!(character.get_infused_items().len(), 1);
 
-        character.set_exhaustion_level(5);
-        assert_eq!(character.get_walking_speed(), 0);
+        Ok(())
     }
 
     #[test]
-    fn _skill_modifier_should_default_to_related_ability_modifier() {
-        let character = Character::dummy();
+    fn _infusions_should_only_change_on_a_long_rest() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character.change_slot_layout(SlotLayout::humanoid());
+        let mut artificer = Class::artificer();
+        artificer.set_level(2).unwrap();
+        character.add_class(artificer);
+        character.learn_infusion(Infusion::enhanced_defense())?;
+        character.equip_item(
+            item::Builder::new()
+                .name("Shield")?
+                .armor_class(ArmorClass::Shield(2))?
+                .build()?,
+            "left hand",
+        )?;
+        character.add_item(item::Builder::new().name("Rope")?.build()?);
+
+        assert!(matches!(
+            character.infuse_item("Enhanced Defense", "Rope"),
+            Err(Error::Infusion(InfusionError::WrongTarget(_, _)))
+        ));
+        assert!(matches!(
+            character.end_infusion("Shield"),
+            Err(Error::Infusion(InfusionError::NotInfused(_)))
+        ));
+
+        let armor_class = character.get_armor_class();
+        character.infuse_item("Enhanced Defense", "Shield")?;
+        assert_eq!(character.get_infusions().get_planned().len(), 1);
+        character.finish_rest(Rest::Short);
+        assert_eq!(character.get_armor_class(), armor_class);
+
+        character.finish_rest(Rest::Long);
+        assert_eq!(character.get_armor_class(), armor_class + 1);
+        assert!(character.get_infusions().get_planned().is_empty());
+
+        character.end_infusion("Shield")?;
+        character.cancel_planned_infusions();
+        character.finish_rest(Rest::Long);
+        assert_eq!(character.get_armor_class(), armor_class + 1);
+
+        character.end_infusion("Shield")?;
+        character.finish_rest(Rest::Long);
+        assert_eq!(character.get_armor_class(), armor_class);
 
-        assert_eq!(character.get_skill_modifier(skills::Identifier::Arcana), -1);
+        Ok(())
     }
 
     #[test]
-    fn _proficiency_should_affect_skill_modifier() {
+    fn _charged_acts_should_scale_and_start_concentration() -> CharacterResult<()> {
         let mut character = Character::dummy();
+        let phantom_foe = Act::new(
+            "Phantom Foe",
+            "1d8 psychic damage at the start of each of its turns.",
+            3..=7,
+            Some(Duration::Minutes(1)),
+        )
+        .damage(Roll::new(1, 8, 0), DamageType::Psychic)
+        .save(ability::Identifier::Intelligence)
+        .scaling(discipline::Scaling::dice(1, 8));
+        let mind_thrust = Act::new("Mind Thrust", "2d6 psychic damage.", 2..=7, None)
+            .damage(Roll::new(2, 6, 0), DamageType::Psychic)
+            .scaling(discipline::Scaling::dice(1, 6));
+        let discipline: discipline::Discipline = discipline::Builder::new()
+            .name("Psychic Phantoms")
+            .order("Awakened")
+            .description("False perceptions.")
+            .focus("Advantage on Charisma (Deception) checks.")
+            .add_act(&phantom_foe)
+            .add_act(&mind_thrust)
+            .try_into()
+            .unwrap();
+        character.psionics = Some(Mystic::new(9).add_discipline(discipline));
+
+        let resolved = character.perform_act("Phantom Foe", 2)?;
+        assert_eq!(resolved.get_psi_points(), 5);
+        assert_eq!(
+            resolved.get_damage(),
+            Some(&(Roll::new(3, 8, 0), DamageType::Psychic))
+        );
+        assert_eq!(resolved.get_save(), Some(ability::Identifier::Intelligence));
+        assert_eq!(
+            character.get_concentration().map(Concentration::get_source),
+            Some("Phantom Foe")
+        );
 
-        let mut class = Class::wizard();
-        class.set_level(1).unwrap();
-        character.add_class(class);
+        let resolved = character.perform_act("Mind Thrust", 0)?;
+        assert_eq!(
+            resolved.get_damage(),
+            Some(&(Roll::new(2, 6, 0), DamageType::Psychic))
+        );
+        assert!(character.get_concentration().is_some());
 
-        character
-            .skills
-            .set_proficiency(skills::Identifier::Arcana, Some(Proficiency::Proficiency));
+        character.add_condition(Condition::Stunned);
+        assert!(character.get_concentration().is_none());
 
-        assert_eq!(character.get_skill_modifier(skills::Identifier::Arcana), 1);
+        Ok(())
     }
 
     #[test]
-    fn _expertise_should_affect_skill_modifier() {
+    fn _psi_points_should_be_spent_on_acts_and_regained_on_a_long_rest() -> CharacterResult<()> {
         let mut character = Character::dummy();
+        assert!(matches!(
+            character.perform_act("Phantom Foe", 0),
+            Err(Error::NoPsionics)
+        ));
+
+        let discipline: discipline::Discipline = discipline::Builder::new()
+            .name("Psychic Phantoms")
+            .order("Awakened")
+            .description("False perceptions.")
+            .focus("Advantage on Charisma (Deception) checks.")
+            .add_act(&Act::new("Phantom Foe", "1d8 psychic damage.", 3..=7, None))
+            .try_into()
+            .unwrap();
+        character.psionics = Some(Mystic::new(5).add_discipline(discipline));
+
+        let preview = character.preview_act("Phantom Foe", 2)?;
+        assert_eq!(preview.get_remaining(), Some(22));
+        assert!(!preview.exceeds_psi_limit());
+
+        character.perform_act("phantom foe", 2)?;
+        assert_eq!(
+            character.get_psionics().map(Psionics::get_psi_points),
+            Some(22)
+        );
+        assert!(matches!(
+            character.perform_act("Mind Thrust", 0),
+            Err(Error::Act(ActError::ActNotPracticed))
+        ));
+
+        character.focus_on("Psychic Phantoms")?;
+        assert!(matches!(
+            character.focus_on("Third Eye"),
+            Err(Error::DisciplineNotFound(_))
+        ));
+
+        character.finish_rest(Rest::Short);
+        assert_eq!(
+            character.get_psionics().map(Psionics::get_psi_points),
+            Some(22)
+        );
+        character.finish_rest(Rest::Long);
+        assert_eq!(
+            character.get_psionics().map(Psionics::get_psi_points),
+            Some(27)
+        );
 
-        let mut class = Class::wizard();
-        class.set_level(1).unwrap();
-
-        character.add_class(class);
-        character
-            .skills
-            .set_proficiency(skills::Identifier::Arcana, Some(Proficiency::Expertise));
-
-        assert_eq!(character.get_skill_modifier(skills::Identifier::Arcana), 3);
+        Ok(())
     }
 
     #[test]
-    fn _passive_perception_should_be_10_plus_perception_modifier() {
+    fn _alert_and_tough_should_raise_initiative_and_hit_points_per_level() {
         let mut character = Character::dummy();
-        character.add_class(Class::wizard());
-
-        assert_eq!(character.get_passive_perception(), 9);
-
-        character.skills.set_proficiency(
-            skills::Identifier::Perception,
-            Some(Proficiency::Proficiency),
-        );
-        assert_eq!(character.get_passive_perception(), 11);
+        let mut wizard = Class::wizard();
+        wizard.set_level(3).unwrap();
+        character.add_class(wizard);
+        let hit_points = character.get_hit_points_max();
+        let initiative = character.get_initiative();
+
+        character.add_race_feat(Feat::alert()).unwrap();
+        character.add_race_feat(Feat::tough()).unwrap();
+
+        assert_eq!(character.get_initiative(), initiative + 5);
+        assert_eq!(character.get_hit_points_max(), hit_points + 6);
+        assert!(character
+            .get_hit_points_max_breakdown()
+            .to_string()
+            .ends_with("+ 6 (Tough)"));
     }
 
     #[test]
-    fn _passive_investigation_should_be_10_plus_investigation_modifier() {
+    fn _ability_score_improvements_should_stop_at_the_maximum() -> CharacterResult<()> {
         let mut character = Character::dummy();
-        character.add_class(Class::wizard());
+        let strength = ability::Identifier::Strength;
+        let mut wizard = Class::wizard();
+        wizard.set_level(8).unwrap();
+        character.add_class(wizard);
+        character.base_ability_scores.set_score(strength, 17);
+
+        character.add_ability_score_improvement(
+            "Wizard",
+            4,
+            AbilityScoreImprovement::Increase(strength, strength),
+        )?;
+        character.add_ability_score_improvement(
+            "Wizard",
+            8,
+            AbilityScoreImprovement::Feat(
+                Feat::new("Athlete", "Climb faster and stand up from prone easily.")
+                    .add_effect(Target::AbilityScore(strength), Modifier::Add(1)),
+            ),
+        )?;
+
+        assert_eq!(character.get_ability_score(strength), 20);
+        assert_eq!(
+            character
+                .get_abilities()
+                .get_breakdown(strength)
+                .map(ToString::to_string),
+            Some(String::from(
+                "17 (Base) + 1 (Human) + 2 (Ability Score Improvement (Wizard 4)) + 1 (Athlete) \
+                 - 1 (Maximum)"
+            ))
+        );
 
-        assert_eq!(character.get_passive_investigation(), 9);
+        for modifier in [Modifier::Add(2), Modifier::RaiseMaximum(2)] {
+            character.add_effect(Effect::new(
+                "Manual of Gainful Exercise",
+                Target::AbilityScore(strength),
+                modifier,
+            ));
+        }
 
-        character.skills.set_proficiency(
-            skills::Identifier::Investigation,
-            Some(Proficiency::Proficiency),
-        );
-        assert_eq!(character.get_passive_investigation(), 11);
+        assert_eq!(character.get_ability_score_maximum(strength), 22);
+        assert_eq!(character.get_ability_score(strength), 22);
+
+        for (level, expected) in [
+            (4, ImprovementError::AlreadyTaken(4)),
+            (5, ImprovementError::NotAnImprovementLevel(5)),
+            (12, ImprovementError::LevelNotReached(12)),
+        ] {
+            assert!(matches!(
+                character.add_ability_score_improvement(
+                    "Wizard",
+                    level,
+                    AbilityScoreImprovement::Increase(strength, strength),
+                ),
+                Err(Error::Improvement(error)) if error == expected
+            ));
+        }
+        assert!(matches!(
+            character.add_ability_score_improvement(
+                "Fighter",
+                4,
+                AbilityScoreImprovement::Increase(strength, strength),
+            ),
+            Err(Error::ClassNotFound(_))
+        ));
+
+        Ok(())
     }
 
     #[test]
-    fn _passive_insight_should_be_10_plus_insight_modifier() {
+    fn _set_scores_should_ignore_the_maximum() {
         let mut character = Character::dummy();
-        character.add_class(Class::wizard());
+        let strength = ability::Identifier::Strength;
+        let constitution = ability::Identifier::Constitution;
+
+        character.add_effect(Effect::new(
+            "Gauntlets of Ogre Power",
+            Target::AbilityScore(strength),
+            Modifier::Minimum(19),
+        ));
+
+        assert_eq!(character.get_ability_score(strength), 19);
+
+        character.base_ability_scores.set_score(constitution, 20);
+        for modifier in [Modifier::Add(4), Modifier::RaiseMaximum(4)] {
+            character.add_effect(Effect::new(
+                "Primal Champion",
+                Target::AbilityScore(constitution),
+                modifier,
+            ));
+        }
 
-        assert_eq!(character.get_passive_insight(), 9);
+        assert_eq!(character.get_ability_score(constitution), 24);
 
-        character
-            .skills
-            .set_proficiency(skills::Identifier::Insight, Some(Proficiency::Proficiency));
-        assert_eq!(character.get_passive_insight(), 11);
+        character.add_effect(Effect::new(
+            "Headband of Focus",
+            Target::AbilityScore(constitution),
+            Modifier::Set(26),
+        ));
+
+        assert_eq!(character.get_ability_score(constitution), 26);
     }
 
     #[test]
-    fn _should_obtain_feats_from_classes_and_race() {
+    fn _racial_selections_should_be_recorded_and_changeable() -> CharacterResult<()> {
         let mut character = Character::dummy();
+        let half_elf = race::Builder::new()
+            .name("Half-Elf")
+            .add_ability(ability::Identifier::Charisma, 2)
+            .add_choice(race::Choice::AbilityScores {
+                count: 2,
+                amount: 1,
+                except: vec![ability::Identifier::Charisma],
+            })
+            .add_choice(race::Choice::Skill(vec![]))
+            .build()
+            .unwrap();
+        character.change_race(half_elf);
+
+        assert!(matches!(
+            character.select_race_option(
+                0,
+                race::Selection::AbilityScores(vec![
+                    ability::Identifier::Charisma,
+                    ability::Identifier::Wisdom
+                ])
+            ),
+            Err(Error::RaceChoice(race::ChoiceError::AbilityNotAllowed(
+                ability::Identifier::Charisma
+            )))
+        ));
+
+        character.select_race_option(
+            0,
+            race::Selection::AbilityScores(vec![
+                ability::Identifier::Strength,
+                ability::Identifier::Wisdom,
+            ]),
+        )?;
+        character.select_race_option(1, race::Selection::Skill(skills::Identifier::Perception))?;
 
-        let spell_sniper = Feat::new(
-            "Spell Sniper",
-            "Doubles casting distance and ignores half cover.",
+        assert_eq!(
+            character.get_ability_score(ability::Identifier::Strength),
+            9
+        );
+        assert_eq!(
+            character.get_skill_proficiency(skills::Identifier::Perception),
+            Some(Proficiency::Proficiency)
         );
-        let mut wizard = Class::wizard();
-        wizard.add_feat(spell_sniper.clone());
 
-        character.add_class(wizard);
+        character.select_race_option(
+            0,
+            race::Selection::AbilityScores(vec![
+                ability::Identifier::Dexterity,
+                ability::Identifier::Wisdom,
+            ]),
+        )?;
 
-        let elven_accuracy = Feat::new(
-            "Elven Accuracy",
-            "When rolling advantage on ranged checks, roll a third die.",
+        assert_eq!(
+            character.get_ability_score(ability::Identifier::Strength),
+            8
+        );
+        assert_eq!(
+            character.get_ability_score(ability::Identifier::Dexterity),
+            9
         );
-        let mut shadar_kai = Race::shadar_kai();
-        shadar_kai.add_feat(elven_accuracy.clone());
-        character.race = shadar_kai;
 
-        assert_eq!(character.get_feats(), vec![&spell_sniper, &elven_accuracy]);
+        Ok(())
     }
 
     #[test]
-    fn _should_derive_armor_class_from_equipment_and_con_mod() -> CharacterResult<()> {
+    fn _background_grants_should_flow_into_skills_tools_and_languages() -> CharacterResult<()> {
         let mut character = Character::dummy();
-        character.add_equipment_slot("chestplate", Slot::new(|_| true));
-        character.add_equipment_slot("helmet", Slot::new(|_| true));
+        let sailor: Background = background::Builder::new()
+            .name("Sailor")
+            .description("Years aboard a seagoing vessel.")
+            .feature(background::Feature::new(
+                "Ship's Passage",
+                "Free passage on a ship.",
+            ))
+            .proficiencies(background::Proficiencies::two_skills_one_tool_one_language(
+                skills::Identifier::Athletics,
+                skills::Identifier::Perception,
+                "Navigator's Tools",
+                "Aquan",
+            ))
+            .ability_scores([
+                ability::Identifier::Strength,
+                ability::Identifier::Dexterity,
+                ability::Identifier::Wisdom,
+            ])
+            .try_into()
+            .unwrap();
+
+        assert!(matches!(
+            character.choose_background_ability_scores(AbilityScoreIncrease::OneEach),
+            Err(Error::NoBackground)
+        ));
+
+        character.change_background(sailor);
+        character.choose_background_ability_scores(AbilityScoreIncrease::TwoAndOne(
+            ability::Identifier::Strength,
+            ability::Identifier::Wisdom,
+        ))?;
 
-        let breastplate = item::Builder::new()
-            .name("Breastplate")?
-            .weight(25)?
-            .add_type("armor")?
-            .armor_class(ArmorClass::Medium(14))?
-            .build()?;
-        character.equip_item(breastplate, "chestplate")?;
+        assert_eq!(
+            character.get_skill_proficiency(skills::Identifier::Athletics),
+            Some(Proficiency::Proficiency)
+        );
+        assert_eq!(
+            character.get_tool_proficiencies(),
+            vec![Tool::named("navigator's tools")]
+        );
+        assert_eq!(character.get_languages_string(), "Common, Aquan");
+        assert_eq!(
+            character
+                .get_ability_score_breakdown(ability::Identifier::Strength)
+                .to_string(),
+            "8 (Base) + 1 (Human) + 2 (Sailor)"
+        );
+        assert!(matches!(
+            character.choose_background_ability_scores(AbilityScoreIncrease::TwoAndOne(
+                ability::Identifier::Charisma,
+                ability::Identifier::Wisdom,
+            )),
+            Err(Error::BackgroundAbilityScores(_))
+        ));
 
-        let pickelbonnet = item::Builder::new()
-            .name("Pickelbonnet")?
-            .weight(2)?
-            .add_type("armor")?
-            .armor_class(ArmorClass::Heavy(3))?
-            .build()?;
-        character.equip_item(pickelbonnet, "helmet")?;
+        Ok(())
+    }
 
-        assert_eq!(character.get_armor_class(), 16);
+    #[test]
+    fn _background_duplicates_should_allow_a_replacement() -> CharacterResult<()> {
+        let mut character = Character::dummy();
+        character
+            .skills
+            .set_proficiency(skills::Identifier::Insight, Some(Proficiency::Proficiency));
+        let acolyte: Background = background::Builder::new()
+            .name("Acolyte")
+            .description("A life in service to a temple.")
+            .feature(background::Feature::new(
+                "Shelter of the Faithful",
+                "Temples of your faith take you in.",
+            ))
+            .proficiencies(
+                background::Proficiencies::new()
+                    .grant(Grant::Skill(skills::Identifier::Insight))
+                    .grant(Grant::Skill(skills::Identifier::Religion))
+                    .choose_any(2, background::GrantKind::Language),
+            )
+            .try_into()
+            .unwrap();
+        character.change_background(acolyte);
+
+        assert_eq!(character.get_open_background_choices(), vec![2]);
+        assert!(matches!(
+            character.choose_background_proficiencies(
+                2,
+                vec![Grant::language("common"), Grant::language("Elvish")]
+            ),
+            Err(Error::BackgroundGrant(GrantError::AlreadyKnown(_)))
+        ));
+
+        character.choose_background_proficiencies(
+            2,
+            vec![Grant::language("Celestial"), Grant::language("Elvish")],
+        )?;
+        character.replace_background_proficiency(
+            Grant::Skill(skills::Identifier::Insight),
+            Grant::Skill(skills::Identifier::History),
+        )?;
+
+        assert!(character.get_open_background_choices().is_empty());
+        assert_eq!(
+            character.get_skill_proficiency(skills::Identifier::History),
+            Some(Proficiency::Proficiency)
+        );
+        assert_eq!(
+            character.get_languages_string(),
+            "Common, Celestial, Elvish"
+        );
+        assert!(matches!(
+            character.replace_background_proficiency(
+                Grant::Skill(skills::Identifier::Religion),
+                Grant::Skill(skills::Identifier::Arcana),
+            ),
+            Err(Error::BackgroundGrant(GrantError::NotDuplicated(_)))
+        ));
 
         Ok(())
     }
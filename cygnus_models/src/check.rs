@@ -0,0 +1,268 @@
+#![warn(clippy::pedantic)]
+
+use std::fmt;
+
+use crate::{
+    ability,
+    dice::Roller,
+    skills::{self, Advantage},
+};
+
+pub use crate::effect::Component;
+
+/// What a d20 check is made for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CheckKind {
+    Ability(ability::Identifier),
+    Skill(skills::Identifier),
+    SavingThrow(ability::Identifier),
+}
+
+impl CheckKind {
+    #[must_use]
+    pub fn get_ability(&self) -> ability::Identifier {
+        match self {
+            CheckKind::Ability(ability) | CheckKind::SavingThrow(ability) => *ability,
+            CheckKind::Skill(skill) => skill.get_default_ability(),
+        }
+    }
+
+    /// Skill checks are ability checks too; saving throws are not.
+    #[must_use]
+    pub fn is_ability_check(&self) -> bool {
+        !matches!(self, CheckKind::SavingThrow(_))
+    }
+}
+
+impl fmt::Display for CheckKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            CheckKind::Ability(ability) => format!("{ability} check"),
+            CheckKind::Skill(skill) => format!("{skill} check"),
+            CheckKind::SavingThrow(ability) => format!("{ability} save"),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// Features that change how ability checks are made.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckRule {
+    /// Adds half the proficiency bonus, rounded down, to ability checks that don't already
+    /// include it, as with Jack of All Trades.
+    HalfProficiency,
+    /// Treats any lower d20 roll as this number on ability checks that include the proficiency
+    /// bonus, as with Reliable Talent.
+    MinimumRoll(usize),
+}
+
+impl fmt::Display for CheckRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            CheckRule::HalfProficiency => "Jack of All Trades",
+            CheckRule::MinimumRoll(_) => "Reliable Talent",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// A rolled check with everything that went into it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    kind: CheckKind,
+    advantage: Option<Advantage>,
+    rolls: Vec<usize>,
+    natural: usize,
+    die: usize,
+    components: Vec<Component>,
+    automatic_failure: bool,
+}
+
+impl Check {
+    /// Rolls the d20, twice with advantage or disadvantage, raising the kept roll to
+    /// `minimum_roll` if one applies.
+    pub fn roll(
+        kind: CheckKind,
+        advantage: Option<Advantage>,
+        components: Vec<Component>,
+        minimum_roll: Option<usize>,
+        roller: &mut impl Roller,
+    ) -> Self {
+        let rolls: Vec<usize> = match advantage {
+            Some(_) => vec![roller.roll_die(20), roller.roll_die(20)],
+            None => vec![roller.roll_die(20)],
+        };
+
+        let natural = match advantage {
+            Some(Advantage::Disadvantage) => rolls.iter().min(),
+            _ => rolls.iter().max(),
+        }
+        .copied()
+        .unwrap_or(1);
+
+        Check {
+            kind,
+            advantage,
+            rolls,
+            natural,
+            die: natural.max(minimum_roll.unwrap_or(0)),
+            components,
+            automatic_failure: false,
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn fail_automatically(mut self) -> Self {
+        self.automatic_failure = true;
+
+        self
+    }
+
+    #[must_use]
+    pub fn get_kind(&self) -> CheckKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn get_advantage(&self) -> Option<Advantage> {
+        self.advantage
+    }
+
+    /// Every d20 rolled, in order.
+    #[must_use]
+    pub fn get_rolls(&self) -> &[usize] {
+        &self.rolls
+    }
+
+    /// The d20 kept after advantage or disadvantage.
+    #[must_use]
+    pub fn get_natural_roll(&self) -> usize {
+        self.natural
+    }
+
+    /// The kept d20 after any minimum roll.
+    #[must_use]
+    pub fn get_die(&self) -> usize {
+        self.die
+    }
+
+    #[must_use]
+    pub fn get_components(&self) -> &[Component] {
+        &self.components
+    }
+
+    #[must_use]
+    pub fn get_total(&self) -> isize {
+        isize::try_from(self.die).unwrap_or(isize::MAX)
+            + self
+                .components
+                .iter()
+                .map(Component::get_value)
+                .sum::<isize>()
+    }
+
+    #[must_use]
+    pub fn is_natural_20(&self) -> bool {
+        self.natural == 20
+    }
+
+    #[must_use]
+    pub fn is_natural_1(&self) -> bool {
+        self.natural == 1
+    }
+
+    #[must_use]
+    pub fn is_automatic_failure(&self) -> bool {
+        self.automatic_failure
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+
+        if let Some(advantage) = self.advantage {
+            write!(f, " ({advantage})")?;
+        }
+
+        let rolls = self
+            .rolls
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, ": [{rolls}] -> {}", self.die)?;
+
+        for component in &self.components {
+            let sign = if component.get_value() < 0 { '-' } else { '+' };
+            write!(
+                f,
+                " {sign} {} ({})",
+                component.get_value().abs(),
+                component.get_source()
+            )?;
+        }
+
+        write!(f, " = {}", self.get_total())?;
+
+        if self.automatic_failure {
+            write!(f, " (automatic failure)")
+        } else if self.is_natural_20() {
+            write!(f, " (natural 20)")
+        } else if self.is_natural_1() {
+            write!(f, " (natural 1)")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dice::Loaded;
+
+    use super::*;
+
+    #[test]
+    fn _should_keep_the_higher_roll_with_advantage_and_lower_with_disadvantage() {
+        let kind = CheckKind::Ability(ability::Identifier::Strength);
+
+        let check = Check::roll(
+            kind,
+            Some(Advantage::Advantage),
+            vec![],
+            None,
+            &mut Loaded::new([4, 17]),
+        );
+        assert_eq!(check.get_natural_roll(), 17);
+
+        let check = Check::roll(
+            kind,
+            Some(Advantage::Disadvantage),
+            vec![],
+            None,
+            &mut Loaded::new([4, 17]),
+        );
+        assert_eq!(check.get_natural_roll(), 4);
+    }
+
+    #[test]
+    fn _minimum_roll_should_not_hide_a_natural_1() {
+        let check = Check::roll(
+            CheckKind::Skill(skills::Identifier::Stealth),
+            None,
+            vec![Component::new("Dexterity", 3)],
+            Some(10),
+            &mut Loaded::new([1]),
+        );
+
+        assert!(check.is_natural_1());
+        assert_eq!(check.get_total(), 13);
+        assert_eq!(
+            check.to_string(),
+            "Stealth check: [1] -> 10 + 3 (Dexterity) = 13 (natural 1)"
+        );
+    }
+}
@@ -1,6 +1,163 @@
 use std::{collections::HashMap, error, fmt};
 
-use crate::{ability, feat::Feat, modifiers::Proficiency, spell::SpellList};
+use crate::{
+    ability,
+    effect::Component,
+    feat::{self, Feat, FeatError},
+    feature::{Feature, Rest},
+    item::ArmorCategory,
+    modifiers::Proficiency,
+    proficiencies::Proficiencies,
+    spell::SpellList,
+    tool::Tool,
+};
+
+/// The class level subclasses are chosen at unless a class says otherwise.
+pub const SUBCLASS_LEVEL: usize = 3;
+
+/// The class levels that grant an Ability Score Improvement unless a class says otherwise.
+pub const IMPROVEMENT_LEVELS: [usize; 5] = [4, 8, 12, 16, 19];
+
+/// What a character takes for an Ability Score Improvement.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AbilityScoreImprovement {
+    /// +1 to each ability; naming the same ability twice gives it +2.
+    Increase(ability::Identifier, ability::Identifier),
+    /// A feat taken instead of the increase.
+    Feat(Feat),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImprovementError {
+    NotAnImprovementLevel(usize),
+    LevelNotReached(usize),
+    AlreadyTaken(usize),
+}
+
+impl fmt::Display for ImprovementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            ImprovementError::NotAnImprovementLevel(level) => {
+                format!("Level {level} doesn't grant an Ability Score Improvement.")
+            }
+            ImprovementError::LevelNotReached(level) => {
+                format!("Class hasn't reached level {level} yet.")
+            }
+            ImprovementError::AlreadyTaken(level) => {
+                format!("The level {level} Ability Score Improvement has already been taken.")
+            }
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for ImprovementError {}
+
+/// A specialization of a class, like the Artificer's Armorer or Battle Smith.
+#[derive(Clone, Debug)]
+pub struct Subclass {
+    name: String,
+    features: Vec<Feature>,
+    /// Always prepared spells by the class level they're gained at.
+    spells: Vec<(usize, String)>,
+    proficiencies: Proficiencies,
+}
+
+impl Subclass {
+    pub fn new(name: impl Into<String>) -> Self {
+        Subclass {
+            name: name.into(),
+            features: vec![],
+            spells: vec![],
+            proficiencies: Proficiencies::default(),
+        }
+    }
+
+    /// Adds a feature gained at its class level. Limited features double as the subclass's
+    /// resource pools, e.g. a Battle Master's superiority dice.
+    #[must_use]
+    pub fn add_feature(mut self, feature: Feature) -> Self {
+        self.features.push(feature);
+        self.features.sort_by_key(Feature::get_level);
+
+        self
+    }
+
+    /// Spells always prepared from class level `level` on, without counting against the number
+    /// of prepared spells.
+    #[must_use]
+    pub fn add_spells<S: Into<String>>(
+        mut self,
+        level: usize,
+        spells: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.spells
+            .extend(spells.into_iter().map(|spell| (level, spell.into())));
+        self.spells.sort_by_key(|(level, _)| *level);
+
+        self
+    }
+
+    #[must_use]
+    pub fn add_armor_proficiency(mut self, armor_category: ArmorCategory) -> Self {
+        self.proficiencies.add_armor_proficiency(armor_category);
+
+        self
+    }
+
+    #[must_use]
+    pub fn add_weapon_proficiency(mut self, weapon: impl Into<String>) -> Self {
+        self.proficiencies.add_weapon_proficiency(weapon);
+
+        self
+    }
+
+    #[must_use]
+    pub fn add_tool_proficiency(mut self, tool: Tool) -> Self {
+        self.proficiencies
+            .add_tool_proficiency(tool, Proficiency::Proficiency);
+
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_features(&self) -> &[Feature] {
+        &self.features
+    }
+
+    pub fn get_spells(&self) -> &[(usize, String)] {
+        &self.spells
+    }
+
+    pub fn get_proficiencies(&self) -> &Proficiencies {
+        &self.proficiencies
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SubclassError {
+    LevelNotReached(usize),
+    AlreadyChosen(String),
+}
+
+impl fmt::Display for SubclassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            SubclassError::LevelNotReached(level) => {
+                format!("Subclasses are chosen at level {level}.")
+            }
+            SubclassError::AlreadyChosen(name) => format!("{name} has already been chosen."),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for SubclassError {}
 
 #[derive(Clone, Debug, Default)]
 pub struct HPIncreases(Vec<usize>);
@@ -73,6 +230,9 @@ pub struct Builder {
     spell_list: Option<SpellList>,
     hp_increases: Option<HPIncreases>,
     feats: Vec<Feat>,
+    features: Vec<Feature>,
+    improvement_levels: Option<Vec<usize>>,
+    subclass_level: Option<usize>,
 }
 
 impl Builder {
@@ -133,6 +293,43 @@ impl Builder {
         Ok(self)
     }
 
+    pub fn add_feature(mut self, feature: Feature) -> Result<Self, ClassConstructionError> {
+        if feature.get_level() == 0 || feature.get_level() > 20 {
+            return Err(ClassConstructionError::LevelOutOfBounds);
+        }
+
+        self.features.push(feature);
+
+        Ok(self)
+    }
+
+    /// Overrides [`IMPROVEMENT_LEVELS`], e.g. for a fighter's extra improvements at 6 and 14.
+    pub fn improvement_levels(
+        mut self,
+        levels: impl Into<Vec<usize>>,
+    ) -> Result<Self, ClassConstructionError> {
+        let levels: Vec<usize> = levels.into();
+
+        if levels.iter().any(|&level| level == 0 || level > 20) {
+            return Err(ClassConstructionError::LevelOutOfBounds);
+        }
+
+        self.improvement_levels = Some(levels);
+
+        Ok(self)
+    }
+
+    /// Overrides [`SUBCLASS_LEVEL`], e.g. for a cleric choosing a domain at 1st level.
+    pub fn subclass_level(mut self, level: usize) -> Result<Self, ClassConstructionError> {
+        if level == 0 || level > 20 {
+            return Err(ClassConstructionError::LevelOutOfBounds);
+        }
+
+        self.subclass_level = Some(level);
+
+        Ok(self)
+    }
+
     pub fn build(self) -> Result<Class, ClassConstructionError> {
         let name = self.name.ok_or(ClassConstructionError::MissingName)?;
 
@@ -146,6 +343,13 @@ impl Builder {
 
         let feats = self.feats;
 
+        let mut features = self.features;
+        features.sort_by_key(Feature::get_level);
+
+        let improvement_levels = self
+            .improvement_levels
+            .unwrap_or_else(|| IMPROVEMENT_LEVELS.to_vec());
+
         Ok(Class {
             name,
             level,
@@ -153,6 +357,11 @@ impl Builder {
             spell_list,
             hp_increases,
             feats,
+            features,
+            improvement_levels,
+            improvements: vec![],
+            subclass_level: self.subclass_level.unwrap_or(SUBCLASS_LEVEL),
+            subclass: None,
         })
     }
 }
@@ -183,6 +392,7 @@ pub struct Template {
     pub spell_list: Option<SpellList>,
     pub hp_increases: HPIncreases,
     pub feats: Vec<Feat>,
+    pub features: Vec<Feature>,
 }
 
 #[derive(Clone, Debug)]
@@ -193,6 +403,11 @@ pub struct Class {
     spell_list: Option<SpellList>,
     hp_increases: HPIncreases,
     feats: Vec<Feat>,
+    features: Vec<Feature>,
+    improvement_levels: Vec<usize>,
+    improvements: Vec<(usize, AbilityScoreImprovement)>,
+    subclass_level: usize,
+    subclass: Option<Subclass>,
 }
 
 impl TryFrom<Template> for Class {
@@ -206,6 +421,11 @@ impl TryFrom<Template> for Class {
             spell_list: value.spell_list,
             hp_increases: value.hp_increases,
             feats: value.feats,
+            features: value.features,
+            improvement_levels: IMPROVEMENT_LEVELS.to_vec(),
+            improvements: vec![],
+            subclass_level: SUBCLASS_LEVEL,
+            subclass: None,
         };
         class.set_level(value.level)?;
         Ok(class)
@@ -254,12 +474,219 @@ impl Class {
         self.hp_increases.get_hit_points(constitution_modifier)
     }
 
-    pub fn get_feats(&self) -> &[Feat] {
-        &self.feats
+    /// Feats granted by the class followed by feats taken as Ability Score Improvements.
+    pub fn get_feats(&self) -> Vec<(feat::Source, &Feat)> {
+        self.feats
+            .iter()
+            .map(|feat| (feat::Source::Class(self.name.clone()), feat))
+            .chain(
+                self.improvements
+                    .iter()
+                    .filter_map(|(level, improvement)| match improvement {
+                        AbilityScoreImprovement::Feat(feat) => Some((
+                            feat::Source::AbilityScoreImprovement(self.name.clone(), *level),
+                            feat,
+                        )),
+                        AbilityScoreImprovement::Increase(..) => None,
+                    }),
+            )
+            .collect()
     }
 
-    pub fn add_feat(&mut self, feat: Feat) {
+    /// Doesn't know the character, so prerequisites are left to [`Character::add_class_feat`].
+    ///
+    /// # Errors
+    ///
+    /// - `AbilityNotChosen`: a half-feat's ability hasn't been picked
+    ///
+    /// [`Character::add_class_feat`]: crate::character::Character::add_class_feat
+    pub fn add_feat(&mut self, feat: Feat) -> Result<(), FeatError> {
+        feat.validate()?;
+
         self.feats.push(feat);
+
+        Ok(())
+    }
+
+    /// Class and subclass features the class has reached the level for, lowest level first.
+    pub fn get_features(&self) -> Vec<&Feature> {
+        let mut features: Vec<&Feature> = self
+            .features
+            .iter()
+            .chain(self.subclass.iter().flat_map(Subclass::get_features))
+            .filter(|feature| feature.get_level() <= self.level)
+            .collect();
+        features.sort_by_key(|feature| feature.get_level());
+
+        features
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `LevelOutOfBounds`: the feature's level isn't between 1 and 20
+    ///
+    pub fn add_feature(&mut self, feature: Feature) -> Result<(), ClassConstructionError> {
+        if feature.get_level() == 0 || feature.get_level() > 20 {
+            return Err(ClassConstructionError::LevelOutOfBounds);
+        }
+
+        self.features.push(feature);
+        self.features.sort_by_key(Feature::get_level);
+
+        Ok(())
+    }
+
+    pub fn get_feature(&self, name: &str) -> Option<&Feature> {
+        self.get_features()
+            .into_iter()
+            .find(|feature| feature.get_name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn get_feature_mut(&mut self, name: &str) -> Option<&mut Feature> {
+        let level = self.level;
+
+        self.features
+            .iter_mut()
+            .chain(
+                self.subclass
+                    .iter_mut()
+                    .flat_map(|subclass| subclass.features.iter_mut()),
+            )
+            .find(|feature| {
+                feature.get_level() <= level && feature.get_name().eq_ignore_ascii_case(name)
+            })
+    }
+
+    /// Regains the uses of every feature that recharges on `rest`.
+    pub fn recharge_features(&mut self, rest: Rest) {
+        for feature in self.features.iter_mut().chain(
+            self.subclass
+                .iter_mut()
+                .flat_map(|subclass| subclass.features.iter_mut()),
+        ) {
+            feature.recharge(rest);
+        }
+    }
+
+    #[must_use]
+    pub fn get_subclass_level(&self) -> usize {
+        self.subclass_level
+    }
+
+    #[must_use]
+    pub fn get_subclass(&self) -> Option<&Subclass> {
+        self.subclass.as_ref()
+    }
+
+    /// # Errors
+    ///
+    /// - `LevelNotReached`: the class is below its subclass level
+    /// - `AlreadyChosen`: the class already has a subclass
+    ///
+    pub fn set_subclass(&mut self, subclass: Subclass) -> Result<(), SubclassError> {
+        if self.level < self.subclass_level {
+            return Err(SubclassError::LevelNotReached(self.subclass_level));
+        }
+
+        if let Some(chosen) = &self.subclass {
+            return Err(SubclassError::AlreadyChosen(chosen.name.clone()));
+        }
+
+        self.subclass = Some(subclass);
+
+        Ok(())
+    }
+
+    /// Names of the subclass spells the class has reached the level for.
+    #[must_use]
+    pub fn get_always_prepared_spells(&self) -> Vec<&str> {
+        self.subclass
+            .iter()
+            .flat_map(Subclass::get_spells)
+            .filter(|(level, _)| *level <= self.level)
+            .map(|(_, spell)| spell.as_str())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn get_improvement_levels(&self) -> &[usize] {
+        &self.improvement_levels
+    }
+
+    #[must_use]
+    pub fn get_ability_score_improvements(&self) -> &[(usize, AbilityScoreImprovement)] {
+        &self.improvements
+    }
+
+    /// Improvement levels the class has reached but not spent yet.
+    #[must_use]
+    pub fn get_open_improvement_levels(&self) -> Vec<usize> {
+        self.improvement_levels
+            .iter()
+            .copied()
+            .filter(|&level| {
+                level <= self.level && !self.improvements.iter().any(|(taken, _)| *taken == level)
+            })
+            .collect()
+    }
+
+    /// # Errors
+    ///
+    /// - `NotAnImprovementLevel`: the class doesn't grant an improvement at `level`
+    /// - `LevelNotReached`: the class is below `level`
+    /// - `AlreadyTaken`: the improvement at `level` has already been spent
+    ///
+    pub fn add_ability_score_improvement(
+        &mut self,
+        level: usize,
+        improvement: AbilityScoreImprovement,
+    ) -> Result<(), ImprovementError> {
+        if !self.improvement_levels.contains(&level) {
+            return Err(ImprovementError::NotAnImprovementLevel(level));
+        }
+
+        if level > self.level {
+            return Err(ImprovementError::LevelNotReached(level));
+        }
+
+        if self.improvements.iter().any(|(taken, _)| *taken == level) {
+            return Err(ImprovementError::AlreadyTaken(level));
+        }
+
+        self.improvements.push((level, improvement));
+        self.improvements.sort_by_key(|(level, _)| *level);
+
+        Ok(())
+    }
+
+    /// Frees the improvement taken at `level` so it can be chosen again.
+    pub fn remove_ability_score_improvement(&mut self, level: usize) {
+        self.improvements.retain(|(taken, _)| *taken != level);
+    }
+
+    /// Each increase to `ability` from the class's Ability Score Improvements.
+    #[must_use]
+    pub fn get_ability_score_increases(&self, ability: ability::Identifier) -> Vec<Component> {
+        self.improvements
+            .iter()
+            .filter_map(|(level, improvement)| match improvement {
+                AbilityScoreImprovement::Increase(first, second) => {
+                    let amount = [first, second]
+                        .into_iter()
+                        .filter(|&&chosen| chosen == ability)
+                        .count();
+
+                    (amount > 0).then(|| {
+                        Component::new(
+                            format!("Ability Score Improvement ({} {level})", self.name),
+                            amount as isize,
+                        )
+                    })
+                }
+                AbilityScoreImprovement::Feat(_) => None,
+            })
+            .collect()
     }
 }
 
@@ -302,9 +729,78 @@ impl Classes {
         })
     }
 
-    pub fn get_feats(&self) -> Vec<&Feat> {
+    pub fn get_feats(&self) -> Vec<(feat::Source, &Feat)> {
         self.0.iter().flat_map(|class| class.get_feats()).collect()
     }
+
+    /// Whether any class has a spell list.
+    #[must_use]
+    pub fn is_spellcaster(&self) -> bool {
+        self.0.iter().any(|class| class.spell_list.is_some())
+    }
+
+    #[must_use]
+    pub fn get_ability_score_increases(&self, ability: ability::Identifier) -> Vec<Component> {
+        self.0
+            .iter()
+            .flat_map(|class| class.get_ability_score_increases(ability))
+            .collect()
+    }
+
+    /// Every unlocked feature with the class it comes from.
+    pub fn get_features(&self) -> Vec<(&Class, &Feature)> {
+        self.0
+            .iter()
+            .flat_map(|class| {
+                class
+                    .get_features()
+                    .into_iter()
+                    .map(move |feature| (class, feature))
+            })
+            .collect()
+    }
+
+    pub fn get_always_prepared_spells(&self) -> Vec<&str> {
+        self.0
+            .iter()
+            .flat_map(Class::get_always_prepared_spells)
+            .collect()
+    }
+
+    /// Proficiencies granted by the classes' subclasses.
+    pub fn get_subclass_proficiencies(&self) -> Vec<&Proficiencies> {
+        self.0
+            .iter()
+            .filter_map(Class::get_subclass)
+            .map(Subclass::get_proficiencies)
+            .collect()
+    }
+
+    pub fn recharge_features(&mut self, rest: Rest) {
+        for class in &mut self.0 {
+            class.recharge_features(rest);
+        }
+    }
+
+    #[must_use]
+    pub fn get_class(&self, name: &str) -> Option<&Class> {
+        self.0
+            .iter()
+            .find(|class| class.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn get_class_mut(&mut self, name: &str) -> Option<&mut Class> {
+        self.0
+            .iter_mut()
+            .find(|class| class.name.eq_ignore_ascii_case(name))
+    }
+
+    #[must_use]
+    pub fn has_class(&self, name: &str) -> bool {
+        self.0
+            .iter()
+            .any(|class| class.name.eq_ignore_ascii_case(name))
+    }
 }
 
 impl fmt::Display for Classes {
@@ -313,7 +809,12 @@ impl fmt::Display for Classes {
             f,
             "{}",
             self.0.iter().fold(String::new(), |acc, class| {
-                format!("{acc} {} {}", class.name, class.level)
+                match &class.subclass {
+                    Some(subclass) => {
+                        format!("{acc} {} ({}) {}", class.name, subclass.name, class.level)
+                    }
+                    None => format!("{acc} {} {}", class.name, class.level),
+                }
             })
         )
     }
@@ -336,6 +837,11 @@ mod tests {
                 spell_list: Some(SpellList::default()),
                 hp_increases: HPIncreases::new(6),
                 feats: vec![],
+                features: vec![],
+                improvement_levels: IMPROVEMENT_LEVELS.to_vec(),
+                improvements: vec![],
+                subclass_level: SUBCLASS_LEVEL,
+                subclass: None,
             }
         }
 
@@ -351,6 +857,11 @@ mod tests {
                 spell_list: Some(SpellList::default()),
                 hp_increases: HPIncreases::new(8),
                 feats: vec![],
+                features: vec![],
+                improvement_levels: IMPROVEMENT_LEVELS.to_vec(),
+                improvements: vec![],
+                subclass_level: SUBCLASS_LEVEL,
+                subclass: None,
             }
         }
     }
@@ -489,6 +1000,11 @@ mod tests {
             spell_list: None,
             hp_increases: HPIncreases::default(),
             feats: vec![],
+            features: vec![],
+            improvement_levels: IMPROVEMENT_LEVELS.to_vec(),
+            improvements: vec![],
+            subclass_level: SUBCLASS_LEVEL,
+            subclass: None,
         }]);
         assert_eq!(lvl4.get_proficiency_bonus(), 2);
 
@@ -499,6 +1015,11 @@ mod tests {
             spell_list: None,
             hp_increases: HPIncreases::default(),
             feats: vec![],
+            features: vec![],
+            improvement_levels: IMPROVEMENT_LEVELS.to_vec(),
+            improvements: vec![],
+            subclass_level: SUBCLASS_LEVEL,
+            subclass: None,
         }]);
         assert_eq!(lvl5.get_proficiency_bonus(), 3);
 
@@ -509,6 +1030,11 @@ mod tests {
             spell_list: None,
             hp_increases: HPIncreases::default(),
             feats: vec![],
+            features: vec![],
+            improvement_levels: IMPROVEMENT_LEVELS.to_vec(),
+            improvements: vec![],
+            subclass_level: SUBCLASS_LEVEL,
+            subclass: None,
         }]);
         assert_eq!(lvl9.get_proficiency_bonus(), 4);
 
@@ -519,6 +1045,11 @@ mod tests {
             spell_list: None,
             hp_increases: HPIncreases::default(),
             feats: vec![],
+            features: vec![],
+            improvement_levels: IMPROVEMENT_LEVELS.to_vec(),
+            improvements: vec![],
+            subclass_level: SUBCLASS_LEVEL,
+            subclass: None,
         }]);
         assert_eq!(lvl13.get_proficiency_bonus(), 5);
 
@@ -529,6 +1060,11 @@ mod tests {
             spell_list: None,
             hp_increases: HPIncreases::default(),
             feats: vec![],
+            features: vec![],
+            improvement_levels: IMPROVEMENT_LEVELS.to_vec(),
+            improvements: vec![],
+            subclass_level: SUBCLASS_LEVEL,
+            subclass: None,
         }]);
         assert_eq!(lvl17.get_proficiency_bonus(), 6);
     }
@@ -657,14 +1193,122 @@ mod tests {
             "Double range distance and ignore half cover.",
         );
         let mut artificer = Class::artificer();
-        artificer.add_feat(sharpshooter.clone());
+        artificer.add_feat(sharpshooter.clone()).unwrap();
 
         let war_caster = Feat::new("War Caster", "Can cast cantrip as attack of opportunity. Advantate on CON saving throws when concentrating.");
         let mut wizard = Class::wizard();
-        wizard.add_feat(war_caster.clone());
+        wizard.add_feat(war_caster.clone()).unwrap();
 
         let multiclass = Classes(vec![artificer, wizard]);
 
-        assert_eq!(multiclass.get_feats(), vec![&sharpshooter, &war_caster]);
+        assert_eq!(
+            multiclass.get_feats(),
+            vec![
+                (
+                    feat::Source::Class(String::from("Artificer")),
+                    &sharpshooter
+                ),
+                (feat::Source::Class(String::from("Wizard")), &war_caster)
+            ]
+        );
+    }
+
+    #[test]
+    fn _ability_score_improvement_feats_should_count_as_class_feats(
+    ) -> Result<(), Box<dyn error::Error>> {
+        let mut fighter = Builder::new()
+            .name("Fighter")?
+            .level(6)?
+            .improvement_levels([4, 6, 8, 12, 14, 16, 19])?
+            .build()?;
+
+        assert_eq!(fighter.get_open_improvement_levels(), vec![4, 6]);
+
+        let sentinel = Feat::new("Sentinel", "Opportunity attacks stop movement.");
+        fighter
+            .add_ability_score_improvement(6, AbilityScoreImprovement::Feat(sentinel.clone()))?;
+
+        assert_eq!(fighter.get_open_improvement_levels(), vec![4]);
+        assert_eq!(
+            fighter.get_feats(),
+            vec![(
+                feat::Source::AbilityScoreImprovement(String::from("Fighter"), 6),
+                &sentinel
+            )]
+        );
+        assert!(fighter
+            .get_ability_score_increases(ability::Identifier::Strength)
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn _added_features_should_stay_within_class_levels() -> Result<(), Box<dyn error::Error>> {
+        let mut artificer = Builder::new().name("Artificer")?.level(2)?.build()?;
+
+        assert!(matches!(
+            artificer.add_feature(Feature::new("Soul of Artifice", "Epic boon.", 21)),
+            Err(ClassConstructionError::LevelOutOfBounds)
+        ));
+        assert!(matches!(
+            artificer.add_feature(Feature::new("Tinker", "Before 1st level.", 0)),
+            Err(ClassConstructionError::LevelOutOfBounds)
+        ));
+
+        artificer.add_feature(Feature::new("Magical Tinkering", "Small magic.", 1))?;
+
+        assert_eq!(artificer.get_features().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _subclasses_should_attach_at_their_level_and_add_features_and_spells(
+    ) -> Result<(), Box<dyn error::Error>> {
+        let battle_smith = || {
+            Subclass::new("Battle Smith")
+                .add_tool_proficiency(Tool::named("Smith's Tools"))
+                .add_spells(3, ["Heroism", "Shield"])
+                .add_spells(5, ["Branding Smite", "Warding Bond"])
+                .add_feature(Feature::new(
+                    "Steel Defender",
+                    "A loyal mechanical companion.",
+                    3,
+                ))
+                .add_feature(Feature::new("Extra Attack", "Attack twice.", 5))
+        };
+        let mut artificer = Builder::new().name("Artificer")?.level(2)?.build()?;
+
+        assert_eq!(
+            artificer.set_subclass(battle_smith()),
+            Err(SubclassError::LevelNotReached(3))
+        );
+
+        artificer.set_level(4).unwrap();
+        artificer.set_subclass(battle_smith())?;
+
+        assert_eq!(
+            artificer.set_subclass(Subclass::new("Armorer")),
+            Err(SubclassError::AlreadyChosen(String::from("Battle Smith")))
+        );
+        assert_eq!(
+            artificer.get_always_prepared_spells(),
+            vec!["Heroism", "Shield"]
+        );
+        assert_eq!(
+            artificer
+                .get_features()
+                .iter()
+                .map(|feature| feature.get_name())
+                .collect::<Vec<_>>(),
+            vec!["Steel Defender"]
+        );
+        assert_eq!(
+            Classes(vec![artificer]).to_string(),
+            " Artificer (Battle Smith) 4"
+        );
+
+        Ok(())
     }
 }
@@ -0,0 +1,192 @@
+#![warn(clippy::pedantic)]
+
+use std::fmt;
+
+use crate::{
+    ability,
+    check::CheckKind,
+    effect::{Effect, Modifier, Target},
+    modifiers::Resistance,
+    race::DamageType,
+    skills::Advantage,
+    units::Duration,
+};
+
+/// A condition currently affecting the character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Condition {
+    Blinded,
+    Charmed,
+    Deafened,
+    Frightened,
+    Grappled,
+    Incapacitated,
+    Invisible,
+    Paralyzed,
+    Petrified,
+    Poisoned,
+    Prone,
+    Restrained,
+    Stunned,
+    Unconscious,
+}
+
+impl Condition {
+    /// Advantage or disadvantage the condition imposes on the character's own check.
+    #[must_use]
+    pub fn get_check_advantage(&self, kind: CheckKind) -> Option<Advantage> {
+        match (self, kind) {
+            (
+                Condition::Frightened | Condition::Poisoned,
+                CheckKind::Ability(_) | CheckKind::Skill(_),
+            )
+            | (Condition::Restrained, CheckKind::SavingThrow(ability::Identifier::Dexterity)) => {
+                Some(Advantage::Disadvantage)
+            }
+            _ => None,
+        }
+    }
+
+    /// Advantage or disadvantage the condition imposes on the character's attack rolls.
+    #[must_use]
+    pub fn get_attack_advantage(&self) -> Option<Advantage> {
+        match self {
+            Condition::Blinded
+            | Condition::Frightened
+            | Condition::Poisoned
+            | Condition::Prone
+            | Condition::Restrained => Some(Advantage::Disadvantage),
+            Condition::Invisible => Some(Advantage::Advantage),
+            _ => None,
+        }
+    }
+
+    /// Effects the condition applies to derived stats, e.g. a speed of 0 while grappled.
+    #[must_use]
+    pub fn get_effects(&self) -> Vec<Effect> {
+        let source = self.to_string();
+
+        match self {
+            Condition::Grappled
+            | Condition::Paralyzed
+            | Condition::Restrained
+            | Condition::Stunned
+            | Condition::Unconscious => {
+                vec![Effect::new(source, Target::WalkingSpeed, Modifier::Set(0))]
+            }
+            Condition::Petrified => [
+                DamageType::Acid,
+                DamageType::Bludgeoning,
+                DamageType::Cold,
+                DamageType::Fire,
+                DamageType::Force,
+                DamageType::Lightning,
+                DamageType::Necrotic,
+                DamageType::Piercing,
+                DamageType::Psychic,
+                DamageType::Radiant,
+                DamageType::Slashing,
+                DamageType::Thunder,
+            ]
+            .into_iter()
+            .map(|damage_type| {
+                Effect::new(
+                    source.clone(),
+                    Target::Damage(damage_type),
+                    Modifier::Resistance(Resistance::Resistant),
+                )
+            })
+            .chain([
+                Effect::new(
+                    source.clone(),
+                    Target::Damage(DamageType::Poison),
+                    Modifier::Resistance(Resistance::Immune),
+                ),
+                Effect::new(source.clone(), Target::WalkingSpeed, Modifier::Set(0)),
+            ])
+            .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Whether the condition stops the character acting, which also breaks concentration.
+    #[must_use]
+    pub fn is_incapacitating(&self) -> bool {
+        matches!(
+            self,
+            Condition::Incapacitated
+                | Condition::Paralyzed
+                | Condition::Petrified
+                | Condition::Stunned
+                | Condition::Unconscious
+        )
+    }
+
+    /// Whether the condition makes the check fail no matter the roll, e.g. a paralyzed
+    /// character's Dexterity saves.
+    #[must_use]
+    pub fn fails_automatically(&self, kind: CheckKind) -> bool {
+        matches!(
+            (self, kind),
+            (
+                Condition::Paralyzed
+                    | Condition::Petrified
+                    | Condition::Stunned
+                    | Condition::Unconscious,
+                CheckKind::SavingThrow(
+                    ability::Identifier::Strength | ability::Identifier::Dexterity
+                ),
+            )
+        )
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Condition::Blinded => "Blinded",
+            Condition::Charmed => "Charmed",
+            Condition::Deafened => "Deafened",
+            Condition::Frightened => "Frightened",
+            Condition::Grappled => "Grappled",
+            Condition::Incapacitated => "Incapacitated",
+            Condition::Invisible => "Invisible",
+            Condition::Paralyzed => "Paralyzed",
+            Condition::Petrified => "Petrified",
+            Condition::Poisoned => "Poisoned",
+            Condition::Prone => "Prone",
+            Condition::Restrained => "Restrained",
+            Condition::Stunned => "Stunned",
+            Condition::Unconscious => "Unconscious",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// Concentration on a lasting spell or psionic act. Starting to concentrate on something else
+/// ends it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Concentration {
+    source: String,
+    duration: Duration,
+}
+
+impl Concentration {
+    pub fn new(source: impl Into<String>, duration: Duration) -> Self {
+        Concentration {
+            source: source.into(),
+            duration,
+        }
+    }
+
+    #[must_use]
+    pub fn get_source(&self) -> &str {
+        &self.source
+    }
+
+    #[must_use]
+    pub fn get_duration(&self) -> Duration {
+        self.duration
+    }
+}
@@ -0,0 +1,307 @@
+#![warn(clippy::pedantic)]
+
+use std::{error, fmt};
+
+use crate::{
+    item::{Item, Items},
+    units::Volume,
+};
+
+/// Storage inside an item, such as a backpack or a Bag of Holding.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Container {
+    weight_capacity: Option<f64>,
+    volume_capacity: Option<Volume>,
+    extradimensional: bool,
+    contents: Items,
+}
+
+impl Container {
+    #[must_use]
+    pub fn new() -> Self {
+        Container::default()
+    }
+
+    #[must_use]
+    pub fn weight_capacity(mut self, pounds: f64) -> Self {
+        let _ = self.weight_capacity.insert(pounds);
+
+        self
+    }
+
+    #[must_use]
+    pub fn volume_capacity(mut self, volume: Volume) -> Self {
+        let _ = self.volume_capacity.insert(volume);
+
+        self
+    }
+
+    /// Contents stop adding to the container's weight, as with a Bag of Holding.
+    #[must_use]
+    pub fn extradimensional(mut self) -> Self {
+        self.extradimensional = true;
+
+        self
+    }
+
+    #[must_use]
+    pub fn get_weight_capacity(&self) -> Option<f64> {
+        self.weight_capacity
+    }
+
+    #[must_use]
+    pub fn get_volume_capacity(&self) -> Option<Volume> {
+        self.volume_capacity
+    }
+
+    #[must_use]
+    pub fn is_extradimensional(&self) -> bool {
+        self.extradimensional
+    }
+
+    #[must_use]
+    pub fn get_contents(&self) -> &Items {
+        &self.contents
+    }
+
+    pub(crate) fn get_contents_mut(&mut self) -> &mut Items {
+        &mut self.contents
+    }
+
+    #[must_use]
+    pub fn get_contents_weight(&self) -> f64 {
+        self.contents.get_total_weight()
+    }
+
+    /// Only counts contents with a known volume.
+    #[must_use]
+    pub fn get_contents_volume(&self) -> Volume {
+        Volume::CubicFeet(
+            self.contents
+                .iter()
+                .filter_map(Item::get_total_volume)
+                .map(|volume| volume.get_cubic_feet())
+                .sum(),
+        )
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `OverWeightCapacity`: if `item` would push the contents past the weight capacity
+    /// - `OverVolumeCapacity`: if `item` would push the contents past the volume capacity
+    ///
+    pub fn check_can_hold(&self, item: &Item) -> Result<(), Error> {
+        if let Some(capacity) = self.weight_capacity {
+            if self.get_contents_weight() + item.get_total_weight() > capacity {
+                return Err(Error::OverWeightCapacity(capacity));
+            }
+        }
+
+        if let (Some(capacity), Some(volume)) = (self.volume_capacity, item.get_total_volume()) {
+            let filled = self.get_contents_volume().get_cubic_feet() + volume.get_cubic_feet();
+
+            if filled > capacity.get_cubic_feet() {
+                return Err(Error::OverVolumeCapacity(capacity));
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `OverWeightCapacity`, `OverVolumeCapacity`: if there isn't room for `item`
+    ///
+    pub fn store(&mut self, item: Item) -> Result<(), Error> {
+        self.check_can_hold(&item)?;
+
+        self.contents.add_item(item);
+
+        Ok(())
+    }
+
+    /// Takes out the whole stack of the first item called `name`.
+    pub fn remove_item(&mut self, name: &str) -> Option<Item> {
+        self.contents.remove_item(name)
+    }
+}
+
+/// A mount or vehicle carrying gear on the character's behalf. What it carries doesn't count
+/// towards the character's own encumbrance.
+#[derive(Clone, Debug)]
+pub struct Mount {
+    name: String,
+    carrying_capacity: Option<f64>,
+    inventory: Items,
+}
+
+impl Mount {
+    pub fn new(name: impl Into<String>, carrying_capacity: Option<f64>) -> Self {
+        Mount {
+            name: name.into(),
+            carrying_capacity,
+            inventory: Items::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn get_carrying_capacity(&self) -> Option<f64> {
+        self.carrying_capacity
+    }
+
+    #[must_use]
+    pub fn get_inventory(&self) -> &Items {
+        &self.inventory
+    }
+
+    pub(crate) fn get_inventory_mut(&mut self) -> &mut Items {
+        &mut self.inventory
+    }
+
+    #[must_use]
+    pub fn get_total_weight(&self) -> f64 {
+        self.inventory.get_total_weight()
+    }
+
+    #[must_use]
+    pub fn is_overloaded(&self) -> bool {
+        self.carrying_capacity
+            .is_some_and(|capacity| self.get_total_weight() > capacity)
+    }
+
+    pub fn add_item(&mut self, item: Item) {
+        self.inventory.add_item(item);
+    }
+
+    /// Takes out the whole stack of the first item called `name`.
+    pub fn remove_item(&mut self, name: &str) -> Option<Item> {
+        self.inventory.remove_item(name)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    NotAContainer(String),
+    CannotContainItself,
+    OverWeightCapacity(f64),
+    OverVolumeCapacity(Volume),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Error::NotAContainer(name) => format!("{name} cannot hold other items."),
+            Error::CannotContainItself => String::from("A container cannot hold itself."),
+            Error::OverWeightCapacity(capacity) => {
+                format!("Container can only hold {capacity} lb.")
+            }
+            Error::OverVolumeCapacity(capacity) => format!("Container can only hold {capacity}."),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use std::error::Error;
+
+    use crate::item;
+
+    use super::*;
+
+    fn backpack() -> Container {
+        Container::new()
+            .weight_capacity(30.0)
+            .volume_capacity(Volume::CubicFeet(1.0))
+    }
+
+    #[test]
+    fn _should_refuse_items_over_weight_capacity() -> Result<(), Box<dyn Error>> {
+        let mut backpack = backpack();
+
+        let rope = item::Builder::new().name("Rope")?.weight(12.0)?.build()?;
+        backpack.store(rope.clone())?;
+        backpack.store(rope.clone())?;
+
+        assert_eq!(
+            backpack.store(rope),
+            Err(super::Error::OverWeightCapacity(30.0))
+        );
+        assert_eq!(backpack.get_contents_weight(), 24.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_refuse_items_over_volume_capacity() -> Result<(), Box<dyn Error>> {
+        let mut backpack = backpack();
+
+        let pillow = item::Builder::new()
+            .name("Pillow")?
+            .volume(Volume::CubicFeet(0.75))?
+            .build()?;
+        backpack.store(pillow.clone())?;
+
+        assert!(matches!(
+            backpack.store(pillow),
+            Err(super::Error::OverVolumeCapacity(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn _extradimensional_containers_should_weigh_the_same_when_full() -> Result<(), Box<dyn Error>>
+    {
+        let mut bag_of_holding = item::Builder::new()
+            .name("Bag of Holding")?
+            .weight(15.0)?
+            .container(Container::new().weight_capacity(500.0).extradimensional())?
+            .build()?;
+        let mut sack = item::Builder::new()
+            .name("Sack")?
+            .weight(1.0)?
+            .container(Container::new().weight_capacity(30.0))?
+            .build()?;
+
+        let gold_bar = item::Builder::new()
+            .name("Gold Bar")?
+            .weight(20.0)?
+            .build()?;
+        for container in [&mut bag_of_holding, &mut sack] {
+            container
+                .get_container_mut()
+                .ok_or("not a container")?
+                .store(gold_bar.clone())?;
+        }
+
+        assert_eq!(bag_of_holding.get_total_weight(), 15.0);
+        assert_eq!(sack.get_total_weight(), 21.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _mounts_should_report_overloading() -> Result<(), Box<dyn Error>> {
+        let mut mule = Mount::new("Mule", Some(420.0));
+
+        mule.add_item(item::Builder::new().name("Anvil")?.weight(400.0)?.build()?);
+        assert!(!mule.is_overloaded());
+
+        mule.add_item(item::Builder::new().name("Tent")?.weight(25.0)?.build()?);
+        assert!(mule.is_overloaded());
+
+        Ok(())
+    }
+}
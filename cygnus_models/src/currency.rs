@@ -0,0 +1,362 @@
+#![warn(clippy::pedantic)]
+
+use std::{error, fmt};
+
+/// Coins weigh a pound per fifty.
+pub const COINS_PER_POUND: usize = 50;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Coin {
+    Copper,
+    Silver,
+    Electrum,
+    Gold,
+    Platinum,
+}
+
+impl Coin {
+    /// Every coin, from least to most valuable.
+    #[must_use]
+    pub fn all() -> [Coin; 5] {
+        [
+            Coin::Copper,
+            Coin::Silver,
+            Coin::Electrum,
+            Coin::Gold,
+            Coin::Platinum,
+        ]
+    }
+
+    /// What one coin is worth in copper pieces.
+    #[must_use]
+    pub fn get_value(self) -> usize {
+        match self {
+            Coin::Copper => 1,
+            Coin::Silver => 10,
+            Coin::Electrum => 50,
+            Coin::Gold => 100,
+            Coin::Platinum => 1000,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Coin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Coin::Copper => "cp",
+            Coin::Silver => "sp",
+            Coin::Electrum => "ep",
+            Coin::Gold => "gp",
+            Coin::Platinum => "pp",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// An amount of a single denomination, e.g. the 45 gp cost of studded leather.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Price {
+    amount: usize,
+    coin: Coin,
+}
+
+impl Price {
+    #[must_use]
+    pub fn new(amount: usize, coin: Coin) -> Self {
+        Price { amount, coin }
+    }
+
+    #[must_use]
+    pub fn get_amount(&self) -> usize {
+        self.amount
+    }
+
+    #[must_use]
+    pub fn get_coin(&self) -> Coin {
+        self.coin
+    }
+
+    #[must_use]
+    pub fn to_copper(&self) -> usize {
+        self.amount * self.coin.get_value()
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.coin)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InsufficientFunds { needed: usize, available: usize },
+    InsufficientCoins(Coin),
+    TooFewToConvert { from: Coin, to: Coin },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Error::InsufficientFunds { needed, available } => {
+                format!("Cannot pay {needed} cp with only {available} cp.")
+            }
+            Error::InsufficientCoins(coin) => format!("Not enough {coin} in the purse."),
+            Error::TooFewToConvert { from, to } => {
+                format!("Not enough {from} to make a single {to}.")
+            }
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for Error {}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Purse([usize; 5]);
+
+impl Purse {
+    #[must_use]
+    pub fn new() -> Self {
+        Purse::default()
+    }
+
+    #[must_use]
+    pub fn get(&self, coin: Coin) -> usize {
+        self.0[coin.index()]
+    }
+
+    pub fn add(&mut self, coin: Coin, amount: usize) {
+        self.0[coin.index()] += amount;
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `InsufficientCoins`: if the purse holds fewer than `amount` of `coin`
+    ///
+    pub fn remove(&mut self, coin: Coin, amount: usize) -> Result<(), Error> {
+        let count = &mut self.0[coin.index()];
+
+        *count = count
+            .checked_sub(amount)
+            .ok_or(Error::InsufficientCoins(coin))?;
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get_total_copper(&self) -> usize {
+        Coin::all()
+            .iter()
+            .map(|&coin| self.get(coin) * coin.get_value())
+            .sum()
+    }
+
+    #[must_use]
+    pub fn count_coins(&self) -> usize {
+        self.0.iter().sum()
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn get_weight(&self) -> f64 {
+        self.count_coins() as f64 / COINS_PER_POUND as f64
+    }
+
+    /// Exchanges as many `from` coins as `amount` allows into `to` coins. Anything that doesn't
+    /// divide evenly into the larger denomination stays as `from`, e.g. converting 25 sp to gold
+    /// gives 2 gp and keeps 5 sp.
+    ///
+    /// # Errors
+    ///
+    /// - `InsufficientCoins`: if the purse holds fewer than `amount` of `from`
+    /// - `TooFewToConvert`: if `amount` isn't worth a single `to` coin
+    ///
+    pub fn convert(&mut self, from: Coin, to: Coin, amount: usize) -> Result<(), Error> {
+        if self.get(from) < amount {
+            return Err(Error::InsufficientCoins(from));
+        }
+
+        let exchanged = amount * from.get_value() / to.get_value() * to.get_value();
+
+        if amount > 0 && exchanged == 0 {
+            return Err(Error::TooFewToConvert { from, to });
+        }
+
+        let spent = exchanged / from.get_value();
+
+        self.remove(from, spent)?;
+        self.add(to, exchanged / to.get_value());
+
+        Ok(())
+    }
+
+    /// Pays with the smallest coins first, breaking a larger coin only when needed and taking
+    /// the change back in as few coins as possible.
+    ///
+    /// # Errors
+    ///
+    /// - `InsufficientFunds`: if the whole purse is worth less than `price`
+    ///
+    pub fn pay(&mut self, price: Price) -> Result<(), Error> {
+        let needed = price.to_copper();
+        let available = self.get_total_copper();
+
+        if needed > available {
+            return Err(Error::InsufficientFunds { needed, available });
+        }
+
+        let mut owed = needed;
+
+        for coin in Coin::all() {
+            let used = self.get(coin).min(owed / coin.get_value());
+            self.0[coin.index()] -= used;
+            owed -= used * coin.get_value();
+        }
+
+        if owed > 0 {
+            // Every coin left is worth more than what's owed, so break the smallest one.
+            let broken = Coin::all()
+                .into_iter()
+                .find(|&coin| self.get(coin) > 0)
+                .ok_or(Error::InsufficientFunds { needed, available })?;
+
+            self.0[broken.index()] -= 1;
+            self.add_change(broken.get_value() - owed);
+        }
+
+        Ok(())
+    }
+
+    fn add_change(&mut self, mut copper: usize) {
+        for coin in Coin::all().into_iter().rev() {
+            self.add(coin, copper / coin.get_value());
+            copper %= coin.get_value();
+        }
+    }
+}
+
+impl fmt::Display for Purse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let coins = Coin::all()
+            .into_iter()
+            .rev()
+            .filter(|&coin| self.get(coin) > 0)
+            .map(|coin| format!("{} {coin}", self.get(coin)))
+            .collect::<Vec<_>>();
+
+        if coins.is_empty() {
+            write!(f, "0 gp")
+        } else {
+            write!(f, "{}", coins.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _should_pay_exactly_when_coins_allow() -> Result<(), Error> {
+        let mut purse = Purse::new();
+        purse.add(Coin::Gold, 3);
+        purse.add(Coin::Silver, 5);
+
+        purse.pay(Price::new(15, Coin::Silver))?;
+
+        assert_eq!(purse.get(Coin::Gold), 2);
+        assert_eq!(purse.get(Coin::Silver), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_make_change_from_a_larger_coin() -> Result<(), Error> {
+        let mut purse = Purse::new();
+        purse.add(Coin::Copper, 5);
+        purse.add(Coin::Gold, 3);
+
+        purse.pay(Price::new(15, Coin::Silver))?;
+
+        assert_eq!(purse.get_total_copper(), 155);
+        assert_eq!(purse.get(Coin::Gold), 1);
+        assert_eq!(purse.get(Coin::Electrum), 1);
+        assert_eq!(purse.get(Coin::Copper), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_refuse_to_pay_more_than_the_purse_holds() {
+        let mut purse = Purse::new();
+        purse.add(Coin::Gold, 1);
+
+        assert_eq!(
+            purse.pay(Price::new(2, Coin::Gold)),
+            Err(Error::InsufficientFunds {
+                needed: 200,
+                available: 100
+            })
+        );
+        assert_eq!(purse.get(Coin::Gold), 1);
+    }
+
+    #[test]
+    fn _should_convert_between_denominations() -> Result<(), Error> {
+        let mut purse = Purse::new();
+        purse.add(Coin::Silver, 25);
+
+        purse.convert(Coin::Silver, Coin::Gold, 25)?;
+
+        assert_eq!(purse.get(Coin::Gold), 2);
+        assert_eq!(purse.get(Coin::Silver), 5);
+
+        purse.convert(Coin::Gold, Coin::Copper, 1)?;
+
+        assert_eq!(purse.get(Coin::Gold), 1);
+        assert_eq!(purse.get(Coin::Copper), 100);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_refuse_to_convert_less_than_a_single_coin() {
+        let mut purse = Purse::new();
+        purse.add(Coin::Silver, 25);
+
+        assert_eq!(
+            purse.convert(Coin::Silver, Coin::Gold, 9),
+            Err(Error::TooFewToConvert {
+                from: Coin::Silver,
+                to: Coin::Gold
+            })
+        );
+        assert_eq!(
+            purse.convert(Coin::Silver, Coin::Gold, 30),
+            Err(Error::InsufficientCoins(Coin::Silver))
+        );
+        assert_eq!(purse.get(Coin::Silver), 25);
+        assert_eq!(purse.get(Coin::Gold), 0);
+    }
+
+    #[test]
+    fn _fifty_coins_should_weigh_a_pound() {
+        let mut purse = Purse::new();
+        purse.add(Coin::Gold, 49);
+
+        assert_eq!(purse.get_weight(), 0.98);
+
+        purse.add(Coin::Copper, 51);
+
+        assert_eq!(purse.get_weight(), 2.0);
+    }
+}
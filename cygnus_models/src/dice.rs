@@ -1,5 +1,19 @@
 use std::{collections::BTreeMap, fmt};
 
+use rand::Rng;
+
+/// A source of die results, so rolls can be made with real randomness or fixed in tests.
+pub trait Roller {
+    /// A face between 1 and `sides`.
+    fn roll_die(&mut self, sides: usize) -> usize;
+}
+
+impl<R: Rng> Roller for R {
+    fn roll_die(&mut self, sides: usize) -> usize {
+        self.gen_range(1..=sides.max(1))
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Die {
     sides: usize,
@@ -33,6 +47,85 @@ impl Roll {
             .and_modify(|die| die.count += count)
             .or_insert(Die { sides, count });
     }
+
+    /// Rolls every die, smallest first, keeping each face.
+    pub fn roll(&self, roller: &mut impl Roller) -> Rolled {
+        let mut faces = vec![];
+
+        for die in self.dice.values() {
+            for _ in 0..die.count {
+                faces.push((die.sides, roller.roll_die(die.sides)));
+            }
+        }
+
+        Rolled {
+            faces,
+            modifier: self.modifier,
+        }
+    }
+}
+
+/// The outcome of a [`Roll`], with the face of every die.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rolled {
+    faces: Vec<(usize, usize)>,
+    modifier: isize,
+}
+
+impl Rolled {
+    /// Each die as `(sides, face)`.
+    pub fn get_faces(&self) -> &[(usize, usize)] {
+        &self.faces
+    }
+
+    pub fn get_modifier(&self) -> isize {
+        self.modifier
+    }
+
+    pub fn get_total(&self) -> isize {
+        self.faces
+            .iter()
+            .map(|&(_, face)| face as isize)
+            .sum::<isize>()
+            + self.modifier
+    }
+}
+
+impl fmt::Display for Rolled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let faces = self
+            .faces
+            .iter()
+            .map(|(_, face)| face.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let modifier = match self.modifier.signum() {
+            -1 => format!(" - {}", self.modifier.abs()),
+            1 => format!(" + {}", self.modifier),
+            _ => String::new(),
+        };
+
+        write!(f, "[{faces}]{modifier} = {}", self.get_total())
+    }
+}
+
+/// Dice that come up with the given faces in order, then 1s once they run out.
+#[cfg(test)]
+pub(crate) struct Loaded(std::collections::VecDeque<usize>);
+
+#[cfg(test)]
+impl Loaded {
+    pub(crate) fn new(faces: impl IntoIterator<Item = usize>) -> Self {
+        Loaded(faces.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+impl Roller for Loaded {
+    fn roll_die(&mut self, _sides: usize) -> usize {
+        self.0.pop_front().unwrap_or(1)
+    }
 }
 
 impl fmt::Display for Roll {
@@ -95,6 +188,25 @@ mod tests {
         assert_eq!(dr.to_string(), "2d4 + 1d10 + 2");
     }
 
+    #[test]
+    fn _should_keep_every_face_rolled() {
+        let mut dr = Roll::new(2, 6, 3);
+        dr.add_die(1, 4);
+
+        let rolled = dr.roll(&mut Loaded::new([2, 5, 6]));
+
+        assert_eq!(rolled.get_faces(), &[(4, 2), (6, 5), (6, 6)]);
+        assert_eq!(rolled.get_total(), 16);
+        assert_eq!(rolled.to_string(), "[2, 5, 6] + 3 = 16");
+    }
+
+    #[test]
+    fn _random_rolls_should_stay_within_the_die() {
+        let mut rng = rand::thread_rng();
+
+        assert!((0..100).all(|_| (1..=20).contains(&rng.roll_die(20))));
+    }
+
     #[test]
     fn _should_combine_like_die() {
         let mut dr = Roll::new(1, 6, 3);
@@ -0,0 +1,487 @@
+#![warn(clippy::pedantic)]
+
+use std::fmt;
+
+use crate::{
+    ability,
+    check::CheckKind,
+    modifiers::{Proficiency, Resistance},
+    race::DamageType,
+    skills::{self, Advantage},
+};
+
+/// A single contribution to a derived value and where it came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Component {
+    source: String,
+    value: isize,
+}
+
+impl Component {
+    pub fn new(source: impl Into<String>, value: isize) -> Self {
+        Component {
+            source: source.into(),
+            value,
+        }
+    }
+
+    #[must_use]
+    pub fn get_source(&self) -> &str {
+        &self.source
+    }
+
+    #[must_use]
+    pub fn get_value(&self) -> isize {
+        self.value
+    }
+}
+
+/// A derived value as the sum of its components.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Breakdown {
+    components: Vec<Component>,
+}
+
+impl Breakdown {
+    #[must_use]
+    pub fn get_components(&self) -> &[Component] {
+        &self.components
+    }
+
+    #[must_use]
+    pub fn get_total(&self) -> isize {
+        self.components.iter().map(Component::get_value).sum()
+    }
+
+    pub fn push(&mut self, component: Component) {
+        self.components.push(component);
+    }
+
+    /// Adds a component that brings the total down to `limit` if it is above it.
+    pub fn limit(&mut self, source: impl Into<String>, limit: isize) {
+        let total = self.get_total();
+
+        if total > limit {
+            self.push(Component::new(source, limit - total));
+        }
+    }
+}
+
+impl From<Vec<Component>> for Breakdown {
+    fn from(components: Vec<Component>) -> Self {
+        Breakdown { components }
+    }
+}
+
+impl From<Breakdown> for Vec<Component> {
+    fn from(breakdown: Breakdown) -> Self {
+        breakdown.components
+    }
+}
+
+impl Extend<Component> for Breakdown {
+    fn extend<I: IntoIterator<Item = Component>>(&mut self, iter: I) {
+        self.components.extend(iter);
+    }
+}
+
+impl fmt::Display for Breakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut components = self.components.iter();
+
+        if let Some(first) = components.next() {
+            write!(f, "{} ({})", first.value, first.source)?;
+        }
+
+        for component in components {
+            let sign = if component.value < 0 { '-' } else { '+' };
+            write!(
+                f,
+                " {sign} {} ({})",
+                component.value.abs(),
+                component.source
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// What an effect changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Target {
+    AbilityScore(ability::Identifier),
+    /// Every ability check, skills included.
+    AbilityChecks,
+    AbilityCheck(ability::Identifier),
+    Skill(skills::Identifier),
+    /// Every saving throw.
+    SavingThrows,
+    SavingThrow(ability::Identifier),
+    AttackRolls,
+    ArmorClass,
+    Initiative,
+    WalkingSpeed,
+    HitPointsMax,
+    /// Added to the hit point maximum once per character level, as with Tough.
+    HitPointsPerLevel,
+    Damage(DamageType),
+}
+
+impl Target {
+    /// Whether an effect on `self` also applies to `target`, e.g. a bonus to all saving
+    /// throws applies to Wisdom saves, or one to Dexterity checks applies to Stealth.
+    #[must_use]
+    pub fn covers(&self, target: Target) -> bool {
+        match (self, target) {
+            (Target::AbilityChecks, Target::AbilityCheck(_) | Target::Skill(_))
+            | (Target::SavingThrows, Target::SavingThrow(_)) => true,
+            (Target::AbilityCheck(ability), Target::Skill(skill)) => {
+                skill.get_default_ability() == *ability
+            }
+            _ => *self == target,
+        }
+    }
+}
+
+impl From<CheckKind> for Target {
+    fn from(kind: CheckKind) -> Self {
+        match kind {
+            CheckKind::Ability(ability) => Target::AbilityCheck(ability),
+            CheckKind::Skill(skill) => Target::Skill(skill),
+            CheckKind::SavingThrow(ability) => Target::SavingThrow(ability),
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Target::AbilityScore(ability) => format!("{ability} score"),
+            Target::AbilityChecks => String::from("ability checks"),
+            Target::AbilityCheck(ability) => format!("{ability} checks"),
+            Target::Skill(skill) => format!("{skill} checks"),
+            Target::SavingThrows => String::from("saving throws"),
+            Target::SavingThrow(ability) => format!("{ability} saves"),
+            Target::AttackRolls => String::from("attack rolls"),
+            Target::ArmorClass => String::from("AC"),
+            Target::Initiative => String::from("initiative"),
+            Target::WalkingSpeed => String::from("walking speed"),
+            Target::HitPointsMax => String::from("hit point maximum"),
+            Target::HitPointsPerLevel => String::from("hit points per level"),
+            Target::Damage(damage_type) => format!("{damage_type} damage"),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// How an effect changes its target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Modifier {
+    Add(isize),
+    /// Replaces the value outright, e.g. a speed of 0 while grappled.
+    Set(isize),
+    /// Raises the value to at least this much, e.g. an Amulet of Health's Constitution of 19.
+    Minimum(isize),
+    /// Raises the highest the value can reach, e.g. a Manual of Bodily Health's +2 to the
+    /// Constitution maximum.
+    RaiseMaximum(isize),
+    Advantage(Advantage),
+    Resistance(Resistance),
+    Proficiency(Proficiency),
+}
+
+impl fmt::Display for Modifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Modifier::Add(value) => format!("{value:+}"),
+            Modifier::Set(value) => format!("= {value}"),
+            Modifier::Minimum(value) => format!("at least {value}"),
+            Modifier::RaiseMaximum(value) => format!("maximum {value:+}"),
+            Modifier::Advantage(advantage) => advantage.to_string(),
+            Modifier::Resistance(resistance) => format!("{resistance:?}"),
+            Modifier::Proficiency(proficiency) => format!("{proficiency:?}"),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// A typed modifier registered by a feature, feat, item, spell or condition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Effect {
+    source: String,
+    target: Target,
+    modifier: Modifier,
+}
+
+impl Effect {
+    pub fn new(source: impl Into<String>, target: Target, modifier: Modifier) -> Self {
+        Effect {
+            source: source.into(),
+            target,
+            modifier,
+        }
+    }
+
+    #[must_use]
+    pub fn get_source(&self) -> &str {
+        &self.source
+    }
+
+    #[must_use]
+    pub fn get_target(&self) -> Target {
+        self.target
+    }
+
+    #[must_use]
+    pub fn get_modifier(&self) -> Modifier {
+        self.modifier
+    }
+}
+
+impl fmt::Display for Effect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} {}", self.source, self.target, self.modifier)
+    }
+}
+
+/// Every effect currently applying to a character, and how they combine.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Effects(Vec<Effect>);
+
+impl Effects {
+    pub fn add(&mut self, effect: Effect) {
+        self.0.push(effect);
+    }
+
+    /// Removes every effect registered by `source`, e.g. when a spell ends.
+    pub fn remove_source(&mut self, source: &str) {
+        self.0.retain(|effect| effect.source != source);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Effect> {
+        self.0.iter()
+    }
+
+    fn matching(&self, target: Target) -> impl Iterator<Item = &Effect> {
+        self.0
+            .iter()
+            .filter(move |effect| effect.target.covers(target))
+    }
+
+    /// Every flat bonus to `target`.
+    #[must_use]
+    pub fn get_bonuses(&self, target: Target) -> Vec<Component> {
+        self.matching(target)
+            .filter_map(|effect| match effect.modifier {
+                Modifier::Add(value) => Some(Component::new(effect.source.clone(), value)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// `maximum` raised by every `RaiseMaximum` on `target`.
+    #[must_use]
+    pub fn get_maximum(&self, target: Target, maximum: isize) -> isize {
+        maximum
+            + self
+                .matching(target)
+                .filter_map(|effect| match effect.modifier {
+                    Modifier::RaiseMaximum(value) => Some(value),
+                    _ => None,
+                })
+                .sum::<isize>()
+    }
+
+    /// Replaces the breakdown with the highest `Set` on `target`, then raises it to the highest
+    /// `Minimum` if it falls short.
+    #[must_use]
+    pub fn apply_overrides(&self, target: Target, breakdown: Breakdown) -> Breakdown {
+        let highest = |pick: fn(Modifier) -> Option<isize>| {
+            self.matching(target)
+                .filter_map(|effect| pick(effect.modifier).map(|value| (value, effect)))
+                .max_by_key(|(value, _)| *value)
+        };
+
+        let breakdown = match highest(|modifier| match modifier {
+            Modifier::Set(value) => Some(value),
+            _ => None,
+        }) {
+            Some((value, effect)) => Breakdown::from(vec![Component::new(&effect.source, value)]),
+            None => breakdown,
+        };
+
+        match highest(|modifier| match modifier {
+            Modifier::Minimum(value) => Some(value),
+            _ => None,
+        }) {
+            Some((value, effect)) if breakdown.get_total() < value => {
+                Breakdown::from(vec![Component::new(&effect.source, value)])
+            }
+            _ => breakdown,
+        }
+    }
+
+    /// Adds every bonus to `base` and then applies any overrides.
+    #[must_use]
+    pub fn resolve(&self, target: Target, base: impl Into<Breakdown>) -> Breakdown {
+        let mut breakdown = base.into();
+        breakdown.extend(self.get_bonuses(target));
+
+        self.apply_overrides(target, breakdown)
+    }
+
+    #[must_use]
+    pub fn get_advantages(&self, target: Target) -> Vec<Advantage> {
+        self.matching(target)
+            .filter_map(|effect| match effect.modifier {
+                Modifier::Advantage(advantage) => Some(advantage),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The best proficiency granted for `target`.
+    #[must_use]
+    pub fn get_proficiency(&self, target: Target) -> Option<Proficiency> {
+        self.matching(target)
+            .filter_map(|effect| match effect.modifier {
+                Modifier::Proficiency(proficiency) => Some(proficiency),
+                _ => None,
+            })
+            .max_by_key(|&proficiency| proficiency as usize)
+    }
+
+    /// Each resistance, immunity or vulnerability to `damage_type` with its source.
+    #[must_use]
+    pub fn get_resistances(&self, damage_type: DamageType) -> Vec<(&str, Resistance)> {
+        self.matching(Target::Damage(damage_type))
+            .filter_map(|effect| match effect.modifier {
+                Modifier::Resistance(resistance) => Some((effect.source.as_str(), resistance)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<Effect> for Effects {
+    fn from_iter<I: IntoIterator<Item = Effect>>(iter: I) -> Self {
+        Effects(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Effect> for Effects {
+    fn extend<I: IntoIterator<Item = Effect>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _broad_targets_should_cover_narrow_ones() {
+        let effects = Effects::from_iter([
+            Effect::new(
+                "Cloak of Protection",
+                Target::SavingThrows,
+                Modifier::Add(1),
+            ),
+            Effect::new(
+                "Bless",
+                Target::SavingThrow(ability::Identifier::Wisdom),
+                Modifier::Add(2),
+            ),
+        ]);
+
+        let breakdown = effects.resolve(
+            Target::SavingThrow(ability::Identifier::Wisdom),
+            vec![Component::new("Wisdom", 3)],
+        );
+
+        assert_eq!(breakdown.get_total(), 6);
+        assert_eq!(
+            breakdown.to_string(),
+            "3 (Wisdom) + 1 (Cloak of Protection) + 2 (Bless)"
+        );
+        assert_eq!(
+            effects
+                .resolve(
+                    Target::SavingThrow(ability::Identifier::Strength),
+                    vec![Component::new("Strength", 0)],
+                )
+                .get_total(),
+            1
+        );
+    }
+
+    #[test]
+    fn _set_should_replace_and_minimum_should_only_raise() {
+        let constitution = Target::AbilityScore(ability::Identifier::Constitution);
+        let effects = Effects::from_iter([Effect::new(
+            "Amulet of Health",
+            constitution,
+            Modifier::Minimum(19),
+        )]);
+
+        assert_eq!(
+            effects
+                .resolve(constitution, vec![Component::new("Base", 14)])
+                .to_string(),
+            "19 (Amulet of Health)"
+        );
+        assert_eq!(
+            effects
+                .resolve(constitution, vec![Component::new("Base", 20)])
+                .get_total(),
+            20
+        );
+
+        let effects = Effects::from_iter([
+            Effect::new("Longstrider", Target::WalkingSpeed, Modifier::Add(10)),
+            Effect::new("Grappled", Target::WalkingSpeed, Modifier::Set(0)),
+        ]);
+
+        assert_eq!(
+            effects
+                .resolve(Target::WalkingSpeed, vec![Component::new("Race", 30)])
+                .get_total(),
+            0
+        );
+    }
+
+    #[test]
+    fn _should_keep_the_best_granted_proficiency() {
+        let mut effects = Effects::from_iter([
+            Effect::new(
+                "Skilled",
+                Target::Skill(skills::Identifier::Stealth),
+                Modifier::Proficiency(Proficiency::Proficiency),
+            ),
+            Effect::new(
+                "Expertise",
+                Target::Skill(skills::Identifier::Stealth),
+                Modifier::Proficiency(Proficiency::Expertise),
+            ),
+        ]);
+
+        assert_eq!(
+            effects.get_proficiency(Target::Skill(skills::Identifier::Stealth)),
+            Some(Proficiency::Expertise)
+        );
+
+        effects.remove_source("Expertise");
+
+        assert_eq!(
+            effects.get_proficiency(Target::Skill(skills::Identifier::Stealth)),
+            Some(Proficiency::Proficiency)
+        );
+        assert_eq!(
+            effects.get_proficiency(Target::Skill(skills::Identifier::Arcana)),
+            None
+        );
+    }
+}
@@ -1,7 +1,78 @@
+use std::{error, fmt};
+
+use crate::{
+    ability,
+    effect::{Effect, Modifier, Target},
+    item::ArmorCategory,
+};
+
+/// Something a character needs before taking a feat.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Prerequisite {
+    /// A minimum score in an ability, e.g. Strength 13 for Grappler.
+    AbilityScore(ability::Identifier, usize),
+    /// A race or subrace, matched by name.
+    Race(String),
+    /// The ability to cast at least one spell.
+    Spellcasting,
+    ArmorProficiency(ArmorCategory),
+    /// Met if any of the prerequisites is, e.g. "Elf or Half-Elf".
+    AnyOf(Vec<Prerequisite>),
+}
+
+impl fmt::Display for Prerequisite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Prerequisite::AbilityScore(ability, minimum) => {
+                format!("{ability} {minimum} or higher")
+            }
+            Prerequisite::Race(race) => race.clone(),
+            Prerequisite::Spellcasting => String::from("The ability to cast at least one spell"),
+            Prerequisite::ArmorProficiency(armor_category) => {
+                format!("Proficiency with {armor_category}")
+            }
+            Prerequisite::AnyOf(prerequisites) => prerequisites
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" or "),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// Where a character got a feat from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Race(String),
+    Class(String),
+    /// Taken instead of the increase at `(class, level)`.
+    AbilityScoreImprovement(String, usize),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Source::Race(name) | Source::Class(name) => name.clone(),
+            Source::AbilityScoreImprovement(class, level) => {
+                format!("Ability Score Improvement ({class} {level})")
+            }
+        };
+
+        write!(f, "{result}")
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Feat {
     name: String,
     description: String,
+    effects: Vec<Effect>,
+    prerequisites: Vec<Prerequisite>,
+    /// Abilities a half-feat can increase by 1.
+    ability_options: Vec<ability::Identifier>,
+    chosen_ability: Option<ability::Identifier>,
 }
 
 impl Feat {
@@ -9,9 +80,73 @@ impl Feat {
         Feat {
             name: name.into(),
             description: description.into(),
+            effects: vec![],
+            prerequisites: vec![],
+            ability_options: vec![],
+            chosen_ability: None,
         }
     }
 
+    /// +5 to initiative.
+    pub fn alert() -> Self {
+        Feat::new(
+            "Alert",
+            "You gain a +5 bonus to initiative, can't be surprised while conscious, and other \
+             creatures don't gain advantage on attack rolls against you as a result of being \
+             unseen by you.",
+        )
+        .add_effect(Target::Initiative, Modifier::Add(5))
+    }
+
+    /// +2 maximum hit points per level.
+    pub fn tough() -> Self {
+        Feat::new(
+            "Tough",
+            "Your hit point maximum increases by an amount equal to twice your level when you \
+             gain this feat, and by an additional 2 hit points whenever you gain a level.",
+        )
+        .add_effect(Target::HitPointsPerLevel, Modifier::Add(2))
+    }
+
+    #[must_use]
+    pub fn add_effect(mut self, target: Target, modifier: Modifier) -> Self {
+        self.effects
+            .push(Effect::new(self.name.clone(), target, modifier));
+
+        self
+    }
+
+    #[must_use]
+    pub fn add_prerequisite(mut self, prerequisite: Prerequisite) -> Self {
+        self.prerequisites.push(prerequisite);
+
+        self
+    }
+
+    /// Makes this a half-feat increasing one of `abilities` by 1.
+    #[must_use]
+    pub fn ability_increase(mut self, abilities: impl Into<Vec<ability::Identifier>>) -> Self {
+        self.ability_options = abilities.into();
+
+        self
+    }
+
+    /// Picks the ability a half-feat increases.
+    ///
+    /// # Errors
+    ///
+    /// - `AbilityNotOffered`: the feat doesn't offer an increase to `ability`
+    ///
+    pub fn choose_ability(mut self, ability: ability::Identifier) -> Result<Self, FeatError> {
+        if !self.ability_options.contains(&ability) {
+            return Err(FeatError::AbilityNotOffered(ability));
+        }
+
+        self.chosen_ability = Some(ability);
+
+        Ok(self)
+    }
+
     pub fn get_name(&self) -> &str {
         &self.name
     }
@@ -19,4 +154,74 @@ impl Feat {
     pub fn get_description(&self) -> &str {
         &self.description
     }
+
+    pub fn get_prerequisites(&self) -> &[Prerequisite] {
+        &self.prerequisites
+    }
+
+    pub fn get_ability_options(&self) -> &[ability::Identifier] {
+        &self.ability_options
+    }
+
+    pub fn get_chosen_ability(&self) -> Option<ability::Identifier> {
+        self.chosen_ability
+    }
+
+    pub fn is_half_feat(&self) -> bool {
+        !self.ability_options.is_empty()
+    }
+
+    /// # Errors
+    ///
+    /// - `AbilityNotChosen`: a half-feat is taken without picking its ability
+    ///
+    pub fn validate(&self) -> Result<(), FeatError> {
+        if self.is_half_feat() && self.chosen_ability.is_none() {
+            return Err(FeatError::AbilityNotChosen(self.name.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// The feat's modifiers, plus the +1 of a half-feat once its ability is chosen.
+    pub fn get_effects(&self) -> Vec<Effect> {
+        self.effects
+            .iter()
+            .cloned()
+            .chain(self.chosen_ability.map(|ability| {
+                Effect::new(
+                    self.name.clone(),
+                    Target::AbilityScore(ability),
+                    Modifier::Add(1),
+                )
+            }))
+            .collect()
+    }
 }
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FeatError {
+    AbilityNotOffered(ability::Identifier),
+    AbilityNotChosen(String),
+    PrerequisiteNotMet(String, Prerequisite),
+}
+
+impl fmt::Display for FeatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            FeatError::AbilityNotOffered(ability) => {
+                format!("The feat doesn't increase {ability}.")
+            }
+            FeatError::AbilityNotChosen(name) => {
+                format!("Choose the ability {name} increases.")
+            }
+            FeatError::PrerequisiteNotMet(name, prerequisite) => {
+                format!("{name} requires: {prerequisite}.")
+            }
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for FeatError {}
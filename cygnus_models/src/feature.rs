@@ -0,0 +1,243 @@
+#![warn(clippy::pedantic)]
+
+use std::{error, fmt};
+
+use crate::ability;
+
+/// The kind of rest that recharges a limited feature. A long rest recharges short rest features
+/// too.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rest {
+    Short,
+    Long,
+}
+
+impl fmt::Display for Rest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Rest::Short => "Short Rest",
+            Rest::Long => "Long Rest",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// How many times a limited feature can be used between rests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UseCount {
+    Fixed(usize),
+    /// Uses by class level, as `(level, uses)` pairs from the lowest level up, e.g. Action Surge
+    /// gaining a second use at 17th level.
+    ByLevel(Vec<(usize, usize)>),
+    ProficiencyBonus,
+    /// The ability modifier, with a minimum of one use.
+    AbilityModifier(ability::Identifier),
+}
+
+impl UseCount {
+    #[must_use]
+    pub fn get_maximum(
+        &self,
+        class_level: usize,
+        proficiency_bonus: usize,
+        ability_modifier: impl Fn(ability::Identifier) -> isize,
+    ) -> usize {
+        match self {
+            UseCount::Fixed(uses) => *uses,
+            UseCount::ByLevel(levels) => levels
+                .iter()
+                .rev()
+                .find(|(level, _)| *level <= class_level)
+                .map_or(0, |(_, uses)| *uses),
+            UseCount::ProficiencyBonus => proficiency_bonus,
+            UseCount::AbilityModifier(ability) => usize::try_from(ability_modifier(*ability))
+                .unwrap_or(0)
+                .max(1),
+        }
+    }
+}
+
+impl fmt::Display for UseCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            UseCount::Fixed(uses) => uses.to_string(),
+            UseCount::ByLevel(levels) => levels
+                .iter()
+                .map(|(level, uses)| format!("{uses} at level {level}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            UseCount::ProficiencyBonus => String::from("Proficiency bonus"),
+            UseCount::AbilityModifier(ability) => format!("{ability} modifier (minimum 1)"),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// The uses of a limited feature and how many have been spent since it last recharged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Uses {
+    count: UseCount,
+    recharge: Rest,
+    spent: usize,
+}
+
+impl Uses {
+    #[must_use]
+    pub fn get_count(&self) -> &UseCount {
+        &self.count
+    }
+
+    #[must_use]
+    pub fn get_recharge(&self) -> Rest {
+        self.recharge
+    }
+
+    #[must_use]
+    pub fn get_spent(&self) -> usize {
+        self.spent
+    }
+}
+
+/// A class feature gained at a class level, like Second Wind or Flash of Genius.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Feature {
+    name: String,
+    description: String,
+    level: usize,
+    uses: Option<Uses>,
+}
+
+impl Feature {
+    pub fn new(name: impl Into<String>, description: impl Into<String>, level: usize) -> Self {
+        Feature {
+            name: name.into(),
+            description: description.into(),
+            level,
+            uses: None,
+        }
+    }
+
+    /// Limits the feature to `count` uses, regained on finishing a `recharge` rest.
+    #[must_use]
+    pub fn limited(mut self, count: UseCount, recharge: Rest) -> Self {
+        self.uses = Some(Uses {
+            count,
+            recharge,
+            spent: 0,
+        });
+
+        self
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+
+    /// The class level the feature is gained at.
+    #[must_use]
+    pub fn get_level(&self) -> usize {
+        self.level
+    }
+
+    #[must_use]
+    pub fn get_uses(&self) -> Option<&Uses> {
+        self.uses.as_ref()
+    }
+
+    /// Spends one use out of `maximum`, returning the uses left.
+    ///
+    /// # Errors
+    ///
+    /// - `Unlimited`: the feature has no limited uses
+    /// - `NoUsesLeft`: every use has been spent
+    ///
+    pub fn spend(&mut self, maximum: usize) -> Result<usize, FeatureError> {
+        let Some(uses) = self.uses.as_mut() else {
+            return Err(FeatureError::Unlimited(self.name.clone()));
+        };
+
+        if uses.spent >= maximum {
+            return Err(FeatureError::NoUsesLeft(self.name.clone()));
+        }
+
+        uses.spent += 1;
+
+        Ok(maximum - uses.spent)
+    }
+
+    /// Regains every use if the feature recharges on `rest`.
+    pub fn recharge(&mut self, rest: Rest) {
+        if let Some(uses) = self.uses.as_mut() {
+            if uses.recharge <= rest {
+                uses.spent = 0;
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FeatureError {
+    NotFound(String),
+    Unlimited(String),
+    NoUsesLeft(String),
+}
+
+impl fmt::Display for FeatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            FeatureError::NotFound(name) => format!("No feature named {name}."),
+            FeatureError::Unlimited(name) => format!("{name} doesn't have limited uses."),
+            FeatureError::NoUsesLeft(name) => format!("{name} has no uses left."),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for FeatureError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _uses_should_scale_with_level_and_recharge_on_the_right_rest() {
+        let mut action_surge = Feature::new("Action Surge", "Take one additional action.", 2)
+            .limited(UseCount::ByLevel(vec![(2, 1), (17, 2)]), Rest::Short);
+        let count = action_surge.get_uses().unwrap().get_count().clone();
+
+        assert_eq!(count.get_maximum(16, 5, |_| 0), 1);
+        assert_eq!(count.get_maximum(17, 6, |_| 0), 2);
+
+        assert_eq!(action_surge.spend(1), Ok(0));
+        assert_eq!(
+            action_surge.spend(1),
+            Err(FeatureError::NoUsesLeft(String::from("Action Surge")))
+        );
+
+        action_surge.recharge(Rest::Long);
+        assert_eq!(action_surge.get_uses().unwrap().get_spent(), 0);
+
+        let mut flash_of_genius = Feature::new("Flash of Genius", "Add your Int modifier.", 7)
+            .limited(
+                UseCount::AbilityModifier(ability::Identifier::Intelligence),
+                Rest::Long,
+            );
+        assert_eq!(
+            UseCount::AbilityModifier(ability::Identifier::Intelligence).get_maximum(7, 3, |_| -1),
+            1
+        );
+
+        flash_of_genius.spend(4).unwrap();
+        flash_of_genius.recharge(Rest::Short);
+        assert_eq!(flash_of_genius.get_uses().unwrap().get_spent(), 1);
+    }
+}
@@ -1,18 +1,489 @@
-use std::{error, fmt};
+use std::{error, fmt, mem};
+
+use crate::{
+    container::Container,
+    currency::Price,
+    dice::Roll,
+    effect::{Effect, Modifier, Target},
+    race::DamageType,
+    units::{Distance, Volume},
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ArmorClass {
     Light(usize),
     Medium(usize),
     Heavy(usize),
+    Shield(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArmorCategory {
+    Light,
+    Medium,
+    Heavy,
+    Shield,
+}
+
+impl fmt::Display for ArmorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            ArmorCategory::Light => "Light Armor",
+            ArmorCategory::Medium => "Medium Armor",
+            ArmorCategory::Heavy => "Heavy Armor",
+            ArmorCategory::Shield => "Shields",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl ArmorClass {
+    #[must_use]
+    pub fn get_category(&self) -> ArmorCategory {
+        match self {
+            ArmorClass::Light(_) => ArmorCategory::Light,
+            ArmorClass::Medium(_) => ArmorCategory::Medium,
+            ArmorClass::Heavy(_) => ArmorCategory::Heavy,
+            ArmorClass::Shield(_) => ArmorCategory::Shield,
+        }
+    }
+
+    #[must_use]
+    pub fn get_base(&self) -> usize {
+        match self {
+            ArmorClass::Light(ac)
+            | ArmorClass::Medium(ac)
+            | ArmorClass::Heavy(ac)
+            | ArmorClass::Shield(ac) => *ac,
+        }
+    }
+
+    /// The most Dexterity modifier the armor lets through, if any.
+    #[must_use]
+    pub fn get_max_dexterity_bonus(&self) -> Option<isize> {
+        match self {
+            ArmorClass::Light(_) => Some(isize::MAX),
+            ArmorClass::Medium(_) => Some(2),
+            ArmorClass::Heavy(_) | ArmorClass::Shield(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_body_armor(&self) -> bool {
+        !matches!(self, ArmorClass::Shield(_))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeaponCategory {
+    Simple,
+    Martial,
+}
+
+impl fmt::Display for WeaponCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            WeaponCategory::Simple => "Simple",
+            WeaponCategory::Martial => "Martial",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WeaponProperty {
+    Ammunition,
+    Finesse,
+    Heavy,
+    Light,
+    Loading,
+    Reach,
+    Thrown,
+    TwoHanded,
+    Versatile(Roll),
+}
+
+impl fmt::Display for WeaponProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            WeaponProperty::Ammunition => String::from("Ammunition"),
+            WeaponProperty::Finesse => String::from("Finesse"),
+            WeaponProperty::Heavy => String::from("Heavy"),
+            WeaponProperty::Light => String::from("Light"),
+            WeaponProperty::Loading => String::from("Loading"),
+            WeaponProperty::Reach => String::from("Reach"),
+            WeaponProperty::Thrown => String::from("Thrown"),
+            WeaponProperty::TwoHanded => String::from("Two-Handed"),
+            WeaponProperty::Versatile(roll) => format!("Versatile ({roll})"),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mastery {
+    Cleave,
+    Graze,
+    Nick,
+    Push,
+    Sap,
+    Slow,
+    Topple,
+    Vex,
+}
+
+impl fmt::Display for Mastery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Mastery::Cleave => "Cleave",
+            Mastery::Graze => "Graze",
+            Mastery::Nick => "Nick",
+            Mastery::Push => "Push",
+            Mastery::Sap => "Sap",
+            Mastery::Slow => "Slow",
+            Mastery::Topple => "Topple",
+            Mastery::Vex => "Vex",
+        };
+
+        write!(f, "{result}")
+    }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeaponRange {
+    normal: Distance,
+    long: Distance,
+}
+
+impl WeaponRange {
+    #[must_use]
+    pub fn new(normal: Distance, long: Distance) -> Self {
+        WeaponRange { normal, long }
+    }
+
+    #[must_use]
+    pub fn get_normal(&self) -> Distance {
+        self.normal
+    }
+
+    #[must_use]
+    pub fn get_long(&self) -> Distance {
+        self.long
+    }
+}
+
+impl fmt::Display for WeaponRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.normal, self.long)
+    }
+}
+
+/// How many hands a weapon is wielded in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Grip {
+    #[default]
+    OneHanded,
+    TwoHanded,
+}
+
+impl Grip {
+    #[must_use]
+    pub fn get_hands(&self) -> usize {
+        match self {
+            Grip::OneHanded => 1,
+            Grip::TwoHanded => 2,
+        }
+    }
+}
+
+impl fmt::Display for Grip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Grip::OneHanded => "One-Handed",
+            Grip::TwoHanded => "Two-Handed",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Weapon {
+    category: WeaponCategory,
+    damage: Roll,
+    damage_type: DamageType,
+    properties: Vec<WeaponProperty>,
+    range: Option<WeaponRange>,
+    mastery: Option<Mastery>,
+    grip: Grip,
+}
+
+impl Weapon {
+    #[must_use]
+    pub fn new(category: WeaponCategory, damage: Roll, damage_type: DamageType) -> Self {
+        Weapon {
+            category,
+            damage,
+            damage_type,
+            properties: vec![],
+            range: None,
+            mastery: None,
+            grip: Grip::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn add_property(mut self, property: WeaponProperty) -> Self {
+        if !self.has_property(&property) {
+            self.properties.push(property);
+        }
+
+        self
+    }
+
+    #[must_use]
+    pub fn range(mut self, normal: Distance, long: Distance) -> Self {
+        let _ = self.range.insert(WeaponRange::new(normal, long));
+
+        self
+    }
+
+    #[must_use]
+    pub fn mastery(mut self, mastery: Mastery) -> Self {
+        let _ = self.mastery.insert(mastery);
+
+        self
+    }
+
+    #[must_use]
+    pub fn get_category(&self) -> WeaponCategory {
+        self.category
+    }
+
+    #[must_use]
+    pub fn get_damage(&self) -> &Roll {
+        &self.damage
+    }
+
+    /// The damage dealt when wielded in two hands, if the weapon is versatile.
+    #[must_use]
+    pub fn get_versatile_damage(&self) -> Option<&Roll> {
+        self.properties.iter().find_map(|property| match property {
+            WeaponProperty::Versatile(roll) => Some(roll),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    pub fn get_damage_type(&self) -> DamageType {
+        self.damage_type
+    }
+
+    #[must_use]
+    pub fn get_properties(&self) -> &[WeaponProperty] {
+        &self.properties
+    }
+
+    /// Compares by kind only, so any `Versatile` roll matches any other.
+    #[must_use]
+    pub fn has_property(&self, property: &WeaponProperty) -> bool {
+        self.properties
+            .iter()
+            .any(|p| mem::discriminant(p) == mem::discriminant(property))
+    }
+
+    #[must_use]
+    pub fn get_range(&self) -> Option<WeaponRange> {
+        self.range
+    }
+
+    #[must_use]
+    pub fn get_mastery(&self) -> Option<Mastery> {
+        self.mastery
+    }
+
+    #[must_use]
+    pub fn is_ranged(&self) -> bool {
+        self.has_property(&WeaponProperty::Ammunition)
+    }
+
+    #[must_use]
+    pub fn is_two_handed(&self) -> bool {
+        self.has_property(&WeaponProperty::TwoHanded)
+    }
+
+    /// Two-handed weapons are always held in both hands; versatile ones in whichever grip was
+    /// chosen.
+    #[must_use]
+    pub fn get_grip(&self) -> Grip {
+        if self.is_two_handed() {
+            Grip::TwoHanded
+        } else {
+            self.grip
+        }
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `NotVersatile`: if the weapon can only be held one way
+    ///
+    pub fn set_grip(&mut self, grip: Grip) -> Result<(), GripError> {
+        if self.get_versatile_damage().is_none() {
+            return Err(GripError::NotVersatile);
+        }
+
+        self.grip = grip;
+
+        Ok(())
+    }
+
+    /// The damage for the current grip, using the versatile roll when held in two hands.
+    #[must_use]
+    pub fn get_wielded_damage(&self) -> &Roll {
+        match self.get_grip() {
+            Grip::TwoHanded => self.get_versatile_damage().unwrap_or(&self.damage),
+            Grip::OneHanded => &self.damage,
+        }
+    }
+
+    fn validate(&self) -> Result<(), ConstructionError> {
+        let needs_range = self.has_property(&WeaponProperty::Ammunition)
+            || self.has_property(&WeaponProperty::Thrown);
+
+        if needs_range && self.range.is_none() {
+            return Err(ConstructionError::MissingWeaponRange);
+        }
+
+        let conflicting = (self.has_property(&WeaponProperty::Light)
+            && self.has_property(&WeaponProperty::Heavy))
+            || (self.has_property(&WeaponProperty::TwoHanded)
+                && self.get_versatile_damage().is_some());
+
+        if conflicting {
+            return Err(ConstructionError::ConflictingWeaponProperties);
+        }
+
+        Ok(())
+    }
+}
+
+/// Who may attune to an item, e.g. "requires attunement by a wizard".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Prerequisite {
+    Class(String),
+    Race(String),
+}
+
+impl fmt::Display for Prerequisite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Prerequisite::Class(name) | Prerequisite::Race(name) => name,
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// An item's attunement requirement. Any one of the `prerequisites` is enough; an empty list
+/// means anyone can attune.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Attunement {
+    prerequisites: Vec<Prerequisite>,
+}
+
+impl Attunement {
+    #[must_use]
+    pub fn get_prerequisites(&self) -> &[Prerequisite] {
+        &self.prerequisites
+    }
+}
+
+impl fmt::Display for Attunement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.prerequisites.is_empty() {
+            return write!(f, "requires attunement");
+        }
+
+        let prerequisites = self
+            .prerequisites
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" or ");
+
+        write!(f, "requires attunement by {prerequisites}")
+    }
+}
+
+pub const MAX_ATTUNED_ITEMS: usize = 3;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AttunementError {
+    NotRequired,
+    AlreadyAttuned,
+    NotAttuned,
+    PrerequisiteNotMet(Attunement),
+    LimitReached,
+}
+
+impl fmt::Display for AttunementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            AttunementError::NotRequired => "Item does not require attunement.".to_owned(),
+            AttunementError::AlreadyAttuned => "Already attuned to item.".to_owned(),
+            AttunementError::NotAttuned => "Not attuned to item.".to_owned(),
+            AttunementError::PrerequisiteNotMet(attunement) => format!("Item {attunement}."),
+            AttunementError::LimitReached => {
+                format!("Cannot attune to more than {MAX_ATTUNED_ITEMS} items at once.")
+            }
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for AttunementError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GripError {
+    NotAWeapon,
+    NotVersatile,
+}
+
+impl fmt::Display for GripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            GripError::NotAWeapon => "Only weapons can change grip.",
+            GripError::NotVersatile => "Only versatile weapons can change grip.",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for GripError {}
+
 #[derive(Debug, Default)]
 pub struct Builder {
     name: Option<String>,
-    weight: Option<usize>,
+    weight: Option<f64>,
     types: Vec<String>,
     armor_class: Option<ArmorClass>,
+    armor_class_bonus: isize,
+    strength_requirement: Option<usize>,
+    stealth_disadvantage: bool,
+    weapon: Option<Weapon>,
+    attunement: Option<Attunement>,
+    quantity: Option<usize>,
+    stackable: bool,
+    cost: Option<Price>,
+    volume: Option<Volume>,
+    container: Option<Container>,
+    effects: Vec<(Target, Modifier)>,
 }
 
 impl Builder {
@@ -32,7 +503,16 @@ impl Builder {
         Ok(self)
     }
 
-    pub fn weight(mut self, weight: usize) -> Result<Self, ConstructionError> {
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidWeight`: if `weight` is negative or not a finite number of pounds
+    ///
+    pub fn weight(mut self, weight: f64) -> Result<Self, ConstructionError> {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(ConstructionError::InvalidWeight);
+        }
+
         self.weight = Some(weight);
 
         Ok(self)
@@ -50,17 +530,144 @@ impl Builder {
         Ok(self)
     }
 
+    /// A flat bonus to AC granted while the item is equipped, e.g. +1 armor or a Ring of Protection.
+    pub fn armor_class_bonus(mut self, bonus: isize) -> Result<Self, ConstructionError> {
+        self.armor_class_bonus = bonus;
+
+        Ok(self)
+    }
+
+    pub fn strength_requirement(mut self, strength: usize) -> Result<Self, ConstructionError> {
+        self.strength_requirement = Some(strength);
+
+        Ok(self)
+    }
+
+    pub fn stealth_disadvantage(mut self) -> Result<Self, ConstructionError> {
+        self.stealth_disadvantage = true;
+
+        Ok(self)
+    }
+
+    pub fn requires_attunement(mut self) -> Result<Self, ConstructionError> {
+        self.attunement.get_or_insert_with(Default::default);
+
+        Ok(self)
+    }
+
+    /// Can be called repeatedly for items usable by several classes or races.
+    pub fn requires_attunement_by(
+        mut self,
+        prerequisite: Prerequisite,
+    ) -> Result<Self, ConstructionError> {
+        self.attunement
+            .get_or_insert_with(Default::default)
+            .prerequisites
+            .push(prerequisite);
+
+        Ok(self)
+    }
+
+    /// Lets identical copies, like arrows or rations, share one entry with a count.
+    pub fn stackable(mut self) -> Result<Self, ConstructionError> {
+        self.stackable = true;
+
+        Ok(self)
+    }
+
+    pub fn quantity(mut self, quantity: usize) -> Result<Self, ConstructionError> {
+        if quantity == 0 {
+            return Err(ConstructionError::ZeroQuantity);
+        }
+
+        self.quantity = Some(quantity);
+
+        Ok(self)
+    }
+
+    /// The price of a single unit.
+    pub fn cost(mut self, cost: Price) -> Result<Self, ConstructionError> {
+        self.cost = Some(cost);
+
+        Ok(self)
+    }
+
+    /// The space a single unit takes up inside a container.
+    pub fn volume(mut self, volume: Volume) -> Result<Self, ConstructionError> {
+        self.volume = Some(volume);
+
+        Ok(self)
+    }
+
+    pub fn container(mut self, container: Container) -> Result<Self, ConstructionError> {
+        self.container = Some(container);
+
+        Ok(self)
+    }
+
+    pub fn weapon(mut self, weapon: Weapon) -> Result<Self, ConstructionError> {
+        weapon.validate()?;
+
+        self.weapon = Some(weapon);
+
+        Ok(self)
+    }
+
+    /// An effect granted while the item is equipped and, if it requires attunement, attuned,
+    /// e.g. a Cloak of Protection's +1 to saving throws.
+    pub fn add_effect(
+        mut self,
+        target: Target,
+        modifier: Modifier,
+    ) -> Result<Self, ConstructionError> {
+        self.effects.push((target, modifier));
+
+        Ok(self)
+    }
+
     pub fn build(self) -> Result<Item, ConstructionError> {
         let name = self.name.ok_or(ConstructionError::MissingName)?;
-        let weight = self.weight.unwrap_or(0);
+        let weight = self.weight.unwrap_or(0.0);
         let types = self.types.clone();
         let armor_class = self.armor_class;
+        let armor_class_bonus = self.armor_class_bonus;
+        let strength_requirement = self.strength_requirement;
+        let stealth_disadvantage = self.stealth_disadvantage;
+        let weapon = self.weapon;
+        let attunement = self.attunement;
+        let quantity = self.quantity.unwrap_or(1);
+        let stackable = self.stackable;
+        let cost = self.cost;
+        let volume = self.volume;
+        let container = self.container;
+        let effects = self
+            .effects
+            .into_iter()
+            .map(|(target, modifier)| Effect::new(name.clone(), target, modifier))
+            .collect();
+
+        if quantity > 1 && !stackable {
+            return Err(ConstructionError::NotStackable);
+        }
 
         Ok(Item {
             name,
             weight,
             types,
             armor_class,
+            armor_class_bonus,
+            strength_requirement,
+            stealth_disadvantage,
+            weapon,
+            attunement,
+            attuned: false,
+            infusion: None,
+            quantity,
+            stackable,
+            cost,
+            volume,
+            container,
+            effects,
         })
     }
 }
@@ -68,12 +675,28 @@ impl Builder {
 #[derive(Debug)]
 pub enum ConstructionError {
     MissingName,
+    MissingWeaponRange,
+    ConflictingWeaponProperties,
+    ZeroQuantity,
+    NotStackable,
+    InvalidWeight,
 }
 
 impl fmt::Display for ConstructionError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let result = match self {
             ConstructionError::MissingName => "Cannot create an Item without a name.",
+            ConstructionError::MissingWeaponRange => {
+                "Thrown and ammunition weapons need a normal and long range."
+            }
+            ConstructionError::ConflictingWeaponProperties => {
+                "Weapon cannot be both light and heavy, or both two-handed and versatile."
+            }
+            ConstructionError::ZeroQuantity => "Cannot create an Item with a quantity of zero.",
+            ConstructionError::NotStackable => {
+                "Only stackable Items can have a quantity above one."
+            }
+            ConstructionError::InvalidWeight => "Item weight must be zero or more pounds.",
         };
 
         write!(f, "{result}")
@@ -82,12 +705,52 @@ impl fmt::Display for ConstructionError {
 
 impl error::Error for ConstructionError {}
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum StackError {
+    NotStackable,
+    Mismatched,
+    InsufficientQuantity { requested: usize, available: usize },
+    NotFound(String),
+}
+
+impl fmt::Display for StackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            StackError::NotStackable => "Item cannot be stacked.".to_owned(),
+            StackError::Mismatched => "Only identical items can be stacked together.".to_owned(),
+            StackError::InsufficientQuantity {
+                requested,
+                available,
+            } => format!("Cannot take {requested} from a stack of {available}."),
+            StackError::NotFound(name) => format!("No item called {name} to take from."),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for StackError {}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Item {
     name: String,
-    weight: usize,
+    weight: f64,
     types: Vec<String>,
     armor_class: Option<ArmorClass>,
+    armor_class_bonus: isize,
+    strength_requirement: Option<usize>,
+    stealth_disadvantage: bool,
+    weapon: Option<Weapon>,
+    attunement: Option<Attunement>,
+    attuned: bool,
+    /// The artificer infusion the item holds and when it was put in, to tell the oldest apart.
+    infusion: Option<(String, usize)>,
+    quantity: usize,
+    stackable: bool,
+    cost: Option<Price>,
+    volume: Option<Volume>,
+    container: Option<Container>,
+    effects: Vec<Effect>,
 }
 
 impl Item {
@@ -95,31 +758,334 @@ impl Item {
         &self.name
     }
 
+    /// The weight of a single unit.
     #[must_use]
-    pub fn get_weight(&self) -> usize {
+    pub fn get_weight(&self) -> f64 {
         self.weight
     }
 
+    /// The weight of the whole stack, including anything stored inside it.
+    #[must_use]
+    pub fn get_total_weight(&self) -> f64 {
+        let contents_weight = self
+            .container
+            .as_ref()
+            .filter(|container| !container.is_extradimensional())
+            .map_or(0.0, Container::get_contents_weight);
+
+        self.weight * self.quantity as f64 + contents_weight
+    }
+
+    #[must_use]
+    pub fn get_volume(&self) -> Option<Volume> {
+        self.volume
+    }
+
+    #[must_use]
+    pub fn get_total_volume(&self) -> Option<Volume> {
+        self.volume
+            .map(|volume| Volume::CubicFeet(volume.get_cubic_feet() * self.quantity as f64))
+    }
+
+    pub fn get_container(&self) -> Option<&Container> {
+        self.container.as_ref()
+    }
+
+    pub fn get_container_mut(&mut self) -> Option<&mut Container> {
+        self.container.as_mut()
+    }
+
+    #[must_use]
+    pub fn is_container(&self) -> bool {
+        self.container.is_some()
+    }
+
+    #[must_use]
+    pub fn get_quantity(&self) -> usize {
+        self.quantity
+    }
+
+    #[must_use]
+    pub fn is_stackable(&self) -> bool {
+        self.stackable
+    }
+
+    /// The price of a single unit.
+    #[must_use]
+    pub fn get_cost(&self) -> Option<Price> {
+        self.cost
+    }
+
+    /// Whether both items are stackable and identical apart from their quantity.
+    #[must_use]
+    pub fn can_stack_with(&self, other: &Item) -> bool {
+        self.stackable
+            && Item {
+                quantity: self.quantity,
+                ..other.clone()
+            } == *self
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `Mismatched`: if `other` is not the same stackable item
+    ///
+    pub fn merge(&mut self, other: &Item) -> Result<(), StackError> {
+        if !self.can_stack_with(other) {
+            return Err(StackError::Mismatched);
+        }
+
+        self.quantity += other.quantity;
+
+        Ok(())
+    }
+
+    /// Takes `amount` off the stack as a new stack of its own.
+    ///
+    /// # Errors
+    ///
+    /// - `NotStackable`: if the item isn't stackable
+    /// - `InsufficientQuantity`: if `amount` is zero or would leave this stack empty
+    ///
+    pub fn split(&mut self, amount: usize) -> Result<Item, StackError> {
+        if !self.stackable {
+            return Err(StackError::NotStackable);
+        }
+
+        if amount == 0 || amount >= self.quantity {
+            return Err(StackError::InsufficientQuantity {
+                requested: amount,
+                available: self.quantity,
+            });
+        }
+
+        self.quantity -= amount;
+
+        Ok(Item {
+            quantity: amount,
+            ..self.clone()
+        })
+    }
+
     pub fn has_type(&self, item_type: impl Into<String>) -> bool {
         self.types.contains(&item_type.into())
     }
 
+    #[must_use]
+    pub fn get_types(&self) -> &[String] {
+        &self.types
+    }
+
     pub fn get_armor_class(&self) -> Option<ArmorClass> {
         self.armor_class
     }
+
+    #[must_use]
+    pub fn get_armor_class_bonus(&self) -> isize {
+        self.armor_class_bonus
+    }
+
+    #[must_use]
+    pub fn get_effects(&self) -> &[Effect] {
+        &self.effects
+    }
+
+    /// The minimum Strength score to wear the armor without losing speed.
+    #[must_use]
+    pub fn get_strength_requirement(&self) -> Option<usize> {
+        self.strength_requirement
+    }
+
+    #[must_use]
+    pub fn has_stealth_disadvantage(&self) -> bool {
+        self.stealth_disadvantage
+    }
+
+    #[must_use]
+    pub fn is_body_armor(&self) -> bool {
+        self.armor_class.is_some_and(|ac| ac.is_body_armor())
+    }
+
+    #[must_use]
+    pub fn is_shield(&self) -> bool {
+        matches!(self.armor_class, Some(ArmorClass::Shield(_)))
+    }
+
+    #[must_use]
+    pub fn get_weapon(&self) -> Option<&Weapon> {
+        self.weapon.as_ref()
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `NotAWeapon`: if the item isn't a weapon
+    /// - `NotVersatile`: if the weapon can only be held one way
+    ///
+    pub fn set_grip(&mut self, grip: Grip) -> Result<(), GripError> {
+        self.weapon
+            .as_mut()
+            .ok_or(GripError::NotAWeapon)?
+            .set_grip(grip)
+    }
+
+    /// How many equipment slots the item takes up at once, e.g. both hands for a greatsword.
+    #[must_use]
+    pub fn get_slot_count(&self) -> usize {
+        self.weapon
+            .as_ref()
+            .map_or(1, |weapon| weapon.get_grip().get_hands())
+    }
+
+    #[must_use]
+    pub fn is_weapon(&self) -> bool {
+        self.weapon.is_some()
+    }
+
+    pub fn get_attunement(&self) -> Option<&Attunement> {
+        self.attunement.as_ref()
+    }
+
+    #[must_use]
+    pub fn requires_attunement(&self) -> bool {
+        self.attunement.is_some()
+    }
+
+    #[must_use]
+    pub fn is_attuned(&self) -> bool {
+        self.attuned
+    }
+
+    /// Whether the item's magic works: it either needs no attunement or is attuned.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.attunement.is_none() || self.attuned
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `NotRequired`: if the item does not require attunement
+    /// - `AlreadyAttuned`: if the item is already attuned
+    ///
+    pub fn attune(&mut self) -> Result<(), AttunementError> {
+        if self.attunement.is_none() {
+            return Err(AttunementError::NotRequired);
+        }
+
+        if self.attuned {
+            return Err(AttunementError::AlreadyAttuned);
+        }
+
+        self.attuned = true;
+
+        Ok(())
+    }
+
+    ///
+    /// # Errors
+    ///
+    /// - `NotAttuned`: if the item is not currently attuned
+    ///
+    pub fn unattune(&mut self) -> Result<(), AttunementError> {
+        if !self.attuned {
+            return Err(AttunementError::NotAttuned);
+        }
+
+        self.attuned = false;
+
+        Ok(())
+    }
+
+    /// The name of the artificer infusion the item holds, if any.
+    #[must_use]
+    pub fn get_infusion(&self) -> Option<&str> {
+        self.infusion.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    /// When the item was infused: items infused earlier have a lower order.
+    #[must_use]
+    pub fn get_infusion_order(&self) -> Option<usize> {
+        self.infusion.as_ref().map(|(_, order)| *order)
+    }
+
+    pub(crate) fn infuse(&mut self, infusion: impl Into<String>, order: usize) {
+        self.infusion = Some((infusion.into(), order));
+    }
+
+    /// Ends the item's infusion, returning its name.
+    pub(crate) fn end_infusion(&mut self) -> Option<String> {
+        self.infusion.take().map(|(name, _)| name)
+    }
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Items(Vec<Item>);
 
 impl Items {
     #[must_use]
-    pub fn get_total_weight(&self) -> usize {
-        self.0.iter().map(Item::get_weight).sum()
+    pub fn get_total_weight(&self) -> f64 {
+        self.0.iter().map(Item::get_total_weight).sum()
     }
 
+    /// Stackable items join an existing stack of the same item when there is one.
     pub fn add_item(&mut self, item: Item) {
-        self.0.push(item);
+        match self.0.iter_mut().find(|stack| stack.can_stack_with(&item)) {
+            Some(stack) => stack.quantity += item.quantity,
+            None => self.0.push(item),
+        }
+    }
+
+    /// Takes `amount` of the first item called `name` out of the list, splitting its stack if
+    /// only part of it is taken.
+    ///
+    /// # Errors
+    ///
+    /// - `NotFound`: if there is no such item
+    /// - `InsufficientQuantity`: if its stack is too small
+    ///
+    pub fn take(&mut self, name: &str, amount: usize) -> Result<Item, StackError> {
+        let index = self
+            .0
+            .iter()
+            .position(|item| item.get_name() == name)
+            .ok_or_else(|| StackError::NotFound(name.to_owned()))?;
+
+        if amount == self.0[index].quantity {
+            Ok(self.0.remove(index))
+        } else {
+            self.0[index].split(amount)
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Item> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn find(&self, name: &str) -> Option<&Item> {
+        self.0.iter().find(|item| item.get_name() == name)
+    }
+
+    /// Takes the first item called `name` out of the list.
+    pub fn remove_item(&mut self, name: &str) -> Option<Item> {
+        let index = self.0.iter().position(|item| item.get_name() == name)?;
+
+        Some(self.0.remove(index))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Item> {
+        self.0.iter_mut()
     }
 }
 
@@ -131,7 +1097,7 @@ mod tests {
     fn _empty_items_should_have_no_weight() {
         let items = Items::default();
 
-        assert_eq!(items.get_total_weight(), 0);
+        assert_eq!(items.get_total_weight(), 0.0);
     }
 
     #[test]
@@ -139,24 +1105,329 @@ mod tests {
         let items = Items(vec![
             Item {
                 name: String::from("one"),
-                weight: 1,
+                weight: 1.0,
                 types: vec![],
                 armor_class: None,
+                armor_class_bonus: 0,
+                strength_requirement: None,
+                stealth_disadvantage: false,
+                weapon: None,
+                attunement: None,
+                attuned: false,
+                infusion: None,
+                quantity: 1,
+                stackable: false,
+                cost: None,
+                volume: None,
+                container: None,
+                effects: vec![],
             },
             Item {
                 name: String::from("two"),
-                weight: 2,
+                weight: 2.0,
                 types: vec![],
                 armor_class: None,
+                armor_class_bonus: 0,
+                strength_requirement: None,
+                stealth_disadvantage: false,
+                weapon: None,
+                attunement: None,
+                attuned: false,
+                infusion: None,
+                quantity: 1,
+                stackable: false,
+                cost: None,
+                volume: None,
+                container: None,
+                effects: vec![],
             },
             Item {
                 name: String::from("three"),
-                weight: 3,
+                weight: 3.0,
                 types: vec![],
                 armor_class: None,
+                armor_class_bonus: 0,
+                strength_requirement: None,
+                stealth_disadvantage: false,
+                weapon: None,
+                attunement: None,
+                attuned: false,
+                infusion: None,
+                quantity: 1,
+                stackable: false,
+                cost: None,
+                volume: None,
+                container: None,
+                effects: vec![],
             },
         ]);
 
-        assert_eq!(items.get_total_weight(), 6);
+        assert_eq!(items.get_total_weight(), 6.0);
+    }
+
+    mod stack {
+        use std::error::Error;
+
+        use crate::currency::Coin;
+
+        use super::*;
+
+        fn arrows(quantity: usize) -> Result<Item, ConstructionError> {
+            Builder::new()
+                .name("Arrow")?
+                .stackable()?
+                .quantity(quantity)?
+                .cost(Price::new(5, Coin::Copper))?
+                .build()
+        }
+
+        #[test]
+        fn _should_not_allow_quantities_of_unstackable_items() -> Result<(), Box<dyn Error>> {
+            let result = Builder::new().name("Longsword")?.quantity(2)?.build();
+
+            assert!(matches!(result, Err(ConstructionError::NotStackable)));
+
+            Ok(())
+        }
+
+        #[test]
+        fn _should_merge_identical_stacks_when_added() -> Result<(), Box<dyn Error>> {
+            let mut items = Items::default();
+            items.add_item(arrows(20)?);
+            items.add_item(arrows(30)?);
+
+            assert_eq!(items.len(), 1);
+            assert_eq!(items.find("Arrow").map(Item::get_quantity), Some(50));
+
+            Ok(())
+        }
+
+        #[test]
+        fn _should_split_and_take_from_stacks() -> Result<(), Box<dyn Error>> {
+            let mut items = Items::default();
+            items.add_item(arrows(50)?);
+
+            let taken = items.take("Arrow", 10)?;
+
+            assert_eq!(taken.get_quantity(), 10);
+            assert_eq!(items.find("Arrow").map(Item::get_quantity), Some(40));
+
+            items.take("Arrow", 40)?;
+
+            assert!(items.is_empty());
+            assert_eq!(
+                items.take("Arrow", 1),
+                Err(StackError::NotFound(String::from("Arrow")))
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn _stack_weight_should_scale_with_quantity() -> Result<(), Box<dyn Error>> {
+            let rations = Builder::new()
+                .name("Rations")?
+                .weight(2.0)?
+                .stackable()?
+                .quantity(5)?
+                .build()?;
+
+            assert_eq!(rations.get_weight(), 2.0);
+            assert_eq!(rations.get_total_weight(), 10.0);
+
+            Ok(())
+        }
+
+        #[test]
+        fn _should_support_fractional_weights() -> Result<(), Box<dyn Error>> {
+            let darts = Builder::new()
+                .name("Dart")?
+                .weight(0.25)?
+                .stackable()?
+                .quantity(10)?
+                .build()?;
+
+            assert_eq!(darts.get_total_weight(), 2.5);
+            assert!(matches!(
+                Builder::new().weight(-1.0),
+                Err(ConstructionError::InvalidWeight)
+            ));
+
+            Ok(())
+        }
+    }
+
+    mod attunement {
+        use std::error::Error;
+
+        use super::*;
+
+        #[test]
+        fn _should_only_be_active_once_attuned() -> Result<(), Box<dyn Error>> {
+            let mut ring = Builder::new()
+                .name("Ring of Protection")?
+                .requires_attunement()?
+                .build()?;
+
+            assert!(!ring.is_active());
+
+            ring.attune()?;
+            assert!(ring.is_active());
+
+            ring.unattune()?;
+            assert!(!ring.is_active());
+
+            Ok(())
+        }
+
+        #[test]
+        fn _should_not_attune_to_mundane_items() -> Result<(), Box<dyn Error>> {
+            let mut rope = Builder::new().name("Rope")?.build()?;
+
+            assert!(rope.is_active());
+            assert_eq!(rope.attune(), Err(AttunementError::NotRequired));
+
+            Ok(())
+        }
+
+        #[test]
+        fn _should_describe_prerequisites() -> Result<(), Box<dyn Error>> {
+            let staff = Builder::new()
+                .name("Staff of Power")?
+                .requires_attunement_by(Prerequisite::Class("Sorcerer".into()))?
+                .requires_attunement_by(Prerequisite::Class("Wizard".into()))?
+                .build()?;
+
+            assert_eq!(
+                staff.get_attunement().map(ToString::to_string),
+                Some("requires attunement by Sorcerer or Wizard".into())
+            );
+
+            Ok(())
+        }
+    }
+
+    mod weapon {
+        use std::error::Error;
+
+        use super::*;
+
+        #[test]
+        fn _should_build_item_with_weapon_data() -> Result<(), Box<dyn Error>> {
+            let longsword = Weapon::new(
+                WeaponCategory::Martial,
+                Roll::new(1, 8, 0),
+                DamageType::Slashing,
+            )
+            .add_property(WeaponProperty::Versatile(Roll::new(1, 10, 0)))
+            .mastery(Mastery::Sap);
+
+            let item = Builder::new()
+                .name("Longsword")?
+                .weight(3.0)?
+                .weapon(longsword.clone())?
+                .build()?;
+
+            assert!(item.is_weapon());
+            assert_eq!(item.get_weapon(), Some(&longsword));
+            assert_eq!(longsword.get_versatile_damage(), Some(&Roll::new(1, 10, 0)));
+            assert_eq!(longsword.get_mastery(), Some(Mastery::Sap));
+
+            Ok(())
+        }
+
+        #[test]
+        fn _should_match_versatile_property_regardless_of_roll() {
+            let quarterstaff = Weapon::new(
+                WeaponCategory::Simple,
+                Roll::new(1, 6, 0),
+                DamageType::Bludgeoning,
+            )
+            .add_property(WeaponProperty::Versatile(Roll::new(1, 8, 0)));
+
+            assert!(quarterstaff.has_property(&WeaponProperty::Versatile(Roll::new(1, 4, 0))));
+            assert!(!quarterstaff.has_property(&WeaponProperty::Finesse));
+        }
+
+        #[test]
+        fn _versatile_weapons_should_switch_grip_and_damage() -> Result<(), Box<dyn Error>> {
+            let mut quarterstaff = Builder::new()
+                .name("Quarterstaff")?
+                .weapon(
+                    Weapon::new(
+                        WeaponCategory::Simple,
+                        Roll::new(1, 6, 0),
+                        DamageType::Bludgeoning,
+                    )
+                    .add_property(WeaponProperty::Versatile(Roll::new(1, 8, 0))),
+                )?
+                .build()?;
+            assert_eq!(quarterstaff.get_slot_count(), 1);
+
+            quarterstaff.set_grip(Grip::TwoHanded)?;
+
+            assert_eq!(quarterstaff.get_slot_count(), 2);
+            assert_eq!(
+                quarterstaff.get_weapon().map(Weapon::get_wielded_damage),
+                Some(&Roll::new(1, 8, 0))
+            );
+
+            let mut dagger = Builder::new()
+                .name("Dagger")?
+                .weapon(Weapon::new(
+                    WeaponCategory::Simple,
+                    Roll::new(1, 4, 0),
+                    DamageType::Piercing,
+                ))?
+                .build()?;
+            assert_eq!(
+                dagger.set_grip(Grip::TwoHanded),
+                Err(GripError::NotVersatile)
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn _should_require_range_for_thrown_and_ammunition_weapons() -> Result<(), Box<dyn Error>> {
+            let javelin = Weapon::new(
+                WeaponCategory::Simple,
+                Roll::new(1, 6, 0),
+                DamageType::Piercing,
+            )
+            .add_property(WeaponProperty::Thrown);
+
+            let result = Builder::new().name("Javelin")?.weapon(javelin.clone());
+            assert!(matches!(result, Err(ConstructionError::MissingWeaponRange)));
+
+            let javelin = javelin.range(Distance::Feet(30), Distance::Feet(120));
+            let item = Builder::new().name("Javelin")?.weapon(javelin)?.build()?;
+            assert_eq!(
+                item.get_weapon().and_then(Weapon::get_range),
+                Some(WeaponRange::new(Distance::Feet(30), Distance::Feet(120)))
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn _should_reject_conflicting_properties() -> Result<(), Box<dyn Error>> {
+            let greatclub = Weapon::new(
+                WeaponCategory::Simple,
+                Roll::new(1, 8, 0),
+                DamageType::Bludgeoning,
+            )
+            .add_property(WeaponProperty::TwoHanded)
+            .add_property(WeaponProperty::Versatile(Roll::new(1, 10, 0)));
+
+            let result = Builder::new().name("Greatclub")?.weapon(greatclub);
+
+            assert!(matches!(
+                result,
+                Err(ConstructionError::ConflictingWeaponProperties)
+            ));
+
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,163 @@
+#![warn(clippy::pedantic)]
+
+use std::fmt;
+
+use crate::catalogue::{self, impl_entry_eq, Entry};
+
+/// How widely a language is known.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rarity {
+    Standard,
+    Exotic,
+    /// Kept within a class or group, like Druidic or Thieves' Cant.
+    Secret,
+}
+
+impl fmt::Display for Rarity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Rarity::Standard => "Standard",
+            Rarity::Exotic => "Exotic",
+            Rarity::Secret => "Secret",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// A spoken language and the script it's written in. Languages with the same name are the same
+/// language, whatever the case.
+#[derive(Clone, Debug)]
+pub struct Language {
+    name: String,
+    rarity: Rarity,
+    script: Option<String>,
+}
+
+const LANGUAGES: [(&str, Rarity, Option<&str>); 18] = [
+    ("Common", Rarity::Standard, Some("Common")),
+    ("Dwarvish", Rarity::Standard, Some("Dwarvish")),
+    ("Elvish", Rarity::Standard, Some("Elvish")),
+    ("Giant", Rarity::Standard, Some("Dwarvish")),
+    ("Gnomish", Rarity::Standard, Some("Dwarvish")),
+    ("Goblin", Rarity::Standard, Some("Dwarvish")),
+    ("Halfling", Rarity::Standard, Some("Common")),
+    ("Orc", Rarity::Standard, Some("Dwarvish")),
+    ("Abyssal", Rarity::Exotic, Some("Infernal")),
+    ("Celestial", Rarity::Exotic, Some("Celestial")),
+    ("Draconic", Rarity::Exotic, Some("Draconic")),
+    ("Deep Speech", Rarity::Exotic, None),
+    ("Infernal", Rarity::Exotic, Some("Infernal")),
+    ("Primordial", Rarity::Exotic, Some("Dwarvish")),
+    ("Sylvan", Rarity::Exotic, Some("Elvish")),
+    ("Undercommon", Rarity::Exotic, Some("Elvish")),
+    ("Druidic", Rarity::Secret, Some("Druidic")),
+    ("Thieves' Cant", Rarity::Secret, None),
+];
+
+impl Language {
+    /// A language outside the built-in catalogue.
+    pub fn new(name: impl Into<String>, rarity: Rarity, script: Option<&str>) -> Self {
+        Language {
+            name: name.into(),
+            rarity,
+            script: script.map(str::to_owned),
+        }
+    }
+
+    /// The built-in language with this name, ignoring case, or a standard homebrew language
+    /// without a script. Use [`Catalogue::named`] to reach homebrew details too.
+    pub fn named(name: impl Into<String>) -> Self {
+        Catalogue::default().named(name)
+    }
+
+    #[must_use]
+    pub fn common() -> Self {
+        Language::named("Common")
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn get_rarity(&self) -> Rarity {
+        self.rarity
+    }
+
+    #[must_use]
+    pub fn get_script(&self) -> Option<&str> {
+        self.script.as_deref()
+    }
+}
+
+impl_entry_eq!(Language);
+
+impl Entry for Language {
+    fn built_in() -> Vec<Self> {
+        LANGUAGES
+            .iter()
+            .map(|&(name, rarity, script)| Language::new(name, rarity, script))
+            .collect()
+    }
+
+    fn unknown(name: String) -> Self {
+        Language::new(name, Rarity::Standard, None)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Every language in `languages` once, in the order first seen.
+pub fn unique(languages: impl IntoIterator<Item = Language>) -> Vec<Language> {
+    languages.into_iter().fold(vec![], |mut unique, language| {
+        if !unique.contains(&language) {
+            unique.push(language);
+        }
+
+        unique
+    })
+}
+
+/// The languages characters can pick from: the built-in ones plus any homebrew.
+pub type Catalogue = catalogue::Catalogue<Language>;
+
+impl Catalogue {
+    #[must_use]
+    pub fn get_by_rarity(&self, rarity: Rarity) -> Vec<&Language> {
+        self.get_entries()
+            .iter()
+            .filter(|language| language.rarity == rarity)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _languages_should_match_regardless_of_case() {
+        let elvish = Language::named("elvish");
+
+        assert_eq!(elvish.get_name(), "Elvish");
+        assert_eq!(elvish.get_script(), Some("Elvish"));
+        assert_eq!(
+            unique([Language::named("Elvish"), Language::common(), elvish]),
+            vec![Language::named("Elvish"), Language::common()]
+        );
+        assert_eq!(
+            Language::named("Thieves' Cant").get_rarity(),
+            Rarity::Secret
+        );
+    }
+}
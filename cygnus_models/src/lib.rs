@@ -1,18 +1,31 @@
 pub mod ability;
+pub mod armor_class;
+pub mod artificer;
 pub mod background;
+pub mod catalogue;
 pub mod character;
 pub mod characteristics;
+pub mod check;
 pub mod class;
+pub mod condition;
+pub mod container;
+pub mod currency;
 pub mod dice;
+pub mod effect;
 pub mod feat;
+pub mod feature;
 pub mod item;
+pub mod language;
 pub mod modifiers;
 pub mod personality;
 pub mod proficiencies;
 pub mod psionics;
 pub mod race;
+pub mod roll_log;
 pub mod senses;
 pub mod skills;
 pub mod slot;
+pub mod slot_layout;
 pub mod spell;
+pub mod tool;
 pub mod units;
@@ -27,3 +27,13 @@ pub enum Encumbrance {
     Encumbered,
     HeavilyEncumbered,
 }
+
+/// Which carrying rule slows a character down.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EncumbranceRule {
+    /// Speed drops to 5 feet over the carrying capacity, and to 0 over push, drag or lift.
+    Standard,
+    /// Speed drops by 10 feet when encumbered and by 20 when heavily encumbered.
+    #[default]
+    Variant,
+}
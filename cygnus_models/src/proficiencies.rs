@@ -1,10 +1,10 @@
-use crate::race::Language;
+use crate::{item::ArmorCategory, language::Language, modifiers::Proficiency, tool::Tool};
 
 #[derive(Clone, Debug, Default)]
 pub struct Proficiencies {
-    armor: Vec<String>,
+    armor: Vec<ArmorCategory>,
     weapons: Vec<String>,
-    tools: Vec<String>,
+    tools: Vec<(Tool, Proficiency)>,
     languages: Vec<Language>,
 }
 
@@ -13,18 +13,28 @@ impl Proficiencies {
         Default::default()
     }
 
-    pub fn add_armor_proficiency(&mut self, armor_class: impl Into<String>) -> &Self {
-        self.armor.push(armor_class.into());
+    pub fn add_armor_proficiency(&mut self, armor_category: ArmorCategory) -> &Self {
+        if !self.armor.contains(&armor_category) {
+            self.armor.push(armor_category);
+        }
 
         self
     }
 
-    pub fn get_armor_proficiencies(&self) -> &[String] {
+    pub fn get_armor_proficiencies(&self) -> &[ArmorCategory] {
         &self.armor
     }
 
+    pub fn is_proficient_with_armor(&self, armor_category: ArmorCategory) -> bool {
+        self.armor.contains(&armor_category)
+    }
+
     pub fn get_armor_proficiencies_string(&self) -> String {
-        self.armor.join(", ")
+        self.armor
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
     }
 
     pub fn add_weapon_proficiency(&mut self, weapon: impl Into<String>) -> &Self {
@@ -41,22 +51,43 @@ impl Proficiencies {
         &self.weapons
     }
 
-    pub fn add_tool_proficiency(&mut self, tool: impl Into<String>) -> &Self {
-        self.tools.push(tool.into());
+    /// Keeps the better of the new and any existing proficiency with `tool`.
+    pub fn add_tool_proficiency(&mut self, tool: Tool, proficiency: Proficiency) -> &Self {
+        match self.tools.iter_mut().find(|(known, _)| *known == tool) {
+            Some((_, existing)) => {
+                if proficiency as usize > *existing as usize {
+                    *existing = proficiency;
+                }
+            }
+            None => self.tools.push((tool, proficiency)),
+        }
 
         self
     }
 
     pub fn get_tool_proficiencies_string(&self) -> String {
-        self.tools.join(", ")
+        self.tools
+            .iter()
+            .map(|(tool, _)| tool.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
     }
 
-    pub fn get_tool_proficiencies(&self) -> &[String] {
+    pub fn get_tool_proficiencies(&self) -> &[(Tool, Proficiency)] {
         &self.tools
     }
 
+    pub fn get_tool_proficiency(&self, tool: &Tool) -> Option<Proficiency> {
+        self.tools
+            .iter()
+            .find(|(known, _)| known == tool)
+            .map(|(_, proficiency)| *proficiency)
+    }
+
     pub fn add_language(&mut self, language: Language) -> &Self {
-        self.languages.push(language);
+        if !self.languages.contains(&language) {
+            self.languages.push(language);
+        }
 
         self
     }
@@ -64,4 +95,29 @@ impl Proficiencies {
     pub fn get_languages(&self) -> &[Language] {
         &self.languages
     }
+
+    /// Adds every proficiency from `other`, skipping ones already held.
+    pub fn extend(&mut self, other: &Proficiencies) {
+        for armor_category in &other.armor {
+            self.add_armor_proficiency(*armor_category);
+        }
+
+        for weapon in &other.weapons {
+            if !self
+                .weapons
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(weapon))
+            {
+                self.add_weapon_proficiency(weapon.clone());
+            }
+        }
+
+        for (tool, proficiency) in &other.tools {
+            self.add_tool_proficiency(tool.clone(), *proficiency);
+        }
+
+        for language in &other.languages {
+            self.add_language(language.clone());
+        }
+    }
 }
@@ -2,16 +2,71 @@
 
 use std::{error::Error, fmt};
 
-use self::discipline::{Act, Discipline};
+use self::discipline::{Act, Discipline, ResolvedAct};
 
-struct Mystic {
+/// Psi points by mystic level, from 1st to 20th.
+const PSI_POINTS: [usize; 20] = [
+    4, 6, 14, 17, 27, 32, 38, 44, 57, 64, 64, 64, 64, 64, 64, 64, 64, 71, 71, 71,
+];
+
+/// The most psi points a single act can take, by mystic level.
+const PSI_LIMITS: [usize; 20] = [2, 2, 3, 3, 5, 5, 6, 6, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7];
+
+fn get_by_level(table: &[usize; 20], level: usize) -> usize {
+    level
+        .checked_sub(1)
+        .map_or(0, |index| table[index.min(table.len() - 1)])
+}
+
+/// The psionic class resource: psi points, talents, disciplines and psychic focus.
+#[derive(Clone, Debug)]
+pub struct Mystic {
+    level: usize,
     psi_points: usize,
-    psi_point_max: usize,
     talents: Vec<Talent>,
     disciplines: Vec<Discipline>,
     focus: Option<Discipline>,
 }
 
+impl Mystic {
+    /// A mystic of `level` with every psi point available.
+    #[must_use]
+    pub fn new(level: usize) -> Self {
+        Mystic {
+            level,
+            psi_points: get_by_level(&PSI_POINTS, level),
+            talents: vec![],
+            disciplines: vec![],
+            focus: None,
+        }
+    }
+
+    #[must_use]
+    pub fn get_level(&self) -> usize {
+        self.level
+    }
+
+    /// Psi points above the new maximum are lost.
+    pub fn set_level(&mut self, level: usize) {
+        self.level = level;
+        self.psi_points = self.psi_points.min(self.get_psi_point_max());
+    }
+
+    #[must_use]
+    pub fn add_talent(mut self, talent: Talent) -> Self {
+        self.talents.push(talent);
+
+        self
+    }
+
+    #[must_use]
+    pub fn add_discipline(mut self, discipline: Discipline) -> Self {
+        self.disciplines.push(discipline);
+
+        self
+    }
+}
+
 impl Psionics for Mystic {
     fn get_talents(&self) -> &[Talent] {
         &self.talents
@@ -38,11 +93,15 @@ impl Psionics for Mystic {
     }
 
     fn get_psi_point_max(&self) -> usize {
-        self.psi_point_max
+        get_by_level(&PSI_POINTS, self.level)
+    }
+
+    fn get_psi_limit(&self) -> usize {
+        get_by_level(&PSI_LIMITS, self.level)
     }
 }
 
-trait Psionics {
+pub trait Psionics {
     fn get_talents(&self) -> &[Talent];
 
     fn get_disciplines(&self) -> &[Discipline];
@@ -53,6 +112,9 @@ trait Psionics {
 
     fn get_psi_point_max(&self) -> usize;
 
+    /// The most psi points a single act can take.
+    fn get_psi_limit(&self) -> usize;
+
     fn reset_psi_points(&mut self) {
         *self.get_psi_points_mut() = self.get_psi_point_max();
     }
@@ -69,26 +131,104 @@ trait Psionics {
         self.get_disciplines().iter().any(|d| d.has_act(a))
     }
 
-    fn perform_act(&mut self, a: &Act, charge_mod: usize) -> Result<(), ActError> {
+    fn get_discipline(&self, name: &str) -> Option<&Discipline> {
+        self.get_disciplines()
+            .iter()
+            .find(|d| d.get_name().eq_ignore_ascii_case(name))
+    }
+
+    fn get_act(&self, name: &str) -> Option<&Act> {
+        self.get_disciplines()
+            .iter()
+            .flat_map(Discipline::get_acts)
+            .find(|a| a.get_name().eq_ignore_ascii_case(name))
+    }
+
+    /// What performing the act with `charge_mod` extra psi points would cost, without
+    /// spending anything.
+    ///
+    /// # Errors
+    ///
+    /// - `ActNotPracticed`: no known discipline has the act
+    /// - `Overcharged`: the charged cost is past the most the act can take
+    ///
+    fn preview_act(&self, a: &Act, charge_mod: usize) -> Result<ActPreview, ActError> {
         if !self.has_act(a) {
             return Err(ActError::ActNotPracticed);
         }
 
-        let psi_points_mut = self.get_psi_points_mut();
+        let total_cost = a
+            .get_cost()
+            .start()
+            .checked_add(charge_mod)
+            .filter(|total_cost| a.get_cost().contains(total_cost))
+            .ok_or(ActError::Overcharged)?;
+
+        Ok(ActPreview {
+            total_cost,
+            remaining: self.get_psi_points().checked_sub(total_cost),
+            psi_limit: self.get_psi_limit(),
+        })
+    }
 
-        let act_cost = a.get_cost();
+    /// Spends the act's cost plus `charge_mod` extra psi points, returning what the act comes
+    /// to. Nothing is spent unless every check passes.
+    ///
+    /// # Errors
+    ///
+    /// - `ActNotPracticed`: no known discipline has the act
+    /// - `Overcharged`: the charged cost is past the most the act can take
+    /// - `PsiLimitExceeded`: the charged cost is past the psi limit
+    /// - `NotEnoughPoints`: too few psi points are left
+    ///
+    fn perform_act(&mut self, a: &Act, charge_mod: usize) -> Result<ResolvedAct, ActError> {
+        let remaining = self.preview_act(a, charge_mod)?.validate()?;
+
+        *self.get_psi_points_mut() = remaining;
+
+        Ok(a.resolve(charge_mod))
+    }
+}
 
-        if *psi_points_mut < act_cost.start {
-            return Err(ActError::NotEnoughPoints);
-        }
+/// The outcome of performing an act, worked out before any psi points are spent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ActPreview {
+    total_cost: usize,
+    remaining: Option<usize>,
+    psi_limit: usize,
+}
 
-        if act_cost.start + charge_mod >= act_cost.end {
-            return Err(ActError::Overcharged);
-        }
+impl ActPreview {
+    /// The act's lowest cost plus the charge.
+    #[must_use]
+    pub fn get_total_cost(&self) -> usize {
+        self.total_cost
+    }
 
-        *psi_points_mut -= act_cost.start + charge_mod;
+    /// The psi points left afterwards, or `None` if there aren't enough.
+    #[must_use]
+    pub fn get_remaining(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    #[must_use]
+    pub fn exceeds_psi_limit(&self) -> bool {
+        self.total_cost > self.psi_limit
+    }
+
+    /// Returns the psi points left if the act can be performed.
+    ///
+    /// # Errors
+    ///
+    /// - `PsiLimitExceeded`: the total cost is past the psi limit
+    /// - `NotEnoughPoints`: too few psi points are left
+    ///
+    pub fn validate(&self) -> Result<usize, ActError> {
+        if self.exceeds_psi_limit() {
+            return Err(ActError::PsiLimitExceeded);
+        }
 
-        Ok(())
+        self.remaining.ok_or(ActError::NotEnoughPoints)
     }
 }
 
@@ -97,6 +237,7 @@ pub enum ActError {
     NotEnoughPoints,
     ActNotPracticed,
     Overcharged,
+    PsiLimitExceeded,
 }
 
 impl fmt::Display for ActError {
@@ -108,6 +249,7 @@ impl fmt::Display for ActError {
                 Self::NotEnoughPoints => "not enough psi points",
                 Self::ActNotPracticed => "user doesn't know act",
                 Self::Overcharged => "act unable to handle psi points",
+                Self::PsiLimitExceeded => "more psi points than the psi limit",
             }
         )
     }
@@ -144,9 +286,9 @@ impl Talent {
 pub mod discipline {
     use super::{fmt, Error};
 
-    use std::ops::Range;
+    use std::ops::RangeInclusive;
 
-    use crate::units::Duration;
+    use crate::{ability, dice::Roll, race::DamageType, units::Duration};
 
     #[derive(Clone, Debug, PartialEq)]
     pub struct Discipline {
@@ -261,6 +403,9 @@ pub mod discipline {
             if let Some(missing_fields) = missing_fields {
                 return Err(BuildError::new_missing_fields_error(&missing_fields));
             }
+            if let Some(act) = value.acts.iter().find(|act| act.cost.is_empty()) {
+                return Err(BuildError::EmptyActCost(act.name.clone()));
+            }
 
             Ok(Discipline {
                 name: value.name.unwrap(),
@@ -275,6 +420,8 @@ pub mod discipline {
     #[derive(Debug, PartialEq)]
     pub enum BuildError {
         MissingField(Vec<String>),
+        /// An act whose lowest cost is above its highest, e.g. `5..=3`.
+        EmptyActCost(String),
     }
 
     impl BuildError {
@@ -297,6 +444,9 @@ pub mod discipline {
                             .collect::<Vec<_>>()
                             .join(", ")
                     ),
+                    Self::EmptyActCost(act) => {
+                        format!("\tact `{act}` costs more at its lowest than at its highest")
+                    }
                 }
             )
         }
@@ -304,20 +454,50 @@ pub mod discipline {
 
     impl Error for BuildError {}
 
+    /// Damage dice an act gains for each psi point spent beyond its cost, e.g. "+1d8 per
+    /// additional psi point".
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Scaling {
+        count: usize,
+        sides: usize,
+    }
+
+    impl Scaling {
+        #[must_use]
+        pub fn dice(count: usize, sides: usize) -> Self {
+            Scaling { count, sides }
+        }
+
+        #[must_use]
+        pub fn get_dice(&self) -> (usize, usize) {
+            (self.count, self.sides)
+        }
+    }
+
+    impl fmt::Display for Scaling {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "+{}d{} per additional psi point", self.count, self.sides)
+        }
+    }
+
     #[derive(Clone, Debug, PartialEq)]
     pub struct Act {
         name: String,
         description: String,
-        cost: Range<usize>,
+        cost: RangeInclusive<usize>,
         duration: Option<Duration>,
+        damage: Option<(Roll, DamageType)>,
+        save: Option<ability::Identifier>,
+        scaling: Option<Scaling>,
     }
 
     impl Act {
+        /// A discipline won't be built with an act whose `cost` is empty, e.g. `5..=3`.
         #[must_use]
         pub fn new(
             name: impl Into<String>,
             description: impl Into<String>,
-            cost: Range<usize>,
+            cost: RangeInclusive<usize>,
             duration: Option<Duration>,
         ) -> Self {
             Act {
@@ -325,9 +505,35 @@ pub mod discipline {
                 description: description.into(),
                 cost,
                 duration,
+                damage: None,
+                save: None,
+                scaling: None,
             }
         }
 
+        /// The damage dealt at the act's lowest cost.
+        #[must_use]
+        pub fn damage(mut self, roll: Roll, damage_type: DamageType) -> Self {
+            self.damage = Some((roll, damage_type));
+
+            self
+        }
+
+        /// The saving throw the target makes against the act.
+        #[must_use]
+        pub fn save(mut self, ability: ability::Identifier) -> Self {
+            self.save = Some(ability);
+
+            self
+        }
+
+        #[must_use]
+        pub fn scaling(mut self, scaling: Scaling) -> Self {
+            self.scaling = Some(scaling);
+
+            self
+        }
+
         #[must_use]
         pub fn get_name(&self) -> &str {
             &self.name
@@ -338,8 +544,9 @@ pub mod discipline {
             &self.description
         }
 
+        /// The fewest and most psi points the act can take, both included.
         #[must_use]
-        pub fn get_cost(&self) -> &Range<usize> {
+        pub fn get_cost(&self) -> &RangeInclusive<usize> {
             &self.cost
         }
 
@@ -347,6 +554,108 @@ pub mod discipline {
         pub fn get_duration(&self) -> &Option<Duration> {
             &self.duration
         }
+
+        #[must_use]
+        pub fn get_damage(&self) -> Option<&(Roll, DamageType)> {
+            self.damage.as_ref()
+        }
+
+        #[must_use]
+        pub fn get_save(&self) -> Option<ability::Identifier> {
+            self.save
+        }
+
+        #[must_use]
+        pub fn get_scaling(&self) -> Option<Scaling> {
+            self.scaling
+        }
+
+        /// The act performed with `charge_mod` psi points beyond its lowest cost, with the
+        /// scaling added to its damage. Only called from `Psionics::perform_act`, once the
+        /// preview has kept the charged cost within the act's range and the psi limit.
+        #[must_use]
+        pub(crate) fn resolve(&self, charge_mod: usize) -> ResolvedAct {
+            let damage = self.damage.clone().map(|(mut roll, damage_type)| {
+                if let Some(Scaling { count, sides }) = self.scaling.filter(|_| charge_mod > 0) {
+                    roll.add_die(count * charge_mod, sides);
+                }
+
+                (roll, damage_type)
+            });
+
+            ResolvedAct {
+                name: self.name.clone(),
+                psi_points: self.cost.start() + charge_mod,
+                damage,
+                save: self.save,
+                duration: self.duration,
+            }
+        }
+    }
+
+    /// What performing an act with a given charge comes down to.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ResolvedAct {
+        name: String,
+        psi_points: usize,
+        damage: Option<(Roll, DamageType)>,
+        save: Option<ability::Identifier>,
+        duration: Option<Duration>,
+    }
+
+    impl ResolvedAct {
+        #[must_use]
+        pub fn get_name(&self) -> &str {
+            &self.name
+        }
+
+        /// The psi points spent.
+        #[must_use]
+        pub fn get_psi_points(&self) -> usize {
+            self.psi_points
+        }
+
+        #[must_use]
+        pub fn get_damage(&self) -> Option<&(Roll, DamageType)> {
+            self.damage.as_ref()
+        }
+
+        #[must_use]
+        pub fn get_save(&self) -> Option<ability::Identifier> {
+            self.save
+        }
+
+        #[must_use]
+        pub fn get_duration(&self) -> Option<Duration> {
+            self.duration
+        }
+
+        /// Whether the act lasts beyond the moment it is performed, needing concentration.
+        #[must_use]
+        pub fn needs_concentration(&self) -> bool {
+            self.duration
+                .is_some_and(|duration| duration != Duration::Instantaneous)
+        }
+    }
+
+    impl fmt::Display for ResolvedAct {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut parts = vec![format!("{} ({} psi)", self.name, self.psi_points)];
+
+            if let Some((roll, damage_type)) = &self.damage {
+                parts.push(format!("{roll} {damage_type}"));
+            }
+
+            if let Some(save) = self.save {
+                parts.push(format!("{save} save"));
+            }
+
+            if let Some(duration) = self.duration.filter(|_| self.needs_concentration()) {
+                parts.push(format!("concentration, {duration}"));
+            }
+
+            write!(f, "{}", parts.join(", "))
+        }
     }
 
     #[cfg(test)]
@@ -366,8 +675,11 @@ pub mod discipline {
                     .add_act(&Act {
                         name: String::from("Distracting Figment"),
                         description: String::from("As an action, choose one creature you can see within 60 feet of you. The target must make an Intelligence saving throw. On a failed save, it takes 1d10 psychic damage per psi point spent and thinks it perceives a threatening creature just out of its sight; until the end of your next turn, it can’t use reactions, and melee attack rolls against it have advantage. On a successful save, it takes half as much damage."),
-                        cost: 1..8,
-                        duration: None }
+                        cost: 1..=7,
+                        duration: None,
+                        damage: None,
+                        save: None,
+                        scaling: None }
                     );
 
                 let built_discipline: Discipline = builder.try_into()?;
@@ -382,8 +694,11 @@ pub mod discipline {
                             Act {
                                 name: String::from("Distracting Figment"),
                                 description: String::from("As an action, choose one creature you can see within 60 feet of you. The target must make an Intelligence saving throw. On a failed save, it takes 1d10 psychic damage per psi point spent and thinks it perceives a threatening creature just out of its sight; until the end of your next turn, it can’t use reactions, and melee attack rolls against it have advantage. On a successful save, it takes half as much damage."),
-                                cost: 1..8,
-                                duration: None
+                                cost: 1..=7,
+                                duration: None,
+                                damage: None,
+                                save: None,
+                                scaling: None
                             }
                         ]
                     }
@@ -425,6 +740,21 @@ pub mod discipline {
                 );
             }
 
+            #[test]
+            fn _should_return_error_when_an_act_cost_is_reversed() {
+                let builder = Builder::new()
+                    .name("Test")
+                    .order("Test")
+                    .description("Test")
+                    .focus("Test")
+                    .add_act(&Act::new("Backwards", "", RangeInclusive::new(5, 3), None));
+
+                assert_eq!(
+                    Discipline::try_from(builder),
+                    Err(BuildError::EmptyActCost(String::from("Backwards")))
+                );
+            }
+
             mod build_error {
                 use super::*;
 
@@ -439,3 +769,141 @@ pub mod discipline {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    use discipline::Builder;
+
+    use crate::{dice::Roll, race::DamageType};
+
+    fn psychic_phantoms(act: &Act) -> Discipline {
+        Builder::new()
+            .name("Psychic Phantoms")
+            .order("Awakened")
+            .description("False perceptions.")
+            .focus("Advantage on Charisma (Deception) checks.")
+            .add_act(act)
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn _mystic_should_spend_psi_points_within_the_psi_limit() {
+        let phantom_foe = Act::new("Phantom Foe", "1d8 psychic damage.", 3..=7, None);
+        let mut mystic = Mystic::new(3).add_discipline(psychic_phantoms(&phantom_foe));
+
+        assert_eq!(mystic.get_psi_point_max(), 14);
+        assert_eq!(mystic.get_psi_limit(), 3);
+        assert_eq!(
+            mystic.perform_act(&phantom_foe, 1),
+            Err(ActError::PsiLimitExceeded)
+        );
+
+        mystic.perform_act(&phantom_foe, 0).unwrap();
+        assert_eq!(mystic.get_psi_points(), 11);
+
+        mystic.set_level(9);
+        mystic.perform_act(&phantom_foe, 4).unwrap();
+        assert_eq!(mystic.get_psi_points(), 4);
+        assert_eq!(
+            mystic.perform_act(&phantom_foe, 4),
+            Err(ActError::NotEnoughPoints)
+        );
+        assert_eq!(mystic.get_psi_points(), 4);
+        assert_eq!(
+            mystic.perform_act(&phantom_foe, 5),
+            Err(ActError::Overcharged)
+        );
+        assert_eq!(
+            mystic.perform_act(&phantom_foe, usize::MAX),
+            Err(ActError::Overcharged)
+        );
+
+        let preview = mystic.preview_act(&phantom_foe, 2).unwrap();
+        assert_eq!(preview.get_total_cost(), 5);
+        assert_eq!(preview.get_remaining(), None);
+        assert_eq!(
+            mystic.perform_act(&phantom_foe, 2),
+            Err(ActError::NotEnoughPoints)
+        );
+        assert_eq!(mystic.get_psi_points(), 4);
+
+        mystic.reset_psi_points();
+        assert_eq!(mystic.get_psi_points(), 57);
+
+        let discipline = mystic.get_discipline("psychic phantoms").unwrap().clone();
+        mystic.focus_on(&discipline);
+        assert_eq!(mystic.get_focus(), Some(&discipline));
+        assert_eq!(Mystic::new(0).get_psi_point_max(), 0);
+    }
+
+    #[test]
+    fn _uncharged_acts_should_keep_their_damage_dice() {
+        let act = Act::new("Phantom Foe", "1d8 psychic damage.", 3..=7, None)
+            .damage(Roll::new(1, 8, 0), DamageType::Psychic)
+            .scaling(discipline::Scaling::dice(1, 6));
+        let damage = |charge_mod| act.resolve(charge_mod).get_damage().cloned();
+
+        assert_eq!(damage(0), Some((Roll::new(1, 8, 0), DamageType::Psychic)));
+        assert_eq!(
+            damage(2).map(|(roll, _)| roll.to_string()),
+            Some(String::from("2d6 + 1d8"))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn _perform_act_should_spend_exactly_the_previewed_cost(
+            lowest in 0usize..10,
+            extra in 0usize..10,
+            charge_mod in 0usize..20,
+            level in 0usize..=20,
+            psi_points in 0usize..80,
+        ) {
+            let act = Act::new("Test", "", lowest..=lowest + extra, None);
+            let mut mystic = Mystic::new(level).add_discipline(psychic_phantoms(&act));
+            mystic.psi_points = psi_points;
+
+            let preview = mystic.preview_act(&act, charge_mod);
+
+            match mystic.perform_act(&act, charge_mod) {
+                Ok(resolved) => {
+                    let total_cost = lowest + charge_mod;
+
+                    prop_assert_eq!(resolved.get_psi_points(), total_cost);
+                    prop_assert!(total_cost <= lowest + extra);
+                    prop_assert!(total_cost <= mystic.get_psi_limit());
+                    prop_assert_eq!(mystic.get_psi_points(), psi_points - total_cost);
+                    prop_assert_eq!(preview?.get_remaining(), Some(mystic.get_psi_points()));
+                }
+                Err(error) => {
+                    prop_assert_eq!(mystic.get_psi_points(), psi_points);
+                    prop_assert_eq!(preview.and_then(|preview| preview.validate()), Err(error));
+                }
+            }
+        }
+
+        #[test]
+        fn _preview_should_flag_costs_past_the_level_psi_limit(
+            level in 1usize..=20,
+            lowest in 0usize..=2,
+        ) {
+            let act = Act::new("Test", "", lowest..=10, None);
+            let mystic = Mystic::new(level).add_discipline(psychic_phantoms(&act));
+            let limit = PSI_LIMITS[level - 1];
+
+            let at_limit = mystic.preview_act(&act, limit - lowest)?;
+            let past_limit = mystic.preview_act(&act, limit + 1 - lowest)?;
+
+            prop_assert_eq!(at_limit.get_total_cost(), limit);
+            prop_assert!(!at_limit.exceeds_psi_limit());
+            prop_assert_eq!(at_limit.validate(), Ok(mystic.get_psi_point_max() - limit));
+            prop_assert!(past_limit.exceeds_psi_limit());
+            prop_assert_eq!(past_limit.validate(), Err(ActError::PsiLimitExceeded));
+        }
+    }
+}
@@ -1,9 +1,15 @@
-use std::{collections::HashMap, error, fmt};
+use std::{
+    collections::{BTreeMap, HashMap},
+    error, fmt,
+};
 
 use crate::{
     ability::{self, Abilities},
-    feat::Feat,
-    modifiers::Resistance,
+    effect::{Effect, Modifier, Target},
+    feat::{Feat, FeatError},
+    language::Language,
+    modifiers::{Proficiency, Resistance},
+    skills,
 };
 
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
@@ -27,10 +33,41 @@ pub enum CreatureType {
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum DamageType {
+    Acid,
+    Bludgeoning,
+    Cold,
+    Fire,
+    Force,
+    Lightning,
     Necrotic,
-    Radiant,
+    Piercing,
     Poison,
-    Force,
+    Psychic,
+    Radiant,
+    Slashing,
+    Thunder,
+}
+
+impl fmt::Display for DamageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            DamageType::Acid => "Acid",
+            DamageType::Bludgeoning => "Bludgeoning",
+            DamageType::Cold => "Cold",
+            DamageType::Fire => "Fire",
+            DamageType::Force => "Force",
+            DamageType::Lightning => "Lightning",
+            DamageType::Necrotic => "Necrotic",
+            DamageType::Piercing => "Piercing",
+            DamageType::Poison => "Poison",
+            DamageType::Psychic => "Psychic",
+            DamageType::Radiant => "Radiant",
+            DamageType::Slashing => "Slashing",
+            DamageType::Thunder => "Thunder",
+        };
+
+        write!(f, "{result}")
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -51,6 +88,21 @@ pub enum Size {
     Gargantuan,
 }
 
+impl Size {
+    /// Scales carrying capacity and push, drag or lift: doubled for each size above Medium
+    /// and halved for Tiny.
+    #[must_use]
+    pub fn get_carrying_capacity_multiplier(&self) -> f64 {
+        match self {
+            Size::Tiny => 0.5,
+            Size::Small | Size::Medium => 1.0,
+            Size::Large => 2.0,
+            Size::Huge => 4.0,
+            Size::Gargantuan => 8.0,
+        }
+    }
+}
+
 impl fmt::Display for Size {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let result = match self {
@@ -66,28 +118,178 @@ impl fmt::Display for Size {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum Language {
-    Common,
-    Undercommon,
+/// Something a race lets the player pick instead of fixing it when the race is authored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Choice {
+    /// +`amount` to each of `count` different abilities, none of them in `except`, like a
+    /// Half-Elf's two +1s.
+    AbilityScores {
+        count: usize,
+        amount: usize,
+        except: Vec<ability::Identifier>,
+    },
+    /// Proficiency in one skill from the list, or in any skill if the list is empty.
+    Skill(Vec<skills::Identifier>),
+    /// One cantrip from the named spell list.
+    Cantrip(String),
+    /// One extra language.
+    Language,
+}
+
+impl fmt::Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Choice::AbilityScores { count, amount, .. } => {
+                format!("+{amount} to {count} abilities")
+            }
+            Choice::Skill(skills) if skills.is_empty() => String::from("One skill"),
+            Choice::Skill(skills) => format!(
+                "One skill from {}",
+                skills
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Choice::Cantrip(spell_list) => format!("One {spell_list} cantrip"),
+            Choice::Language => String::from("One language"),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// What the player picked for a racial [`Choice`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Selection {
+    AbilityScores(Vec<ability::Identifier>),
+    Skill(skills::Identifier),
+    Cantrip(String),
+    Language(Language),
+}
+
+/// The player's racial selections, kept on the character so they can be changed later.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Selections {
+    /// Keyed by the index of the choice in [`Race::get_choices`].
+    selections: BTreeMap<usize, Selection>,
+    /// Fixed ability bonuses moved to another ability, as `(from, to)`.
+    reassignments: Vec<(ability::Identifier, ability::Identifier)>,
+}
+
+impl Selections {
+    #[must_use]
+    pub fn get_selection(&self, choice: usize) -> Option<&Selection> {
+        self.selections.get(&choice)
+    }
+
+    pub fn get_selections(&self) -> impl Iterator<Item = &Selection> {
+        self.selections.values()
+    }
+
+    #[must_use]
+    pub fn get_reassignments(&self) -> &[(ability::Identifier, ability::Identifier)] {
+        &self.reassignments
+    }
+
+    pub fn clear(&mut self) {
+        self.selections.clear();
+        self.reassignments.clear();
+    }
+
+    /// Records `selection`, replacing whatever was picked for that choice before.
+    ///
+    /// # Errors
+    ///
+    /// - see [`Race::validate_selection`]
+    ///
+    pub fn select(
+        &mut self,
+        race: &Race,
+        index: usize,
+        selection: Selection,
+    ) -> Result<(), ChoiceError> {
+        race.validate_selection(index, &selection)?;
+
+        self.selections.insert(index, selection);
+
+        Ok(())
+    }
+
+    /// Moves the race's fixed bonus from one ability to another, replacing any earlier move of
+    /// the same bonus.
+    ///
+    /// # Errors
+    ///
+    /// - see [`Race::validate_reassignments`]
+    ///
+    pub fn reassign(
+        &mut self,
+        race: &Race,
+        from: ability::Identifier,
+        to: ability::Identifier,
+    ) -> Result<(), ChoiceError> {
+        let mut reassignments: Vec<_> = self
+            .reassignments
+            .iter()
+            .copied()
+            .filter(|(moved, _)| *moved != from)
+            .collect();
+        if from != to {
+            reassignments.push((from, to));
+        }
+
+        race.validate_reassignments(&reassignments)?;
+
+        self.reassignments = reassignments;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChoiceError {
+    NoSuchChoice(usize),
+    WrongSelection(usize),
+    AbilityCount { expected: usize, found: usize },
+    DuplicateAbility(ability::Identifier),
+    AbilityNotAllowed(ability::Identifier),
+    SkillNotOffered(skills::Identifier),
+    NoBonusToMove(ability::Identifier),
+    BonusesStack(ability::Identifier),
 }
 
-impl fmt::Display for Language {
+impl fmt::Display for ChoiceError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Language::Common => "Common",
-                Language::Undercommon => "Undercommon",
+        let result = match self {
+            ChoiceError::NoSuchChoice(index) => format!("Race has no choice #{index}."),
+            ChoiceError::WrongSelection(index) => {
+                format!("Selection doesn't fit race choice #{index}.")
+            }
+            ChoiceError::AbilityCount { expected, found } => {
+                format!("Expected {expected} abilities, found {found}.")
             }
-        )
+            ChoiceError::DuplicateAbility(ability) => format!("{ability} was picked twice."),
+            ChoiceError::AbilityNotAllowed(ability) => format!("{ability} cannot be picked."),
+            ChoiceError::SkillNotOffered(skill) => format!("{skill} is not offered."),
+            ChoiceError::NoBonusToMove(ability) => {
+                format!("Race has no {ability} bonus to move.")
+            }
+            ChoiceError::BonusesStack(ability) => {
+                format!("{ability} would get more than one racial bonus.")
+            }
+        };
+
+        write!(f, "{result}")
     }
 }
 
+impl error::Error for ChoiceError {}
+
 #[derive(Debug, Default)]
 pub struct Builder {
     name: Option<String>,
+    parent: Option<String>,
     creature_type: Option<CreatureType>,
     size: Option<Size>,
     walking_speed: Option<usize>,
@@ -96,6 +298,7 @@ pub struct Builder {
     condition_resistance: HashMap<Condition, Resistance>,
     languages: Vec<Language>,
     feats: Vec<Feat>,
+    choices: Vec<Choice>,
 }
 
 impl Builder {
@@ -103,6 +306,24 @@ impl Builder {
         Builder::default()
     }
 
+    /// Starts a subrace with everything `parent` has; the subrace's own traits are added on
+    /// top, and a size or speed set on the builder replaces the parent's.
+    pub fn subrace_of(parent: &Race) -> Self {
+        Builder {
+            name: None,
+            parent: Some(parent.name.clone()),
+            creature_type: Some(parent.creature_type),
+            size: Some(parent.size),
+            walking_speed: Some(parent.walking_speed),
+            abilities: parent.abilities.clone(),
+            damage_resistances: parent.damage_resistances.clone(),
+            condition_resistance: parent.condition_resistances.clone(),
+            languages: parent.languages.clone(),
+            feats: parent.feats.clone(),
+            choices: parent.choices.clone(),
+        }
+    }
+
     pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
         self.name = Some(name.into());
 
@@ -173,6 +394,12 @@ impl Builder {
         self
     }
 
+    pub fn add_choice(&mut self, choice: Choice) -> &mut Self {
+        self.choices.push(choice);
+
+        self
+    }
+
     pub fn build(&self) -> Result<Race, RaceConstructionError> {
         let name = self
             .name
@@ -186,9 +413,11 @@ impl Builder {
         let condition_resistances = self.condition_resistance.clone();
         let languages = self.languages.clone();
         let feats = self.feats.clone();
+        let choices = self.choices.clone();
 
         Ok(Race {
             name,
+            parent: self.parent.clone(),
             creature_type,
             size,
             walking_speed,
@@ -197,6 +426,7 @@ impl Builder {
             condition_resistances,
             languages,
             feats,
+            choices,
         })
     }
 }
@@ -232,6 +462,7 @@ pub struct Template {
 #[derive(Clone, Debug)]
 pub struct Race {
     name: String,
+    parent: Option<String>,
     creature_type: CreatureType,
     size: Size,
     walking_speed: usize,
@@ -240,6 +471,7 @@ pub struct Race {
     condition_resistances: HashMap<Condition, Resistance>,
     languages: Vec<Language>,
     feats: Vec<Feat>,
+    choices: Vec<Choice>,
 }
 
 impl Race {
@@ -248,6 +480,12 @@ impl Race {
         &self.name
     }
 
+    /// The race a subrace was built from.
+    #[must_use]
+    pub fn get_parent_name(&self) -> Option<&str> {
+        self.parent.as_deref()
+    }
+
     #[must_use]
     pub fn get_creature_type(&self) -> &CreatureType {
         &self.creature_type
@@ -292,8 +530,173 @@ impl Race {
         self.feats.iter().collect()
     }
 
-    pub fn add_feat(&mut self, feat: Feat) {
+    /// Doesn't know the character, so prerequisites are left to [`Character::add_race_feat`].
+    ///
+    /// # Errors
+    ///
+    /// - `AbilityNotChosen`: a half-feat's ability hasn't been picked
+    ///
+    /// [`Character::add_race_feat`]: crate::character::Character::add_race_feat
+    pub fn add_feat(&mut self, feat: Feat) -> Result<(), FeatError> {
+        feat.validate()?;
+
         self.feats.push(feat);
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get_choices(&self) -> &[Choice] {
+        &self.choices
+    }
+
+    /// # Errors
+    ///
+    /// - `NoSuchChoice`: the race has no choice at `index`
+    /// - `WrongSelection`: the selection is for a different kind of choice
+    /// - `AbilityCount`, `DuplicateAbility`, `AbilityNotAllowed`: the abilities don't fit
+    /// - `SkillNotOffered`: the skill isn't one of the choice's skills
+    ///
+    pub fn validate_selection(
+        &self,
+        index: usize,
+        selection: &Selection,
+    ) -> Result<(), ChoiceError> {
+        let choice = self
+            .choices
+            .get(index)
+            .ok_or(ChoiceError::NoSuchChoice(index))?;
+
+        match (choice, selection) {
+            (Choice::AbilityScores { count, except, .. }, Selection::AbilityScores(abilities)) => {
+                if abilities.len() != *count {
+                    return Err(ChoiceError::AbilityCount {
+                        expected: *count,
+                        found: abilities.len(),
+                    });
+                }
+
+                for (position, ability) in abilities.iter().enumerate() {
+                    if except.contains(ability) {
+                        return Err(ChoiceError::AbilityNotAllowed(*ability));
+                    }
+
+                    if abilities[..position].contains(ability) {
+                        return Err(ChoiceError::DuplicateAbility(*ability));
+                    }
+                }
+
+                Ok(())
+            }
+            (Choice::Skill(skills), Selection::Skill(skill)) => {
+                if skills.is_empty() || skills.contains(skill) {
+                    Ok(())
+                } else {
+                    Err(ChoiceError::SkillNotOffered(*skill))
+                }
+            }
+            (Choice::Cantrip(_), Selection::Cantrip(_))
+            | (Choice::Language, Selection::Language(_)) => Ok(()),
+            _ => Err(ChoiceError::WrongSelection(index)),
+        }
+    }
+
+    /// Checks moving fixed ability bonuses to other abilities, as with Tasha's "customize your
+    /// origin".
+    ///
+    /// # Errors
+    ///
+    /// - `NoBonusToMove`: the race has no fixed bonus to the `from` ability
+    /// - `BonusesStack`: an ability would end up with two racial bonuses
+    ///
+    pub fn validate_reassignments(
+        &self,
+        reassignments: &[(ability::Identifier, ability::Identifier)],
+    ) -> Result<(), ChoiceError> {
+        if let Some((from, _)) = reassignments
+            .iter()
+            .find(|(from, _)| self.abilities.get_score(*from).unwrap_or(0) == 0)
+        {
+            return Err(ChoiceError::NoBonusToMove(*from));
+        }
+
+        let fixed = self.get_fixed_ability_bonuses(reassignments);
+
+        for (position, (ability, _)) in fixed.iter().enumerate() {
+            if fixed[..position].iter().any(|(other, _)| other == ability) {
+                return Err(ChoiceError::BonusesStack(*ability));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_fixed_ability_bonuses(
+        &self,
+        reassignments: &[(ability::Identifier, ability::Identifier)],
+    ) -> Vec<(ability::Identifier, usize)> {
+        ability::Identifier::all()
+            .into_iter()
+            .filter_map(|ability| {
+                let bonus = self
+                    .abilities
+                    .get_score(ability)
+                    .filter(|&bonus| bonus > 0)?;
+                let moved_to = reassignments
+                    .iter()
+                    .find(|(from, _)| *from == ability)
+                    .map_or(ability, |(_, to)| *to);
+
+                Some((moved_to, bonus))
+            })
+            .collect()
+    }
+
+    /// The racial bonus to `ability` once reassignments and chosen bonuses are applied.
+    #[must_use]
+    pub fn get_ability_bonus(
+        &self,
+        ability: ability::Identifier,
+        selections: &Selections,
+    ) -> usize {
+        let fixed: usize = self
+            .get_fixed_ability_bonuses(&selections.reassignments)
+            .into_iter()
+            .filter(|(bonus_ability, _)| *bonus_ability == ability)
+            .map(|(_, bonus)| bonus)
+            .sum();
+
+        let chosen: usize = selections
+            .selections
+            .iter()
+            .filter_map(
+                |(index, selection)| match (self.choices.get(*index), selection) {
+                    (
+                        Some(Choice::AbilityScores { amount, .. }),
+                        Selection::AbilityScores(abilities),
+                    ) if abilities.contains(&ability) => Some(*amount),
+                    _ => None,
+                },
+            )
+            .sum();
+
+        fixed + chosen
+    }
+
+    /// Effects of the player's selections, e.g. proficiency in a chosen skill.
+    #[must_use]
+    pub fn get_effects(&self, selections: &Selections) -> Vec<Effect> {
+        selections
+            .get_selections()
+            .filter_map(|selection| match selection {
+                Selection::Skill(skill) => Some(Effect::new(
+                    self.name.clone(),
+                    Target::Skill(*skill),
+                    Modifier::Proficiency(Proficiency::Proficiency),
+                )),
+                _ => None,
+            })
+            .collect()
     }
 }
 
@@ -301,6 +704,7 @@ impl From<Template> for Race {
     fn from(value: Template) -> Self {
         Race {
             name: value.name,
+            parent: None,
             creature_type: value.creature_type,
             size: value.size,
             walking_speed: value.walking_speed,
@@ -309,6 +713,7 @@ impl From<Template> for Race {
             condition_resistances: value.condition_resistances,
             languages: value.languages,
             feats: vec![],
+            choices: vec![],
         }
     }
 }
@@ -324,6 +729,7 @@ mod tests {
         pub fn human() -> Self {
             Race {
                 name: "Human".into(),
+                parent: None,
                 creature_type: CreatureType::Humanoid,
                 size: Size::Medium,
                 walking_speed: 30,
@@ -337,8 +743,9 @@ mod tests {
                 }),
                 damage_resistances: HashMap::new(),
                 condition_resistances: HashMap::new(),
-                languages: vec![Language::Common],
+                languages: vec![Language::common()],
                 feats: vec![],
+                choices: vec![],
             }
         }
 
@@ -346,6 +753,7 @@ mod tests {
         pub fn shadar_kai() -> Self {
             Race {
                 name: "Shadar-kai".into(),
+                parent: None,
                 creature_type: CreatureType::Humanoid,
                 size: Size::Medium,
                 walking_speed: 30,
@@ -359,9 +767,97 @@ mod tests {
                     Condition::MagicalSleep,
                     Resistance::Immune,
                 )]),
-                languages: vec![Language::Common, Language::Undercommon],
+                languages: vec![Language::common(), Language::named("Undercommon")],
                 feats: vec![],
+                choices: vec![],
             }
         }
     }
+
+    #[test]
+    fn _subraces_should_inherit_from_their_parent() -> Result<(), RaceConstructionError> {
+        let elf = Builder::new()
+            .name("Elf")
+            .add_ability(ability::Identifier::Dexterity, 2)
+            .add_condition_immunity(Condition::MagicalSleep)
+            .add_language(Language::common())
+            .build()?;
+        let wood_elf = Builder::subrace_of(&elf)
+            .name("Wood Elf")
+            .walking_speed(35)
+            .add_ability(ability::Identifier::Wisdom, 1)
+            .add_choice(Choice::Cantrip(String::from("Druid")))
+            .build()?;
+
+        assert_eq!(wood_elf.get_parent_name(), Some("Elf"));
+        assert_eq!(wood_elf.get_walking_speed(), 35);
+        assert_eq!(
+            wood_elf
+                .get_abilities()
+                .get_score(ability::Identifier::Dexterity),
+            Some(2)
+        );
+        assert_eq!(
+            wood_elf
+                .get_abilities()
+                .get_score(ability::Identifier::Wisdom),
+            Some(1)
+        );
+        assert_eq!(
+            wood_elf.get_condition_resistance(&Condition::MagicalSleep),
+            Some(&Resistance::Immune)
+        );
+        assert!(wood_elf.can_speak(&Language::common()));
+        assert_eq!(elf.get_choices(), &[]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_validate_selections_and_reassignments() {
+        let race = Race::shadar_kai();
+        let mut selections = Selections::default();
+
+        assert_eq!(
+            selections.select(
+                &race,
+                0,
+                Selection::Language(Language::named("Undercommon"))
+            ),
+            Err(ChoiceError::NoSuchChoice(0))
+        );
+
+        assert_eq!(
+            selections.reassign(
+                &race,
+                ability::Identifier::Intelligence,
+                ability::Identifier::Dexterity
+            ),
+            Err(ChoiceError::BonusesStack(ability::Identifier::Dexterity))
+        );
+        assert_eq!(
+            selections.reassign(
+                &race,
+                ability::Identifier::Wisdom,
+                ability::Identifier::Strength
+            ),
+            Err(ChoiceError::NoBonusToMove(ability::Identifier::Wisdom))
+        );
+        assert_eq!(
+            selections.reassign(
+                &race,
+                ability::Identifier::Intelligence,
+                ability::Identifier::Charisma
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            race.get_ability_bonus(ability::Identifier::Charisma, &selections),
+            2
+        );
+        assert_eq!(
+            race.get_ability_bonus(ability::Identifier::Intelligence, &selections),
+            0
+        );
+    }
 }
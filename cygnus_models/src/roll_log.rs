@@ -0,0 +1,419 @@
+#![warn(clippy::pedantic)]
+
+use std::{error, fmt, str::FromStr};
+
+use crate::{check::Check, dice::Rolled, skills::Advantage};
+
+/// A single roll, kept with enough detail to show how the total came about.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollEntry {
+    /// Seconds since the Unix epoch.
+    timestamp: u64,
+    source: String,
+    /// Each die as `(sides, face)`.
+    faces: Vec<(usize, usize)>,
+    /// Each modifier as `(source, value)`; the source may be empty.
+    modifiers: Vec<(String, isize)>,
+    total: isize,
+    notes: Vec<String>,
+}
+
+impl RollEntry {
+    /// Logs a check, keeping both d20s when rolled with advantage or disadvantage.
+    #[must_use]
+    pub fn from_check(timestamp: u64, check: &Check) -> Self {
+        let mut notes = vec![];
+
+        match check.get_advantage() {
+            Some(Advantage::Advantage) => notes.push(String::from("advantage")),
+            Some(Advantage::Disadvantage) => notes.push(String::from("disadvantage")),
+            None => {}
+        }
+
+        if check.get_die() != check.get_natural_roll() {
+            notes.push(format!("counts as {}", check.get_die()));
+        }
+
+        if check.is_automatic_failure() {
+            notes.push(String::from("automatic failure"));
+        } else if check.is_natural_20() {
+            notes.push(String::from("natural 20"));
+        } else if check.is_natural_1() {
+            notes.push(String::from("natural 1"));
+        }
+
+        RollEntry {
+            timestamp,
+            source: check.get_kind().to_string(),
+            faces: check.get_rolls().iter().map(|&roll| (20, roll)).collect(),
+            modifiers: check
+                .get_components()
+                .iter()
+                .map(|component| (component.get_source().to_owned(), component.get_value()))
+                .collect(),
+            total: check.get_total(),
+            notes,
+        }
+    }
+
+    /// Logs a plain dice roll such as damage or a hit die.
+    pub fn from_rolled(timestamp: u64, source: impl Into<String>, rolled: &Rolled) -> Self {
+        let modifiers = match rolled.get_modifier() {
+            0 => vec![],
+            modifier => vec![(String::new(), modifier)],
+        };
+
+        RollEntry {
+            timestamp,
+            source: source.into(),
+            faces: rolled.get_faces().to_vec(),
+            modifiers,
+            total: rolled.get_total(),
+            notes: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    #[must_use]
+    pub fn get_source(&self) -> &str {
+        &self.source
+    }
+
+    #[must_use]
+    pub fn get_faces(&self) -> &[(usize, usize)] {
+        &self.faces
+    }
+
+    #[must_use]
+    pub fn get_modifiers(&self) -> &[(String, isize)] {
+        &self.modifiers
+    }
+
+    #[must_use]
+    pub fn get_total(&self) -> isize {
+        self.total
+    }
+
+    #[must_use]
+    pub fn get_notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// The time of day the roll was made, as `HH:MM:SS` UTC.
+    #[must_use]
+    pub fn get_time_of_day(&self) -> String {
+        let seconds = self.timestamp % 86_400;
+
+        format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )
+    }
+
+    /// The dice grouped by size, e.g. `d20 [4, 17] d6 [3]`.
+    #[must_use]
+    pub fn fmt_faces(&self) -> String {
+        let mut groups: Vec<(usize, Vec<String>)> = vec![];
+
+        for &(sides, face) in &self.faces {
+            match groups.last_mut() {
+                Some((last, faces)) if *last == sides => faces.push(face.to_string()),
+                _ => groups.push((sides, vec![face.to_string()])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(sides, faces)| format!("d{sides} [{}]", faces.join(", ")))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The modifiers with their signs, e.g. `+ 3 (Dexterity) - 1`.
+    #[must_use]
+    pub fn fmt_modifiers(&self) -> String {
+        self.modifiers
+            .iter()
+            .map(|(source, value)| {
+                let sign = if *value < 0 { '-' } else { '+' };
+
+                if source.is_empty() {
+                    format!("{sign} {}", value.abs())
+                } else {
+                    format!("{sign} {} ({source})", value.abs())
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// One tab-separated line of the session file.
+    #[must_use]
+    pub fn to_session_line(&self) -> String {
+        let faces = self
+            .faces
+            .iter()
+            .map(|(sides, face)| format!("{sides}:{face}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let modifiers = self
+            .modifiers
+            .iter()
+            .map(|(source, value)| format!("{}:{value}", clean(source)))
+            .collect::<Vec<_>>()
+            .join("|");
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| clean(note))
+            .collect::<Vec<_>>()
+            .join("|");
+
+        [
+            self.timestamp.to_string(),
+            clean(&self.source),
+            faces,
+            modifiers,
+            self.total.to_string(),
+            notes,
+        ]
+        .join("\t")
+    }
+}
+
+/// Keeps free text from breaking the session file's separators.
+fn clean(text: &str) -> String {
+    text.replace(['\t', '\n', '|'], " ")
+}
+
+impl fmt::Display for RollEntry {
+    /// Plain text meant for pasting into a chat, e.g.
+    /// `[12:34:56] Stealth check: d20 [4, 17] + 3 (Dexterity) = 20 (advantage)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {}: {}",
+            self.get_time_of_day(),
+            self.source,
+            self.fmt_faces()
+        )?;
+
+        if !self.modifiers.is_empty() {
+            write!(f, " {}", self.fmt_modifiers())?;
+        }
+
+        write!(f, " = {}", self.total)?;
+
+        if !self.notes.is_empty() {
+            write!(f, " ({})", self.notes.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for RollEntry {
+    type Err = ParseError;
+
+    /// Reads a line written by [`RollEntry::to_session_line`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || ParseError::MalformedLine(s.to_owned());
+
+        let fields: Vec<&str> = s.split('\t').collect();
+        let [timestamp, source, faces, modifiers, total, notes] = fields[..] else {
+            return Err(malformed());
+        };
+
+        let faces = faces
+            .split(',')
+            .filter(|face| !face.is_empty())
+            .map(|face| {
+                let (sides, face) = face.split_once(':')?;
+
+                Some((sides.parse().ok()?, face.parse().ok()?))
+            })
+            .collect::<Option<_>>()
+            .ok_or_else(malformed)?;
+        let modifiers = modifiers
+            .split('|')
+            .filter(|modifier| !modifier.is_empty())
+            .map(|modifier| {
+                let (source, value) = modifier.rsplit_once(':')?;
+
+                Some((source.to_owned(), value.parse().ok()?))
+            })
+            .collect::<Option<_>>()
+            .ok_or_else(malformed)?;
+
+        Ok(RollEntry {
+            timestamp: timestamp.parse().map_err(|_| malformed())?,
+            source: source.to_owned(),
+            faces,
+            modifiers,
+            total: total.parse().map_err(|_| malformed())?,
+            notes: notes
+                .split('|')
+                .filter(|note| !note.is_empty())
+                .map(str::to_owned)
+                .collect(),
+        })
+    }
+}
+
+/// Every roll made during play, oldest first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RollLog(Vec<RollEntry>);
+
+impl RollLog {
+    pub fn push(&mut self, entry: RollEntry) {
+        self.0.push(entry);
+    }
+
+    #[must_use]
+    pub fn get_entries(&self) -> &[RollEntry] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// The whole log as session file contents, one entry per line.
+    #[must_use]
+    pub fn to_session(&self) -> String {
+        self.0
+            .iter()
+            .map(|entry| entry.to_session_line() + "\n")
+            .collect()
+    }
+
+    /// Reads the contents of a session file, skipping blank lines.
+    ///
+    /// # Errors
+    ///
+    /// - `MalformedLine`: a line isn't in the format written by `to_session`
+    ///
+    pub fn from_session(session: &str) -> Result<Self, ParseError> {
+        session
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map(RollLog)
+    }
+
+    /// The whole log as plain text, one roll per line.
+    #[must_use]
+    pub fn to_plain_text(&self) -> String {
+        self.0
+            .iter()
+            .map(|entry| entry.to_string() + "\n")
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MalformedLine(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            ParseError::MalformedLine(line) => format!("Malformed roll log line `{line}`."),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        check::{CheckKind, Component},
+        dice::{Loaded, Roll},
+        skills,
+    };
+
+    use super::*;
+
+    #[test]
+    fn _should_show_every_face_modifier_and_note_as_plain_text() {
+        let check = Check::roll(
+            CheckKind::Skill(skills::Identifier::Stealth),
+            Some(Advantage::Advantage),
+            vec![
+                Component::new("Dexterity", 3),
+                Component::new("Proficiency", 2),
+            ],
+            None,
+            &mut Loaded::new([4, 20]),
+        );
+        let entry = RollEntry::from_check(45_296, &check);
+
+        assert_eq!(
+            entry.to_string(),
+            "[12:34:56] Stealth check: d20 [4, 20] + 3 (Dexterity) + 2 (Proficiency) = 25 \
+             (advantage, natural 20)"
+        );
+
+        let mut damage = Roll::new(2, 6, -1);
+        damage.add_die(1, 4);
+        let entry = RollEntry::from_rolled(
+            0,
+            "Quarterstaff damage",
+            &damage.roll(&mut Loaded::new([3, 5, 2])),
+        );
+
+        assert_eq!(
+            entry.to_string(),
+            "[00:00:00] Quarterstaff damage: d4 [3] d6 [5, 2] - 1 = 9"
+        );
+    }
+
+    #[test]
+    fn _session_should_round_trip() -> Result<(), ParseError> {
+        let mut log = RollLog::default();
+        log.push(RollEntry::from_check(
+            1_700_000_000,
+            &Check::roll(
+                CheckKind::SavingThrow(crate::ability::Identifier::Dexterity),
+                None,
+                vec![Component::new("Dexterity", -1)],
+                Some(10),
+                &mut Loaded::new([1]),
+            ),
+        ));
+        log.push(RollEntry::from_rolled(
+            1_700_000_060,
+            "Hit die",
+            &Roll::new(1, 8, 2).roll(&mut Loaded::new([6])),
+        ));
+
+        assert_eq!(RollLog::from_session(&log.to_session())?, log);
+        assert!(matches!(
+            RollLog::from_session("not a roll"),
+            Err(ParseError::MalformedLine(_))
+        ));
+
+        Ok(())
+    }
+}
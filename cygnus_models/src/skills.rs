@@ -108,6 +108,38 @@ pub enum Advantage {
     Disadvantage,
 }
 
+impl Advantage {
+    /// Any number of advantages and disadvantages cancel out to a straight roll.
+    pub fn combine(advantages: impl IntoIterator<Item = Advantage>) -> Option<Advantage> {
+        let (has_advantage, has_disadvantage) =
+            advantages
+                .into_iter()
+                .fold((false, false), |(adv, dis), advantage| match advantage {
+                    Advantage::Advantage => (true, dis),
+                    Advantage::Disadvantage => (adv, true),
+                });
+
+        match (has_advantage, has_disadvantage) {
+            (true, false) => Some(Advantage::Advantage),
+            (false, true) => Some(Advantage::Disadvantage),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Advantage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Advantage::Advantage => "Adv",
+                Advantage::Disadvantage => "Dis",
+            }
+        )
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Skill {
     proficiency: Option<Proficiency>,
@@ -145,6 +177,17 @@ impl Skills {
         }
     }
 
+    #[must_use]
+    pub fn get_advantage(&self, skill: Identifier) -> Option<Advantage> {
+        self.0.get(&skill).and_then(|skill| skill.get_advantage())
+    }
+
+    pub fn set_advantage(&mut self, skill: Identifier, advantage: Option<Advantage>) {
+        if let Some(skill) = self.0.get_mut(&skill) {
+            skill.advantage = advantage;
+        }
+    }
+
     pub fn get_modifier(
         &self,
         skill: Identifier,
@@ -173,6 +216,35 @@ impl Default for Skills {
 mod tests {
     use super::*;
 
+    mod advantage {
+        use super::*;
+
+        #[test]
+        fn _should_be_straight_without_sources() {
+            assert_eq!(Advantage::combine([]), None);
+        }
+
+        #[test]
+        fn _should_keep_a_single_kind() {
+            assert_eq!(
+                Advantage::combine([Advantage::Disadvantage, Advantage::Disadvantage]),
+                Some(Advantage::Disadvantage)
+            );
+        }
+
+        #[test]
+        fn _should_cancel_regardless_of_count() {
+            assert_eq!(
+                Advantage::combine([
+                    Advantage::Advantage,
+                    Advantage::Disadvantage,
+                    Advantage::Disadvantage
+                ]),
+                None
+            );
+        }
+    }
+
     mod skill {
         use super::*;
 
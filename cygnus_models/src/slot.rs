@@ -1,21 +1,44 @@
 use std::{collections::HashMap, error, fmt};
 
-use crate::item::Item;
+use crate::{
+    item::Item,
+    slot_layout::{SlotLayout, SlotRule},
+};
+
+/// Decides whether a value may go into a [`Slot`].
+pub trait Validator<T> {
+    fn validate(&self, value: &T) -> bool;
+}
 
-#[derive(Clone, Debug)]
-pub struct Slot<T, F>
+impl<T, F> Validator<T> for F
 where
     F: Fn(&T) -> bool,
+{
+    fn validate(&self, value: &T) -> bool {
+        self(value)
+    }
+}
+
+impl Validator<Item> for SlotRule {
+    fn validate(&self, item: &Item) -> bool {
+        self.matches(item)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Slot<T, V>
+where
+    V: Validator<T>,
 {
     value: Option<T>,
-    validator: F,
+    validator: V,
 }
 
-impl<T, F> Slot<T, F>
+impl<T, V> Slot<T, V>
 where
-    F: Fn(&T) -> bool,
+    V: Validator<T>,
 {
-    pub fn new(validator: F) -> Self {
+    pub fn new(validator: V) -> Self {
         Self {
             value: None,
             validator,
@@ -27,7 +50,7 @@ where
             return Err(SlotError::Full);
         }
 
-        if !(self.validator)(&value) {
+        if !self.validator.validate(&value) {
             return Err(SlotError::Invalid);
         }
 
@@ -39,6 +62,15 @@ where
     pub fn unequip(&mut self) -> SlotResult<T> {
         self.value.take().ok_or(SlotError::Empty)
     }
+
+    pub fn get_value(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Whether `value` could be equipped right now.
+    pub fn accepts(&self, value: &T) -> bool {
+        self.value.is_none() && self.validator.validate(value)
+    }
 }
 
 #[derive(Debug)]
@@ -64,60 +96,275 @@ impl error::Error for SlotError {}
 
 pub type SlotResult<T> = Result<T, SlotError>;
 
-type ItemSlot = Slot<Item, fn(&Item) -> bool>;
+type ItemSlot = Slot<Item, SlotRule>;
+
+/// Equipment slots built from a [`SlotLayout`], which is kept so the layout can be saved
+/// alongside the character. An item taking up several slots is held by the first of them and
+/// claims the rest.
+#[derive(Clone, Debug)]
+pub struct ItemSlots {
+    layout: SlotLayout,
+    slots: HashMap<String, ItemSlot>,
+    claims: HashMap<String, String>,
+}
+
+impl Default for ItemSlots {
+    fn default() -> Self {
+        ItemSlots::from(SlotLayout::new("custom"))
+    }
+}
 
-#[derive(Clone, Debug, Default)]
-pub struct ItemSlots(HashMap<String, ItemSlot>);
+impl From<SlotLayout> for ItemSlots {
+    fn from(layout: SlotLayout) -> Self {
+        let slots = layout
+            .get_slots()
+            .iter()
+            .map(|(name, rule)| (name.clone(), Slot::new(rule.clone())))
+            .collect();
+
+        ItemSlots {
+            layout,
+            slots,
+            claims: HashMap::new(),
+        }
+    }
+}
 
 impl ItemSlots {
-    pub fn add_slot(&mut self, slot_name: impl Into<String>, slot: Slot<Item, fn(&Item) -> bool>) {
-        self.0.insert(slot_name.into(), slot);
+    /// Adds a slot to the layout, replacing any slot of the same name.
+    pub fn add_slot(&mut self, slot_name: impl Into<String>, rule: SlotRule) {
+        let slot_name = slot_name.into();
+
+        self.layout.insert_slot(slot_name.clone(), rule.clone());
+        self.slots.insert(slot_name, Slot::new(rule));
+    }
+
+    pub fn get_layout(&self) -> &SlotLayout {
+        &self.layout
+    }
+
+    /// Empties every slot, returning what was equipped ordered by slot name.
+    pub fn unequip_all(&mut self) -> Vec<Item> {
+        let mut slot_names: Vec<String> = self.slots.keys().cloned().collect();
+        slot_names.sort();
+        self.claims.clear();
+
+        slot_names
+            .iter()
+            .filter_map(|name| self.slots.get_mut(name)?.unequip().ok())
+            .collect()
     }
 
     pub fn equip(&mut self, item: Item, slot_name: impl Into<String>) -> SlotsResult<()> {
         let slot_name = slot_name.into();
 
-        self.0
-            .get_mut(&slot_name)
-            .ok_or(SlotsError::NotExists { slot: slot_name })
-            .and_then(|slot| slot.equip(item).map_err(Into::<SlotsError>::into))
+        self.equip_across(item, &[slot_name.as_str()])
     }
 
+    /// Equips `item` into every slot in `slot_names` at once, e.g. both hands for a
+    /// greatsword. Nothing changes unless all of them can take it.
+    pub fn equip_across(&mut self, item: Item, slot_names: &[&str]) -> SlotsResult<()> {
+        self.check_can_equip(&item, slot_names)?;
+
+        let Some((&holder, claimed)) = slot_names.split_first() else {
+            return Err(SlotsError::WrongSlotCount {
+                item: item.get_name().to_owned(),
+                needed: item.get_slot_count(),
+                given: 0,
+            });
+        };
+
+        self.slots
+            .get_mut(holder)
+            .ok_or_else(|| SlotsError::NotExists {
+                slot: holder.to_owned(),
+            })?
+            .equip(item)?;
+
+        for &slot_name in claimed {
+            self.claims.insert(slot_name.to_owned(), holder.to_owned());
+        }
+
+        Ok(())
+    }
+
+    /// Checks `item` could go into exactly `slot_names`, naming the first slot that blocks it.
+    pub fn check_can_equip(&self, item: &Item, slot_names: &[&str]) -> SlotsResult<()> {
+        let mut distinct = slot_names.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+
+        let needed = item.get_slot_count();
+        if distinct.len() != slot_names.len() || slot_names.len() != needed {
+            return Err(SlotsError::WrongSlotCount {
+                item: item.get_name().to_owned(),
+                needed,
+                given: distinct.len(),
+            });
+        }
+
+        for &slot_name in slot_names {
+            let slot = self
+                .slots
+                .get(slot_name)
+                .ok_or_else(|| SlotsError::NotExists {
+                    slot: slot_name.to_owned(),
+                })?;
+
+            if let Some(occupant) = self.get_item_in(slot_name) {
+                return Err(SlotsError::Occupied {
+                    slot: slot_name.to_owned(),
+                    item: occupant.get_name().to_owned(),
+                });
+            }
+
+            if !slot.validator.validate(item) {
+                return Err(SlotsError::Rejected {
+                    slot: slot_name.to_owned(),
+                    item: item.get_name().to_owned(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Takes the item out of every slot it occupies, whichever of them is named.
     pub fn unequip(&mut self, slot_name: impl Into<String>) -> SlotsResult<Item> {
         let slot_name = slot_name.into();
+        let holder = self.claims.get(&slot_name).cloned().unwrap_or(slot_name);
+
+        let item = self
+            .slots
+            .get_mut(&holder)
+            .ok_or_else(|| SlotsError::NotExists {
+                slot: holder.clone(),
+            })?
+            .unequip()?;
+
+        self.claims.retain(|_, claimed_by| *claimed_by != holder);
 
-        self.0
-            .get_mut(&slot_name)
-            .ok_or(SlotsError::NotExists { slot: slot_name })
-            .and_then(|slot| slot.unequip().map_err(Into::<SlotsError>::into))
+        Ok(item)
+    }
+
+    /// The item occupying `slot_name`, whether it's held there or claims it from another slot.
+    pub fn get_item_in(&self, slot_name: &str) -> Option<&Item> {
+        let holder = self.claims.get(slot_name).map_or(slot_name, String::as_str);
+
+        self.slots.get(holder)?.get_value()
+    }
+
+    /// Every slot taken up by the item in `slot_name`, starting with the one holding it.
+    pub fn get_occupied_slots(&self, slot_name: &str) -> Vec<&str> {
+        let holder = self
+            .claims
+            .get_key_value(slot_name)
+            .map_or(slot_name, |(_, holder)| holder.as_str());
+
+        let Some((holder, _)) = self
+            .slots
+            .get_key_value(holder)
+            .filter(|(_, slot)| slot.value.is_some())
+        else {
+            return vec![];
+        };
+
+        let mut claimed: Vec<&str> = self
+            .claims
+            .iter()
+            .filter(|(_, claimed_by)| *claimed_by == holder)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        claimed.sort_unstable();
+
+        [holder.as_str()].into_iter().chain(claimed).collect()
     }
 
     pub fn has_item_equipped_matching_criteria(&self, item_criteria: fn(&Item) -> bool) -> bool {
-        self.0
+        self.slots
             .values()
             .filter_map(|slot| slot.value.as_ref())
             .any(item_criteria)
     }
 
-    pub fn get_total_weight(&self) -> usize {
-        self.0
+    pub fn get_total_weight(&self) -> f64 {
+        self.slots
             .values()
             .filter_map(|slot| slot.value.as_ref())
-            .map(|item| item.get_weight())
+            .map(Item::get_total_weight)
             .sum()
     }
 
     pub fn get_equipped_items(&self) -> Vec<&Item> {
-        self.0
+        self.slots
             .values()
             .filter_map(|slot| slot.value.as_ref())
             .collect()
     }
+
+    /// Equipped items alongside the name of the slot holding them, ordered by slot name.
+    pub fn get_equipped_items_by_slot(&self) -> Vec<(&str, &Item)> {
+        let mut equipped: Vec<(&str, &Item)> = self
+            .slots
+            .iter()
+            .filter_map(|(name, slot)| slot.value.as_ref().map(|item| (name.as_str(), item)))
+            .collect();
+        equipped.sort_by_key(|(name, _)| *name);
+
+        equipped
+    }
+
+    /// Every slot whose rule accepts `item`, empty ones first, then alphabetically.
+    pub fn get_slots_accepting(&self, item: &Item) -> Vec<&str> {
+        let mut accepting: Vec<&str> = self
+            .slots
+            .iter()
+            .filter(|(_, slot)| slot.validator.validate(item))
+            .map(|(name, _)| name.as_str())
+            .collect();
+        accepting.sort_by_key(|&name| (self.get_item_in(name).is_some(), name));
+
+        accepting
+    }
+
+    /// As many empty slots accepting `item` as it needs, if there are enough.
+    pub fn find_free_slots(&self, item: &Item) -> Option<Vec<&str>> {
+        let free: Vec<&str> = self
+            .get_slots_accepting(item)
+            .into_iter()
+            .filter(|name| self.get_item_in(name).is_none())
+            .take(item.get_slot_count())
+            .collect();
+
+        (free.len() == item.get_slot_count()).then_some(free)
+    }
+
+    pub fn get_equipped_items_mut(&mut self) -> Vec<&mut Item> {
+        self.slots
+            .values_mut()
+            .filter_map(|slot| slot.value.as_mut())
+            .collect()
+    }
 }
 
 #[derive(Debug)]
 pub enum SlotsError {
-    NotExists { slot: String },
+    NotExists {
+        slot: String,
+    },
+    Occupied {
+        slot: String,
+        item: String,
+    },
+    Rejected {
+        slot: String,
+        item: String,
+    },
+    WrongSlotCount {
+        item: String,
+        needed: usize,
+        given: usize,
+    },
     SlotProblem(SlotError),
 }
 
@@ -131,6 +378,13 @@ impl fmt::Display for SlotsError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let result = match self {
             SlotsError::NotExists { slot } => format!("{slot} slot does not exist."),
+            SlotsError::Occupied { slot, item } => format!("{slot} slot is occupied by {item}."),
+            SlotsError::Rejected { slot, item } => format!("{slot} slot cannot hold {item}."),
+            SlotsError::WrongSlotCount {
+                item,
+                needed,
+                given,
+            } => format!("{item} needs {needed} slot(s), but {given} were given."),
             SlotsError::SlotProblem(e) => e.to_string(),
         };
 
@@ -230,19 +484,102 @@ mod tests {
     mod slots {
         use std::error::Error;
 
-        use crate::item::ArmorClass;
+        use crate::{
+            dice::Roll,
+            item::{ArmorClass, Weapon, WeaponCategory, WeaponProperty},
+            race::DamageType,
+        };
 
         use super::*;
 
+        fn hands() -> ItemSlots {
+            let mut equipment = ItemSlots::default();
+            equipment.add_slot(
+                "left hand",
+                SlotRule::AnyOf(vec![SlotRule::Weapon, SlotRule::Shield]),
+            );
+            equipment.add_slot("right hand", SlotRule::Weapon);
+
+            equipment
+        }
+
+        fn greatsword() -> Result<Item, Box<dyn Error>> {
+            Ok(item::Builder::new()
+                .name("Greatsword")?
+                .weapon(
+                    Weapon::new(
+                        WeaponCategory::Martial,
+                        Roll::new(2, 6, 0),
+                        DamageType::Slashing,
+                    )
+                    .add_property(WeaponProperty::Heavy)
+                    .add_property(WeaponProperty::TwoHanded),
+                )?
+                .build()?)
+        }
+
+        #[test]
+        fn _two_handed_weapons_should_occupy_both_hands() -> Result<(), Box<dyn Error>> {
+            let mut equipment = hands();
+
+            assert!(matches!(
+                equipment.equip(greatsword()?, "right hand"),
+                Err(SlotsError::WrongSlotCount {
+                    needed: 2,
+                    given: 1,
+                    ..
+                })
+            ));
+
+            equipment.equip_across(greatsword()?, &["right hand", "left hand"])?;
+
+            assert_eq!(
+                equipment.get_occupied_slots("left hand"),
+                vec!["right hand", "left hand"]
+            );
+            assert_eq!(equipment.find_free_slots(&greatsword()?), None);
+
+            let greatsword = equipment.unequip("left hand")?;
+
+            assert_eq!(greatsword.get_name(), "Greatsword");
+            assert!(equipment.get_equipped_items().is_empty());
+            assert_eq!(
+                equipment.find_free_slots(&greatsword),
+                Some(vec!["left hand", "right hand"])
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn _should_name_the_slot_blocking_a_multi_slot_item() -> Result<(), Box<dyn Error>> {
+            let mut equipment = hands();
+            let shield = item::Builder::new()
+                .name("Shield")?
+                .armor_class(ArmorClass::Shield(2))?
+                .build()?;
+            equipment.equip(shield, "left hand")?;
+
+            let result = equipment.equip_across(greatsword()?, &["right hand", "left hand"]);
+
+            assert!(matches!(
+                result,
+                Err(SlotsError::Occupied { slot, item }) if slot == "left hand" && item == "Shield"
+            ));
+            assert!(equipment.get_item_in("right hand").is_none());
+
+            Ok(())
+        }
+
         #[test]
         fn _should_allow_equipping_to_multiple_slots() -> Result<(), Box<dyn Error>> {
             let mut equipment = ItemSlots::default();
-            equipment.add_slot("armor", Slot::new(|item| item.has_type("armor")));
-            equipment.add_slot("right hand", Slot::new(|item| item.has_type("weapon")));
+            equipment.add_slot("armor", SlotRule::of_type("armor"));
+            equipment.add_slot("right hand", SlotRule::of_type("weapon"));
 
             let chain_mail = item::Builder::new()
                 .name("Chain Mail")?
-                .weight(55)?
+                .weight(55.0)?
                 .add_type("armor")?
                 .armor_class(ArmorClass::Heavy(16))?
                 .build()?;
@@ -250,7 +587,7 @@ mod tests {
 
             let rapier = item::Builder::new()
                 .name("Rapier")?
-                .weight(2)?
+                .weight(2.0)?
                 .add_type("weapon")?
                 .build()?;
             equipment.equip(rapier, "right hand")?;
@@ -261,14 +598,14 @@ mod tests {
         #[test]
         fn _should_return_whether_contains_thing_of_given_type() -> Result<(), Box<dyn Error>> {
             let mut equipment = ItemSlots::default();
-            equipment.add_slot("armor", Slot::new(|item| item.has_type("armor")));
-            equipment.add_slot("right hand", Slot::new(|item| item.has_type("weapon")));
+            equipment.add_slot("armor", SlotRule::of_type("armor"));
+            equipment.add_slot("right hand", SlotRule::of_type("weapon"));
 
             let armor_criteria = |item: &Item| item.has_type("armor");
 
             let rapier = item::Builder::new()
                 .name("Rapier")?
-                .weight(2)?
+                .weight(2.0)?
                 .add_type("weapon")?
                 .build()?;
             equipment.equip(rapier, "right hand")?;
@@ -276,7 +613,7 @@ mod tests {
 
             let chain_mail = item::Builder::new()
                 .name("Chain Mail")?
-                .weight(55)?
+                .weight(55.0)?
                 .add_type("armor")?
                 .armor_class(ArmorClass::Heavy(16))?
                 .build()?;
@@ -286,15 +623,37 @@ mod tests {
             Ok(())
         }
 
+        #[test]
+        fn _should_find_the_first_free_slot_accepting_an_item() -> Result<(), Box<dyn Error>> {
+            let mut equipment = ItemSlots::default();
+            equipment.add_slot("ring 2", SlotRule::of_type("ring"));
+            equipment.add_slot("ring 1", SlotRule::of_type("ring"));
+            equipment.add_slot("armor", SlotRule::of_type("armor"));
+
+            let ring = item::Builder::new()
+                .name("Ring")?
+                .add_type("ring")?
+                .build()?;
+            assert_eq!(equipment.find_free_slots(&ring), Some(vec!["ring 1"]));
+
+            equipment.equip(ring.clone(), "ring 1")?;
+            assert_eq!(equipment.find_free_slots(&ring), Some(vec!["ring 2"]));
+
+            equipment.equip(ring.clone(), "ring 2")?;
+            assert_eq!(equipment.find_free_slots(&ring), None);
+
+            Ok(())
+        }
+
         #[test]
         fn _should_return_the_total_weight_of_equipped_items() -> Result<(), Box<dyn Error>> {
             let mut equipment = ItemSlots::default();
-            equipment.add_slot("armor", Slot::new(|_: &Item| true));
-            equipment.add_slot("right hand", Slot::new(|_: &Item| true));
+            equipment.add_slot("armor", SlotRule::Any);
+            equipment.add_slot("right hand", SlotRule::Any);
 
             let chain_mail = item::Builder::new()
                 .name("Chain Mail")?
-                .weight(55)?
+                .weight(55.0)?
                 .add_type("armor")?
                 .armor_class(ArmorClass::Heavy(16))?
                 .build()?;
@@ -302,12 +661,12 @@ mod tests {
 
             let rapier = item::Builder::new()
                 .name("Rapier")?
-                .weight(2)?
+                .weight(2.0)?
                 .add_type("weapon")?
                 .build()?;
             equipment.equip(rapier, "right hand")?;
 
-            assert_eq!(equipment.get_total_weight(), 57);
+            assert_eq!(equipment.get_total_weight(), 57.0);
 
             Ok(())
         }
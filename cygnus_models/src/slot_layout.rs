@@ -0,0 +1,471 @@
+#![warn(clippy::pedantic)]
+
+use std::{error, fmt, str::FromStr};
+
+use crate::item::Item;
+
+/// A declarative check an item has to pass to go into an equipment slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SlotRule {
+    Any,
+    Type(String),
+    BodyArmor,
+    Shield,
+    Weapon,
+    OneHandedWeapon,
+    AnyOf(Vec<SlotRule>),
+    AllOf(Vec<SlotRule>),
+    Not(Box<SlotRule>),
+}
+
+impl SlotRule {
+    pub fn of_type(item_type: impl Into<String>) -> Self {
+        SlotRule::Type(item_type.into())
+    }
+
+    #[must_use]
+    pub fn matches(&self, item: &Item) -> bool {
+        match self {
+            SlotRule::Any => true,
+            SlotRule::Type(item_type) => item.has_type(item_type),
+            SlotRule::BodyArmor => item.is_body_armor(),
+            SlotRule::Shield => item.is_shield(),
+            SlotRule::Weapon => item.is_weapon(),
+            SlotRule::OneHandedWeapon => item
+                .get_weapon()
+                .is_some_and(|weapon| !weapon.is_two_handed()),
+            SlotRule::AnyOf(rules) => rules.iter().any(|rule| rule.matches(item)),
+            SlotRule::AllOf(rules) => rules.iter().all(|rule| rule.matches(item)),
+            SlotRule::Not(rule) => !rule.matches(item),
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(self, SlotRule::AnyOf(_) | SlotRule::AllOf(_))
+    }
+
+    fn fmt_operand(&self) -> String {
+        if self.is_compound() {
+            format!("({self})")
+        } else {
+            self.to_string()
+        }
+    }
+}
+
+impl fmt::Display for SlotRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |rules: &[SlotRule], separator: &str| {
+            rules
+                .iter()
+                .map(SlotRule::fmt_operand)
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+
+        let result = match self {
+            SlotRule::Any => String::from("any"),
+            SlotRule::Type(item_type) => format!("type({})", quote(item_type)),
+            SlotRule::BodyArmor => String::from("body armor"),
+            SlotRule::Shield => String::from("shield"),
+            SlotRule::Weapon => String::from("weapon"),
+            SlotRule::OneHandedWeapon => String::from("one-handed weapon"),
+            SlotRule::AnyOf(rules) => join(rules, " | "),
+            SlotRule::AllOf(rules) => join(rules, " & "),
+            SlotRule::Not(rule) => format!("!{}", rule.fmt_operand()),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl FromStr for SlotRule {
+    type Err = ParseError;
+
+    /// Parses the format written by `Display`: `|` binds looser than `&`, `!` negates, and
+    /// parentheses group. Type names are quoted, with `\"`, `\\` and `\n` escaped, so they can
+    /// hold any of those; an unquoted one is read up to its closing parenthesis.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        for (separator, combine) in [
+            ('|', SlotRule::AnyOf as fn(Vec<SlotRule>) -> SlotRule),
+            ('&', SlotRule::AllOf),
+        ] {
+            let operands = split_top_level(s, separator)?;
+
+            if operands.len() > 1 {
+                return operands
+                    .into_iter()
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()
+                    .map(combine);
+            }
+        }
+
+        if let Some(negated) = s.strip_prefix('!') {
+            return Ok(SlotRule::Not(Box::new(negated.parse()?)));
+        }
+
+        if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            return inner.parse();
+        }
+
+        if let Some(item_type) = s.strip_prefix("type(").and_then(|s| s.strip_suffix(')')) {
+            let item_type = item_type.trim();
+
+            return match item_type.strip_prefix('"') {
+                Some(quoted) => unquote(quoted)
+                    .map(SlotRule::of_type)
+                    .ok_or_else(|| ParseError::UnknownRule(s.to_owned())),
+                None => Ok(SlotRule::of_type(item_type)),
+            };
+        }
+
+        match s {
+            "any" => Ok(SlotRule::Any),
+            "body armor" => Ok(SlotRule::BodyArmor),
+            "shield" => Ok(SlotRule::Shield),
+            "weapon" => Ok(SlotRule::Weapon),
+            "one-handed weapon" => Ok(SlotRule::OneHandedWeapon),
+            _ => Err(ParseError::UnknownRule(s.to_owned())),
+        }
+    }
+}
+
+/// Wraps a type name in double quotes, escaping any quote, backslash or line break in it.
+fn quote(item_type: &str) -> String {
+    format!(
+        "\"{}\"",
+        item_type
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
+
+/// Reads a type name written by `quote`, given everything after its opening quote. Nothing may
+/// follow the closing quote.
+fn unquote(quoted: &str) -> Option<String> {
+    let mut item_type = String::new();
+    let mut chars = quoted.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => item_type.push('\n'),
+                c => item_type.push(c),
+            },
+            '"' => return chars.as_str().is_empty().then_some(item_type),
+            c => item_type.push(c),
+        }
+    }
+
+    None
+}
+
+/// Splits `s` on `separator` wherever it isn't nested inside parentheses or quotes.
+fn split_top_level(s: &str, separator: char) -> Result<Vec<&str>, ParseError> {
+    let mut operands = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for (index, c) in s.char_indices() {
+        if quoted {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => quoted = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' => quoted = true,
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| ParseError::UnknownRule(s.to_owned()))?;
+            }
+            c if c == separator && depth == 0 => {
+                operands.push(&s[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    if depth != 0 || quoted {
+        return Err(ParseError::UnknownRule(s.to_owned()));
+    }
+
+    operands.push(&s[start..]);
+
+    Ok(operands)
+}
+
+/// A named set of equipment slots and what each one accepts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlotLayout {
+    name: String,
+    slots: Vec<(String, SlotRule)>,
+}
+
+impl SlotLayout {
+    pub fn new(name: impl Into<String>) -> Self {
+        SlotLayout {
+            name: name.into(),
+            slots: vec![],
+        }
+    }
+
+    /// Replaces the rule of a slot that already has `slot_name`.
+    #[must_use]
+    pub fn add_slot(mut self, slot_name: impl Into<String>, rule: SlotRule) -> Self {
+        self.insert_slot(slot_name, rule);
+
+        self
+    }
+
+    pub(crate) fn insert_slot(&mut self, slot_name: impl Into<String>, rule: SlotRule) {
+        let slot_name = slot_name.into();
+
+        match self.slots.iter_mut().find(|(name, _)| *name == slot_name) {
+            Some((_, existing)) => *existing = rule,
+            None => self.slots.push((slot_name, rule)),
+        }
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn get_slots(&self) -> &[(String, SlotRule)] {
+        &self.slots
+    }
+
+    #[must_use]
+    pub fn humanoid() -> Self {
+        SlotLayout::new("humanoid")
+            .add_slot("armor", SlotRule::of_type("armor"))
+            .add_slot("cloak", SlotRule::of_type("cloak"))
+            .add_slot("neck", SlotRule::of_type("amulet"))
+            .add_slot("ring 1", SlotRule::of_type("ring"))
+            .add_slot("ring 2", SlotRule::of_type("ring"))
+            .add_slot(
+                "left hand",
+                SlotRule::AnyOf(vec![SlotRule::Weapon, SlotRule::Shield]),
+            )
+            .add_slot("right hand", SlotRule::Weapon)
+    }
+
+    /// The humanoid layout with the suit of armor split into separate pieces, as an artificer
+    /// armorer wears it.
+    #[must_use]
+    pub fn armorer() -> Self {
+        let mut layout = SlotLayout::new("armorer");
+
+        for (slot_name, item_type) in [
+            ("helmet", "helmet"),
+            ("chestplate", "chestplate"),
+            ("gauntlets", "gauntlets"),
+            ("boots", "boots"),
+        ] {
+            layout.insert_slot(slot_name, SlotRule::of_type(item_type));
+        }
+
+        for (slot_name, rule) in SlotLayout::humanoid().slots {
+            if slot_name != "armor" {
+                layout.insert_slot(slot_name, rule);
+            }
+        }
+
+        layout
+    }
+}
+
+impl fmt::Display for SlotLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[{}]", self.name)?;
+
+        for (slot_name, rule) in &self.slots {
+            writeln!(f, "{slot_name} = {rule}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for SlotLayout {
+    type Err = ParseError;
+
+    /// Reads a `[name]` header followed by one `slot name = rule` line per slot. Blank lines
+    /// and lines starting with `#` are skipped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        let mut layout = lines
+            .next()
+            .and_then(|line| line.strip_prefix('[')?.strip_suffix(']'))
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(SlotLayout::new)
+            .ok_or(ParseError::MissingName)?;
+
+        for line in lines {
+            let (slot_name, rule) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::MalformedLine(line.to_owned()))?;
+            let slot_name = slot_name.trim();
+
+            if slot_name.is_empty() {
+                return Err(ParseError::MalformedLine(line.to_owned()));
+            }
+
+            if layout.slots.iter().any(|(name, _)| name == slot_name) {
+                return Err(ParseError::DuplicateSlot(slot_name.to_owned()));
+            }
+
+            layout.insert_slot(slot_name, rule.parse()?);
+        }
+
+        Ok(layout)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingName,
+    MalformedLine(String),
+    DuplicateSlot(String),
+    UnknownRule(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            ParseError::MissingName => String::from("Slot layout must start with a [name] line."),
+            ParseError::MalformedLine(line) => {
+                format!("Expected `slot name = rule`, found `{line}`.")
+            }
+            ParseError::DuplicateSlot(slot) => format!("{slot} slot is defined more than once."),
+            ParseError::UnknownRule(rule) => format!("Unknown slot rule `{rule}`."),
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+impl error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::{
+        dice::Roll,
+        item::{self, ArmorClass, Weapon, WeaponCategory, WeaponProperty},
+        race::DamageType,
+    };
+
+    use super::*;
+
+    #[test]
+    fn _left_hand_should_accept_shields_and_one_handed_weapons() -> Result<(), Box<dyn Error>> {
+        let rule = SlotRule::AnyOf(vec![SlotRule::OneHandedWeapon, SlotRule::Shield]);
+
+        let shield = item::Builder::new()
+            .name("Shield")?
+            .armor_class(ArmorClass::Shield(2))?
+            .build()?;
+        let greatsword = item::Builder::new()
+            .name("Greatsword")?
+            .weapon(
+                Weapon::new(
+                    WeaponCategory::Martial,
+                    Roll::new(2, 6, 0),
+                    DamageType::Slashing,
+                )
+                .add_property(WeaponProperty::Heavy)
+                .add_property(WeaponProperty::TwoHanded),
+            )?
+            .build()?;
+
+        assert!(rule.matches(&shield));
+        assert!(!rule.matches(&greatsword));
+        assert!(SlotRule::Not(Box::new(rule)).matches(&greatsword));
+
+        Ok(())
+    }
+
+    #[test]
+    fn _layouts_should_round_trip_through_text() -> Result<(), ParseError> {
+        let layout = SlotLayout::armorer().add_slot(
+            "belt",
+            SlotRule::AllOf(vec![
+                SlotRule::AnyOf(vec![SlotRule::of_type("belt"), SlotRule::Weapon]),
+                SlotRule::Not(Box::new(SlotRule::BodyArmor)),
+            ]),
+        );
+
+        assert_eq!(layout.to_string().parse::<SlotLayout>()?, layout);
+
+        Ok(())
+    }
+
+    #[test]
+    fn _type_names_should_round_trip_whatever_they_hold() -> Result<(), ParseError> {
+        let rule = SlotRule::AnyOf(vec![
+            SlotRule::of_type("belt & pouch"),
+            SlotRule::AllOf(vec![
+                SlotRule::of_type("!(rod | wand)"),
+                SlotRule::of_type("the \"odd\"\none\\"),
+            ]),
+        ]);
+
+        assert_eq!(rule.to_string().parse::<SlotRule>()?, rule);
+        assert_eq!(
+            SlotLayout::new("custom")
+                .add_slot("belt", rule.clone())
+                .to_string()
+                .parse::<SlotLayout>()?
+                .get_slots(),
+            &[(String::from("belt"), rule)]
+        );
+        assert_eq!("type(belt)".parse::<SlotRule>()?, SlotRule::of_type("belt"));
+        assert_eq!(
+            r#"type("belt) | any"#.parse::<SlotRule>(),
+            Err(ParseError::UnknownRule(String::from(
+                r#"type("belt) | any"#
+            )))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn _should_report_layout_parse_errors() {
+        assert_eq!(
+            "armor = any".parse::<SlotLayout>(),
+            Err(ParseError::MissingName)
+        );
+        assert_eq!(
+            "[custom]\narmor = plate".parse::<SlotLayout>(),
+            Err(ParseError::UnknownRule(String::from("plate")))
+        );
+        assert_eq!(
+            "[custom]\narmor = any\narmor = shield".parse::<SlotLayout>(),
+            Err(ParseError::DuplicateSlot(String::from("armor")))
+        );
+    }
+}
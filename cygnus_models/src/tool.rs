@@ -0,0 +1,161 @@
+#![warn(clippy::pedantic)]
+
+use std::fmt;
+
+use crate::catalogue::{self, impl_entry_eq, Entry};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category {
+    ArtisansTools,
+    GamingSet,
+    MusicalInstrument,
+    /// Kits and specialist tools such as Thieves' Tools.
+    Kit,
+    Vehicles,
+    Other,
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Category::ArtisansTools => "Artisan's Tools",
+            Category::GamingSet => "Gaming Set",
+            Category::MusicalInstrument => "Musical Instrument",
+            Category::Kit => "Kit",
+            Category::Vehicles => "Vehicles",
+            Category::Other => "Other",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// A tool a character can be proficient with. Tools with the same name are the same tool,
+/// whatever the case.
+#[derive(Clone, Debug)]
+pub struct Tool {
+    name: String,
+    category: Category,
+}
+
+const TOOLS: [(&str, Category); 39] = [
+    ("Alchemist's Supplies", Category::ArtisansTools),
+    ("Brewer's Supplies", Category::ArtisansTools),
+    ("Calligrapher's Supplies", Category::ArtisansTools),
+    ("Carpenter's Tools", Category::ArtisansTools),
+    ("Cartographer's Tools", Category::ArtisansTools),
+    ("Cobbler's Tools", Category::ArtisansTools),
+    ("Cook's Utensils", Category::ArtisansTools),
+    ("Glassblower's Tools", Category::ArtisansTools),
+    ("Jeweler's Tools", Category::ArtisansTools),
+    ("Leatherworker's Tools", Category::ArtisansTools),
+    ("Mason's Tools", Category::ArtisansTools),
+    ("Painter's Supplies", Category::ArtisansTools),
+    ("Potter's Tools", Category::ArtisansTools),
+    ("Smith's Tools", Category::ArtisansTools),
+    ("Tinker's Tools", Category::ArtisansTools),
+    ("Weaver's Tools", Category::ArtisansTools),
+    ("Woodcarver's Tools", Category::ArtisansTools),
+    ("Dice Set", Category::GamingSet),
+    ("Dragonchess Set", Category::GamingSet),
+    ("Playing Card Set", Category::GamingSet),
+    ("Three-Dragon Ante Set", Category::GamingSet),
+    ("Bagpipes", Category::MusicalInstrument),
+    ("Drum", Category::MusicalInstrument),
+    ("Dulcimer", Category::MusicalInstrument),
+    ("Flute", Category::MusicalInstrument),
+    ("Horn", Category::MusicalInstrument),
+    ("Lute", Category::MusicalInstrument),
+    ("Lyre", Category::MusicalInstrument),
+    ("Pan Flute", Category::MusicalInstrument),
+    ("Shawm", Category::MusicalInstrument),
+    ("Viol", Category::MusicalInstrument),
+    ("Disguise Kit", Category::Kit),
+    ("Forgery Kit", Category::Kit),
+    ("Herbalism Kit", Category::Kit),
+    ("Navigator's Tools", Category::Kit),
+    ("Poisoner's Kit", Category::Kit),
+    ("Thieves' Tools", Category::Kit),
+    ("Land Vehicles", Category::Vehicles),
+    ("Water Vehicles", Category::Vehicles),
+];
+
+impl Tool {
+    /// A tool outside the built-in catalogue.
+    pub fn new(name: impl Into<String>, category: Category) -> Self {
+        Tool {
+            name: name.into(),
+            category,
+        }
+    }
+
+    /// The built-in tool with this name, ignoring case, or a homebrew tool of no particular
+    /// category. Use [`Catalogue::named`] to reach homebrew details too.
+    pub fn named(name: impl Into<String>) -> Self {
+        Catalogue::default().named(name)
+    }
+
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn get_category(&self) -> Category {
+        self.category
+    }
+}
+
+impl_entry_eq!(Tool);
+
+impl Entry for Tool {
+    fn built_in() -> Vec<Self> {
+        TOOLS
+            .iter()
+            .map(|&(name, category)| Tool::new(name, category))
+            .collect()
+    }
+
+    fn unknown(name: String) -> Self {
+        Tool::new(name, Category::Other)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for Tool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// The tools characters can pick from: the built-in ones plus any homebrew.
+pub type Catalogue = catalogue::Catalogue<Tool>;
+
+impl Catalogue {
+    #[must_use]
+    pub fn get_by_category(&self, category: Category) -> Vec<&Tool> {
+        self.get_entries()
+            .iter()
+            .filter(|tool| tool.category == category)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _catalogue_should_group_tools_by_category() {
+        let catalogue = Catalogue::default();
+
+        assert_eq!(Tool::named("thieves' tools").get_category(), Category::Kit);
+        assert!(catalogue
+            .get_by_category(Category::GamingSet)
+            .contains(&&Tool::named("Dice Set")));
+        assert_eq!(catalogue.get_by_category(Category::Vehicles).len(), 2);
+    }
+}
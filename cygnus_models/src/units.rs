@@ -44,9 +44,18 @@ impl fmt::Display for Distance {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 pub enum Weight {
-    Pounds(usize),
+    Pounds(f64),
+}
+
+impl Weight {
+    #[must_use]
+    pub fn get_pounds(&self) -> f64 {
+        match self {
+            Weight::Pounds(pounds) => *pounds,
+        }
+    }
 }
 
 impl fmt::Display for Weight {
@@ -60,3 +69,29 @@ impl fmt::Display for Weight {
         )
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum Volume {
+    CubicFeet(f64),
+}
+
+impl Volume {
+    #[must_use]
+    pub fn get_cubic_feet(&self) -> f64 {
+        match self {
+            Volume::CubicFeet(cubic_feet) => *cubic_feet,
+        }
+    }
+}
+
+impl fmt::Display for Volume {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Volume::CubicFeet(c) => format!("{c} cu. ft."),
+            }
+        )
+    }
+}
@@ -1,27 +1,225 @@
-use std::error;
+use std::{
+    env, error, fmt, fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use cygnus_models::{
     ability::{self, Abilities, AbilitiesTemplate},
+    artificer::{ArcaneArmor, Infusion, Piece},
+    background,
     character::{self, Character},
     characteristics::{self, Characteristics, Conformity, Gender, Morality},
-    class::{self, HPIncreases},
-    item::{self, ArmorClass},
+    check::CheckKind,
+    class::{self, HPIncreases, Subclass},
+    container::{Container, Mount},
+    currency::{Coin, Price},
+    dice::Roll,
+    effect::{Modifier, Target},
+    feature::{Feature, Rest, UseCount},
+    item::{
+        self, ArmorCategory, ArmorClass, Grip, Item, Mastery, Weapon, WeaponCategory,
+        WeaponProperty,
+    },
+    language::Language,
     personality::Personality,
-    race::{self, Language, Size},
+    psionics::{
+        discipline::{self, Act, Discipline, Scaling},
+        Mystic, Psionics, Talent,
+    },
+    race::{self, DamageType, Size},
+    roll_log::{RollEntry, RollLog},
     senses, skills,
-    slot::Slot,
-    units::{Distance, Duration, Weight},
+    slot_layout::SlotLayout,
+    tool::Tool,
+    units::{Distance, Duration, Volume, Weight},
 };
+use ratatui::widgets::{ListState, TableState};
+
+use crate::ui::PageLink;
 
 /// Application result type.
 pub type AppResult<T> = std::result::Result<T, Box<dyn error::Error>>;
 
+/// Name of the file the roll log is kept in between sessions.
+const ROLL_LOG_SESSION_FILE: &str = "roll_log.tsv";
+
+/// Name of the file the character's equipment slot layout is kept in between sessions.
+const SLOT_LAYOUT_SESSION_FILE: &str = "slot_layout.txt";
+
+/// Name of the plain text file the roll log is exported to.
+const ROLL_LOG_EXPORT_FILE: &str = "roll_log.txt";
+
 #[derive(Clone, Debug, Default)]
 pub struct NavMenuState {
     pub is_open: bool,
     pub selected: usize,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InventorySort {
+    #[default]
+    Name,
+    Weight,
+    Equipped,
+}
+
+impl InventorySort {
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            InventorySort::Name => InventorySort::Weight,
+            InventorySort::Weight => InventorySort::Equipped,
+            InventorySort::Equipped => InventorySort::Name,
+        }
+    }
+}
+
+impl fmt::Display for InventorySort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            InventorySort::Name => "Name",
+            InventorySort::Weight => "Weight",
+            InventorySort::Equipped => "Equipped",
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InventoryState {
+    pub table_state: TableState,
+    pub sort: InventorySort,
+    /// Feedback from the last inventory action, e.g. why an item couldn't be equipped.
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ActionsState {
+    pub list_state: ListState,
+    /// Psi points spent on the selected act beyond its cost.
+    pub charge: usize,
+    /// Feedback from the last act performed, e.g. how many psi points are left.
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RollLogState {
+    /// How many entries back from the newest the log is scrolled.
+    pub scroll: usize,
+    /// Feedback from the last roll log action, e.g. where it was exported to.
+    pub message: Option<String>,
+}
+
+/// Where an inventory row's item currently is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Location {
+    Carried,
+    Equipped(String),
+    Container(String),
+    Mount(String),
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self {
+            Location::Carried => "-",
+            Location::Equipped(name) | Location::Container(name) | Location::Mount(name) => name,
+        };
+
+        write!(f, "{result}")
+    }
+}
+
+/// A row of the inventory table.
+pub struct InventoryEntry<'a> {
+    pub item: &'a Item,
+    pub location: Location,
+}
+
+/// Every equipped, carried, stored and mount-borne item, in the order given by `sort`.
+#[must_use]
+pub fn get_inventory_entries(
+    character: &Character,
+    sort: InventorySort,
+) -> Vec<InventoryEntry<'_>> {
+    let equipped = character
+        .get_equipment()
+        .get_equipped_items_by_slot()
+        .into_iter()
+        .map(|(slot, item)| InventoryEntry {
+            item,
+            location: Location::Equipped(slot.to_owned()),
+        });
+    let carried = character.get_inventory().iter().map(|item| InventoryEntry {
+        item,
+        location: Location::Carried,
+    });
+    let stored = character
+        .get_containers()
+        .into_iter()
+        .flat_map(|container| {
+            container
+                .get_container()
+                .into_iter()
+                .flat_map(|contents| contents.get_contents().iter())
+                .map(|item| InventoryEntry {
+                    item,
+                    location: Location::Container(container.get_name().to_owned()),
+                })
+        });
+    let mounted = character.get_mounts().iter().flat_map(|mount| {
+        mount.get_inventory().iter().map(|item| InventoryEntry {
+            item,
+            location: Location::Mount(mount.get_name().to_owned()),
+        })
+    });
+
+    let mut entries: Vec<InventoryEntry> = equipped
+        .chain(carried)
+        .chain(stored)
+        .chain(mounted)
+        .collect();
+
+    match sort {
+        InventorySort::Name => entries.sort_by(|a, b| a.item.get_name().cmp(b.item.get_name())),
+        InventorySort::Weight => entries.sort_by(|a, b| {
+            b.item
+                .get_total_weight()
+                .total_cmp(&a.item.get_total_weight())
+                .then_with(|| a.item.get_name().cmp(b.item.get_name()))
+        }),
+        InventorySort::Equipped => entries.sort_by(|a, b| {
+            let is_equipped =
+                |entry: &InventoryEntry| matches!(entry.location, Location::Equipped(_));
+
+            is_equipped(b)
+                .cmp(&is_equipped(a))
+                .then_with(|| a.item.get_name().cmp(b.item.get_name()))
+        }),
+    }
+
+    entries
+}
+
+/// Every act of the character's known disciplines, with its discipline.
+#[must_use]
+pub fn get_acts(character: &Character) -> Vec<(&Discipline, &Act)> {
+    character
+        .get_psionics()
+        .map(Psionics::get_disciplines)
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|discipline| {
+            discipline
+                .get_acts()
+                .iter()
+                .map(move |act| (discipline, act))
+        })
+        .collect()
+}
+
 /// Application.
 #[derive(Debug)]
 pub struct App {
@@ -31,6 +229,16 @@ pub struct App {
     pub character: Option<Character>,
 
     pub nav_menu_state: NavMenuState,
+
+    pub inventory_state: InventoryState,
+
+    pub actions_state: ActionsState,
+
+    pub skills_state: TableState,
+
+    pub roll_log: RollLog,
+
+    pub roll_log_state: RollLogState,
 }
 
 impl Default for App {
@@ -39,6 +247,11 @@ impl Default for App {
             running: true,
             character: None,
             nav_menu_state: NavMenuState::default(),
+            inventory_state: InventoryState::default(),
+            actions_state: ActionsState::default(),
+            skills_state: TableState::default(),
+            roll_log: RollLog::default(),
+            roll_log_state: RollLogState::default(),
         }
     }
 }
@@ -56,19 +269,115 @@ impl App {
             .name("Haskellian")
             .add_ability(ability::Identifier::Intelligence, 2)
             .add_ability(ability::Identifier::Dexterity, 1)
-            .add_language(Language::Common)
-            .add_language(Language::Undercommon)
+            .add_language(Language::common())
+            .add_language(Language::named("Undercommon"))
             .build()?;
 
         let hp_increases = HPIncreases::try_from(vec![8, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5])?;
 
-        let artificer = class::Builder::new()
+        let armorer = Subclass::new("Armorer")
+            .add_armor_proficiency(ArmorCategory::Heavy)
+            .add_tool_proficiency(Tool::named("Smith's Tools"))
+            .add_spells(3, ["Magic Missile", "Thunderwave"])
+            .add_spells(5, ["Mirror Image", "Shatter"])
+            .add_spells(9, ["Hypnotic Pattern", "Lightning Bolt"])
+            .add_feature(Feature::new(
+                "Arcane Armor",
+                "As an action, you can turn a suit of armor you are wearing into Arcane Armor, provided you have smith's tools in hand. You can doff or don the armor as an action, and it can replace a missing limb.",
+                3,
+            ))
+            .add_feature(Feature::new(
+                "Armor Model",
+                "You can customize your Arcane Armor as a Guardian or an Infiltrator, changing the model whenever you finish a short or long rest.",
+                3,
+            ))
+            .add_feature(Feature::new(
+                "Extra Attack",
+                "You can attack twice, rather than once, whenever you take the Attack action on your turn.",
+                5,
+            ))
+            .add_feature(Feature::new(
+                "Armor Modifications",
+                "Your Arcane Armor counts as separate items for the purposes of your Infuse Items feature: armor, boots, helmet and the armor's special weapon.",
+                9,
+            ));
+
+        let mut artificer = class::Builder::new()
             .name("Artificer")?
             .level(12)?
             .hp_increases(hp_increases)?
             .add_saving_throw_proficiency(ability::Identifier::Intelligence)?
             .add_saving_throw_proficiency(ability::Identifier::Constitution)?
+            .add_feature(Feature::new(
+                "Magical Tinkering",
+                "You can use thieves' tools or artisan's tools to invest a spark of magic into a Tiny nonmagical object, giving it a light, a recorded message, an odor or sound, or a static visual effect.",
+                1,
+            ))?
+            .add_feature(Feature::new(
+                "Infuse Item",
+                "Whenever you finish a long rest, you can touch a nonmagical object and imbue it with one of your artificer infusions, turning it into a magic item.",
+                2,
+            ))?
+            .add_feature(Feature::new(
+                "The Right Tool for the Job",
+                "With thieves' tools or artisan's tools in hand, you can magically create one set of artisan's tools in an unoccupied space within 5 feet of you.",
+                3,
+            ))?
+            .add_feature(Feature::new(
+                "Tool Expertise",
+                "Your proficiency bonus is doubled for any ability check you make that uses your proficiency with a tool.",
+                6,
+            ))?
+            .add_feature(
+                Feature::new(
+                    "Flash of Genius",
+                    "When you or another creature you can see within 30 feet of you makes an ability check or a saving throw, you can use your reaction to add your Intelligence modifier to the roll.",
+                    7,
+                )
+                .limited(
+                    UseCount::AbilityModifier(ability::Identifier::Intelligence),
+                    Rest::Long,
+                ),
+            )?
+            .add_feature(Feature::new(
+                "Magic Item Adept",
+                "You can attune to up to four magic items at once, and crafting a common or uncommon magic item takes you a quarter of the normal time and half as much gold.",
+                10,
+            ))?
             .build()?;
+        artificer.set_subclass(armorer)?;
+
+        let psychic_phantoms: Discipline = discipline::Builder::new()
+            .name("Psychic Phantoms")
+            .order("Awakened")
+            .description("Your power reaches into a creature’s mind and causes it false perceptions.")
+            .focus("While focused on this discipline, you have advantage on Charisma (Deception) checks.")
+            .add_act(&Act::new(
+                "Distracting Figment",
+                "As an action, choose one creature you can see within 60 feet of you. The target must make an Intelligence saving throw. On a failed save, it takes 1d10 psychic damage per psi point spent and thinks it perceives a threatening creature just out of its sight; until the end of your next turn, it can’t use reactions, and melee attack rolls against it have advantage. On a successful save, it takes half as much damage.",
+                1..=7,
+                None,
+            )
+            .damage(Roll::new(1, 10, 0), DamageType::Psychic)
+            .save(ability::Identifier::Intelligence)
+            .scaling(Scaling::dice(1, 10)))
+            .add_act(&Act::new(
+                "Phantom Foe",
+                "As an action, choose one creature you can see within 60 feet of you. The target must make an Intelligence saving throw. On a failed save, it perceives a horrid creature adjacent to it until your concentration ends. During this time, the target can’t take reactions, and it takes 1d8 psychic damage at the start of each of its turns. The target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. You can increase the damage by 1d8 for each additional psi point spent on the ability.",
+                3..=7,
+                Some(Duration::Minutes(1)),
+            )
+            .damage(Roll::new(1, 8, 0), DamageType::Psychic)
+            .save(ability::Identifier::Intelligence)
+            .scaling(Scaling::dice(1, 8)))
+            .try_into()?;
+
+        let mystic = Mystic::new(12)
+            .add_talent(Talent::new(
+                "Mind Meld",
+                "As a bonus action, you can communicate telepathically with one creature you can see within 120 feet of you.",
+            ))
+            .add_discipline(psychic_phantoms);
 
         let senses = senses::Builder::new().darkvision(60).build();
 
@@ -84,7 +393,22 @@ impl App {
             .hair_color("Silver")
             .skin_tone("Fair")
             .age(Duration::Years(23))
-            .weight(Weight::Pounds(142))
+            .weight(Weight::Pounds(142.0))
+            .try_into()?;
+
+        let background: background::Background = background::Builder::new()
+            .name("Urban Bounty Hunter")
+            .description("You did bounty hunter stuff in an urban setting.")
+            .feature(background::Feature::new(
+                "Ear to the Ground",
+                "You are in frequent contact with people in the segment of society that your chosen quarries move through. These people might be associated with the criminal underworld, the rough-and-tumble folk of the streets, or members of high society. This connection comes in the form of a contact in any city you visit, a person who provides information about the people and places of the local area.",
+            ))
+            .proficiencies(background::Proficiencies::two_skills_two_tools(
+                skills::Identifier::Deception,
+                skills::Identifier::Persuasion,
+                "Dice Set",
+                "Playing Card Set",
+            ))
             .try_into()?;
 
         let mut character = character::Builder::new()
@@ -92,6 +416,7 @@ impl App {
             .characteristics(characteristics)?
             .personality(personality)?
             .race(race)?
+            .background(background)?
             .base_ability_scores(Abilities::from(AbilitiesTemplate {
                 strength: 10,
                 dexterity: 15,
@@ -106,49 +431,184 @@ impl App {
             .add_skill_proficiency(skills::Identifier::Investigation)?
             .add_skill_proficiency(skills::Identifier::Perception)?
             .add_skill_proficiency(skills::Identifier::Stealth)?
-            .add_equipment_slot("armor", Slot::new(|item| item.has_type("armor")))?
-            .add_equipment_slot("cloak", Slot::new(|item| item.has_type("cloak")))?
-            .add_equipment_slot("left hand", Slot::new(|item| item.has_type("hand")))?
+            .slot_layout(SlotLayout::armorer())?
             .senses(senses)?
-            .add_armor_proficiency("Heavy Armor")?
-            .add_armor_proficiency("Light Armor")?
-            .add_armor_proficiency("Medium Armor")?
-            .add_armor_proficiency("Shields")?
+            .psionics(mystic)?
+            .add_armor_proficiency(ArmorCategory::Light)?
+            .add_armor_proficiency(ArmorCategory::Medium)?
+            .add_armor_proficiency(ArmorCategory::Heavy)?
+            .add_armor_proficiency(ArmorCategory::Shield)?
             .add_weapon_proficiency("Firearms")?
             .add_weapon_proficiency("Rapier")?
             .add_weapon_proficiency("Simple Weapons")?
             .add_weapon_proficiency("Whip")?
-            .add_tool_proficiency("Alchemist's Supplies")?
-            .add_tool_proficiency("Playing Card Set")?
-            .add_tool_proficiency("Smith's Tools")?
-            .add_tool_proficiency("Thieves' Tools")?
-            .add_tool_proficiency("Three-Dragon Ante Set")?
-            .add_tool_proficiency("Tinker's Tools")?
+            .add_tool_proficiency(Tool::named("Alchemist's Supplies"))?
+            .add_tool_proficiency(Tool::named("Playing Card Set"))?
+            .add_tool_proficiency(Tool::named("Smith's Tools"))?
+            .add_tool_proficiency(Tool::named("Thieves' Tools"))?
+            .add_tool_proficiency(Tool::named("Three-Dragon Ante Set"))?
+            .add_tool_expertise(Tool::named("Tinker's Tools"))?
             .build()?;
 
         let mithral_plate = item::Builder::new()
             .name("Mithral Plate")?
-            .weight(65)?
+            .weight(65.0)?
             .armor_class(ArmorClass::Heavy(18))?
             .add_type("armor")?
             .build()?;
-        character.equip_item(mithral_plate, "armor")?;
+        let arcane_armor = ArcaneArmor::new(mithral_plate)?;
+
+        for (piece, item) in Piece::ALL.into_iter().zip(arcane_armor.get_pieces()?) {
+            character.equip_item(item, piece.get_slot())?;
+        }
+
+        character.learn_infusion(Infusion::enhanced_defense())?;
+        character.infuse_item("Enhanced Defense", "Mithral Plate Chestplate")?;
+        character.finish_rest(Rest::Long);
 
         let cloak_of_protection = item::Builder::new()
             .name("Cloak of Protection")?
-            .armor_class(ArmorClass::Heavy(1))?
+            .armor_class_bonus(1)?
+            .add_effect(Target::SavingThrows, Modifier::Add(1))?
             .add_type("cloak")?
+            .requires_attunement()?
             .build()?;
         character.equip_item(cloak_of_protection, "cloak")?;
+        character.attune_item("Cloak of Protection")?;
 
         let shield = item::Builder::new()
             .name("Shield")?
-            .armor_class(ArmorClass::Heavy(2))?
-            .weight(6)?
-            .add_type("hand")?
+            .armor_class(ArmorClass::Shield(2))?
+            .weight(6.0)?
+            .cost(Price::new(10, Coin::Gold))?
             .build()?;
         character.equip_item(shield, "left hand")?;
 
+        let rapier = item::Builder::new()
+            .name("Rapier")?
+            .weight(2.0)?
+            .cost(Price::new(25, Coin::Gold))?
+            .weapon(
+                Weapon::new(
+                    WeaponCategory::Martial,
+                    Roll::new(1, 8, 0),
+                    DamageType::Piercing,
+                )
+                .add_property(WeaponProperty::Finesse)
+                .mastery(Mastery::Vex),
+            )?
+            .build()?;
+        character.equip_item(rapier, "right hand")?;
+
+        let quarterstaff = item::Builder::new()
+            .name("Quarterstaff")?
+            .weight(4.0)?
+            .cost(Price::new(2, Coin::Silver))?
+            .weapon(
+                Weapon::new(
+                    WeaponCategory::Simple,
+                    Roll::new(1, 6, 0),
+                    DamageType::Bludgeoning,
+                )
+                .add_property(WeaponProperty::Versatile(Roll::new(1, 8, 0)))
+                .mastery(Mastery::Topple),
+            )?
+            .build()?;
+        character.add_item(quarterstaff);
+
+        let amulet_of_health = item::Builder::new()
+            .name("Amulet of Health")?
+            .add_type("Wondrous Item")?
+            .add_type("amulet")?
+            .add_effect(
+                Target::AbilityScore(ability::Identifier::Constitution),
+                Modifier::Minimum(19),
+            )?
+            .requires_attunement()?
+            .build()?;
+        character.add_item(amulet_of_health);
+        character.attune_item("Amulet of Health")?;
+        character.equip_from_inventory("Amulet of Health")?;
+
+        let travelers_clothes = item::Builder::new()
+            .name("Clothes, Traveler's")?
+            .weight(4.0)?
+            .cost(Price::new(2, Coin::Gold))?
+            .add_type("Adventuring Gear")?
+            .build()?;
+        character.add_item(travelers_clothes);
+
+        let studded_leather = item::Builder::new()
+            .name("Studded Leather")?
+            .weight(13.0)?
+            .cost(Price::new(45, Coin::Gold))?
+            .armor_class(ArmorClass::Light(12))?
+            .add_type("armor")?
+            .build()?;
+        character.add_item(studded_leather);
+
+        let rations = item::Builder::new()
+            .name("Rations (1 day)")?
+            .weight(2.0)?
+            .cost(Price::new(5, Coin::Silver))?
+            .add_type("Adventuring Gear")?
+            .stackable()?
+            .quantity(5)?
+            .build()?;
+        character.add_item(rations);
+
+        let potion_of_healing = item::Builder::new()
+            .name("Potion of Healing")?
+            .weight(0.5)?
+            .cost(Price::new(50, Coin::Gold))?
+            .add_type("Potion")?
+            .stackable()?
+            .quantity(2)?
+            .build()?;
+        character.add_item(potion_of_healing);
+
+        let backpack = item::Builder::new()
+            .name("Backpack")?
+            .weight(5.0)?
+            .cost(Price::new(2, Coin::Gold))?
+            .add_type("Adventuring Gear")?
+            .container(
+                Container::new()
+                    .weight_capacity(30.0)
+                    .volume_capacity(Volume::CubicFeet(1.0)),
+            )?
+            .build()?;
+        character.add_item(backpack);
+        character.store_item("Rations (1 day)", "Backpack")?;
+
+        let bag_of_holding = item::Builder::new()
+            .name("Bag of Holding")?
+            .weight(15.0)?
+            .add_type("Wondrous Item")?
+            .container(
+                Container::new()
+                    .weight_capacity(500.0)
+                    .volume_capacity(Volume::CubicFeet(64.0))
+                    .extradimensional(),
+            )?
+            .build()?;
+        character.add_item(bag_of_holding);
+
+        let mut riding_horse = Mount::new("Riding Horse", Some(480.0));
+        riding_horse.add_item(
+            item::Builder::new()
+                .name("Tent, two-person")?
+                .weight(20.0)?
+                .cost(Price::new(2, Coin::Gold))?
+                .add_type("Adventuring Gear")?
+                .build()?,
+        );
+        character.add_mount(riding_horse);
+
+        character.add_coins(Coin::Gold, 37);
+        character.add_coins(Coin::Silver, 8);
+        character.add_coins(Coin::Copper, 14);
+
         self.character = Some(character);
 
         Ok(())
@@ -161,9 +621,211 @@ impl App {
         app.create_character()
             .unwrap_or_else(|err| panic!("Failed to create Character: {err}"));
 
+        if let Err(err) = app.load_session() {
+            app.roll_log_state.message = Some(format!("Couldn't load the last session: {err}"));
+        }
+
         app
     }
 
+    /// Directory the session is kept in: `$XDG_STATE_HOME/cygnus`, falling back to
+    /// `~/.local/state/cygnus`.
+    fn get_session_dir() -> Option<PathBuf> {
+        env::var_os("XDG_STATE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state")))
+            .map(|dir| dir.join("cygnus"))
+    }
+
+    /// Restores the roll log and slot layout saved by the last session, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// If a session file can't be read or parsed.
+    ///
+    pub fn load_session(&mut self) -> AppResult<()> {
+        let Some(dir) = Self::get_session_dir() else {
+            return Ok(());
+        };
+
+        let roll_log_path = dir.join(ROLL_LOG_SESSION_FILE);
+
+        if roll_log_path.exists() {
+            self.roll_log = RollLog::from_session(&fs::read_to_string(roll_log_path)?)?;
+        }
+
+        let layout_path = dir.join(SLOT_LAYOUT_SESSION_FILE);
+
+        if layout_path.exists() {
+            self.restore_slot_layout(fs::read_to_string(layout_path)?.parse()?);
+        }
+
+        Ok(())
+    }
+
+    /// Switches the character to `layout`, equipping again whatever still fits.
+    fn restore_slot_layout(&mut self, layout: SlotLayout) {
+        let Some(character) = self.character.as_mut() else {
+            return;
+        };
+
+        if *character.get_slot_layout() == layout {
+            return;
+        }
+
+        let equipped: Vec<String> = character
+            .get_equipment()
+            .get_equipped_items()
+            .iter()
+            .map(|item| item.get_name().to_owned())
+            .collect();
+
+        character.change_slot_layout(layout);
+
+        for name in equipped {
+            // Anything the new layout has no room for stays in the inventory.
+            let _ = character.equip_from_inventory(&name);
+        }
+    }
+
+    /// Saves the roll log and slot layout so the next session picks up where this one left off.
+    ///
+    /// # Errors
+    ///
+    /// If a session file can't be written.
+    ///
+    pub fn save_session(&self) -> AppResult<()> {
+        let Some(dir) = Self::get_session_dir() else {
+            return Ok(());
+        };
+
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(ROLL_LOG_SESSION_FILE), self.roll_log.to_session())?;
+
+        if let Some(character) = &self.character {
+            fs::write(
+                dir.join(SLOT_LAYOUT_SESSION_FILE),
+                character.get_slot_layout().to_string(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs())
+    }
+
+    /// Adds a roll to the log and scrolls back to the newest entry.
+    fn log_roll(&mut self, entry: RollEntry) {
+        self.roll_log.push(entry);
+        self.roll_log_state.scroll = 0;
+        self.roll_log_state.message = None;
+    }
+
+    pub fn roll_log_older(&mut self) {
+        let len = self.roll_log.len();
+        let state = &mut self.roll_log_state;
+
+        state.scroll = (state.scroll + 1).min(len.saturating_sub(1));
+    }
+
+    pub fn roll_log_newer(&mut self) {
+        self.roll_log_state.scroll = self.roll_log_state.scroll.saturating_sub(1);
+    }
+
+    /// Writes the roll log as plain text, ready to paste into a chat.
+    pub fn export_roll_log(&mut self) {
+        let Some(dir) = Self::get_session_dir() else {
+            self.roll_log_state.message = Some(String::from(
+                "Couldn't export the roll log: neither XDG_STATE_HOME nor HOME is set",
+            ));
+
+            return;
+        };
+        let path = dir.join(ROLL_LOG_EXPORT_FILE);
+
+        let result =
+            fs::create_dir_all(&dir).and_then(|()| fs::write(&path, self.roll_log.to_plain_text()));
+
+        self.roll_log_state.message = Some(match result {
+            Ok(()) => format!("Exported to {}", path.display()),
+            Err(err) => format!("Couldn't export the roll log: {err}"),
+        });
+    }
+
+    /// The page selected in the nav menu.
+    #[must_use]
+    pub fn get_page(&self) -> PageLink {
+        PageLink::from(self.nav_menu_state.selected)
+    }
+
+    #[must_use]
+    pub fn is_on_skills_page(&self) -> bool {
+        !self.nav_menu_state.is_open && self.get_page() == PageLink::Skills
+    }
+
+    pub fn skills_down(&mut self) {
+        let count = skills::Identifier::all().len();
+        let state = &mut self.skills_state;
+
+        state.select(Some(state.selected().map_or(0, |i| (i + 1) % count)));
+    }
+
+    pub fn skills_up(&mut self) {
+        let count = skills::Identifier::all().len();
+        let state = &mut self.skills_state;
+
+        state.select(Some(
+            state
+                .selected()
+                .map_or(count - 1, |i| (i + count - 1) % count),
+        ));
+    }
+
+    pub fn roll_selected_skill(&mut self) {
+        let Some(skill) = self
+            .skills_state
+            .selected()
+            .and_then(|i| skills::Identifier::all().get(i).copied())
+        else {
+            return;
+        };
+        let Some(character) = self.character.as_ref() else {
+            return;
+        };
+
+        let check = character.roll_check(CheckKind::Skill(skill), &mut rand::thread_rng());
+
+        self.log_roll(RollEntry::from_check(Self::now(), &check));
+    }
+
+    /// Rolls damage for the selected weapon, with the grip it is currently held in.
+    pub fn roll_selected_damage(&mut self) {
+        let Some((name, location)) = self.get_selected_inventory_entry() else {
+            return;
+        };
+        let Some(entry) = self.character.as_ref().and_then(|character| {
+            get_inventory_entries(character, self.inventory_state.sort)
+                .into_iter()
+                .find(|entry| entry.item.get_name() == name && entry.location == location)
+        }) else {
+            return;
+        };
+        let Some(weapon) = entry.item.get_weapon() else {
+            self.inventory_state.message = Some(format!("{name} is not a weapon."));
+
+            return;
+        };
+
+        let rolled = weapon.get_wielded_damage().roll(&mut rand::thread_rng());
+        let source = format!("{name} damage ({})", weapon.get_damage_type());
+
+        self.log_roll(RollEntry::from_rolled(Self::now(), source, &rolled));
+    }
+
     /// Handles the tick event of the terminal.
     pub fn tick(&self) {}
 
@@ -182,6 +844,284 @@ impl App {
         }
     }
 
+    #[must_use]
+    pub fn is_on_inventory_page(&self) -> bool {
+        !self.nav_menu_state.is_open && self.get_page() == PageLink::Inventory
+    }
+
+    fn count_inventory_entries(&self) -> usize {
+        self.character.as_ref().map_or(0, |character| {
+            get_inventory_entries(character, self.inventory_state.sort).len()
+        })
+    }
+
+    pub fn inventory_down(&mut self) {
+        let count = self.count_inventory_entries();
+        let state = &mut self.inventory_state.table_state;
+
+        state.select((count > 0).then(|| state.selected().map_or(0, |i| (i + 1) % count)));
+    }
+
+    pub fn inventory_up(&mut self) {
+        let count = self.count_inventory_entries();
+        let state = &mut self.inventory_state.table_state;
+
+        state.select((count > 0).then(|| {
+            state
+                .selected()
+                .map_or(count - 1, |i| (i + count - 1) % count)
+        }));
+    }
+
+    pub fn cycle_inventory_sort(&mut self) {
+        self.inventory_state.sort = self.inventory_state.sort.next();
+    }
+
+    /// The name and location of the highlighted inventory row.
+    fn get_selected_inventory_entry(&self) -> Option<(String, Location)> {
+        let character = self.character.as_ref()?;
+        let selected = self.inventory_state.table_state.selected()?;
+
+        get_inventory_entries(character, self.inventory_state.sort)
+            .into_iter()
+            .nth(selected)
+            .map(|entry| (entry.item.get_name().to_owned(), entry.location))
+    }
+
+    fn run_inventory_action(
+        &mut self,
+        action: impl FnOnce(&mut Character, &str, &Location) -> Result<String, character::Error>,
+    ) {
+        let Some((name, location)) = self.get_selected_inventory_entry() else {
+            return;
+        };
+        let Some(character) = self.character.as_mut() else {
+            return;
+        };
+
+        self.inventory_state.message =
+            Some(action(character, &name, &location).unwrap_or_else(|err| err.to_string()));
+
+        let count = self.count_inventory_entries();
+        let state = &mut self.inventory_state.table_state;
+        if state.selected().is_some_and(|i| i >= count) {
+            state.select(count.checked_sub(1));
+        }
+    }
+
+    pub fn equip_selected_item(&mut self) {
+        self.run_inventory_action(|character, name, location| match location {
+            Location::Carried => character
+                .equip_from_inventory(name)
+                .map(|()| format!("Equipped {name}.")),
+            Location::Equipped(_) => Ok(format!("{name} is already equipped.")),
+            Location::Container(_) | Location::Mount(_) => {
+                Ok(format!("Take {name} out before equipping it."))
+            }
+        });
+    }
+
+    pub fn unequip_selected_item(&mut self) {
+        self.run_inventory_action(|character, name, location| match location {
+            Location::Equipped(slot) => character
+                .unequip_to_inventory(slot)
+                .map(|()| format!("Unequipped {name}.")),
+            _ => Ok(format!("{name} is not equipped.")),
+        });
+    }
+
+    /// Switches the selected versatile weapon between one and two hands.
+    pub fn toggle_selected_grip(&mut self) {
+        self.run_inventory_action(|character, name, location| {
+            let Location::Equipped(slot) = location else {
+                return Ok(format!("Equip {name} before changing its grip."));
+            };
+
+            let grip = match character
+                .get_equipment()
+                .get_item_in(slot)
+                .and_then(Item::get_weapon)
+                .map(Weapon::get_grip)
+            {
+                Some(Grip::OneHanded) => Grip::TwoHanded,
+                _ => Grip::OneHanded,
+            };
+
+            character
+                .set_grip(slot, grip)
+                .map(|()| format!("Holding {name} {}.", grip.to_string().to_lowercase()))
+        });
+    }
+
+    pub fn drop_selected_item(&mut self) {
+        self.run_inventory_action(|character, name, location| {
+            match location {
+                Location::Container(container) => character.drop_from_container(name, container),
+                Location::Mount(mount) => character.drop_from_mount(name, mount),
+                Location::Equipped(slot) => character.drop_equipped(slot),
+                Location::Carried => character.drop_item(name),
+            }
+            .map(|item| format!("Dropped {}.", item.get_name()))
+        });
+    }
+
+    /// Cycles the selected item from loose in the inventory, through each container with room
+    /// for it, and back out again. Items on a mount are unloaded into the inventory.
+    pub fn move_selected_item(&mut self) {
+        self.run_inventory_action(|character, name, location| {
+            let containers: Vec<String> = character
+                .get_containers()
+                .iter()
+                .map(|container| container.get_name().to_owned())
+                .filter(|container| container != name)
+                .collect();
+
+            let remaining = match location {
+                Location::Equipped(_) => return Ok(format!("Unequip {name} before moving it.")),
+                Location::Mount(mount) => {
+                    character.unload_from_mount(name, mount)?;
+
+                    return Ok(format!("Unloaded {name} from {mount}."));
+                }
+                Location::Carried => &containers[..],
+                Location::Container(current) => {
+                    let next = containers
+                        .iter()
+                        .position(|container| container == current)
+                        .map_or(containers.len(), |i| i + 1);
+
+                    &containers[next..]
+                }
+            };
+
+            for container in remaining {
+                let stored = match location {
+                    Location::Container(current) => {
+                        character.transfer_item(name, current, container)
+                    }
+                    _ => character.store_item(name, container),
+                };
+
+                if stored.is_ok() {
+                    return Ok(format!("Stored {name} in {container}."));
+                }
+            }
+
+            Ok(match location {
+                Location::Container(current) => {
+                    character.retrieve_item(name, current)?;
+
+                    format!("Took {name} out of {current}.")
+                }
+                _ => format!("No container has room for {name}."),
+            })
+        });
+    }
+
+    #[must_use]
+    pub fn is_on_actions_page(&self) -> bool {
+        !self.nav_menu_state.is_open && self.get_page() == PageLink::Actions
+    }
+
+    fn count_acts(&self) -> usize {
+        self.character
+            .as_ref()
+            .map_or(0, |character| get_acts(character).len())
+    }
+
+    pub fn actions_down(&mut self) {
+        let count = self.count_acts();
+        let state = &mut self.actions_state;
+
+        state.list_state.select(
+            (count > 0).then(|| state.list_state.selected().map_or(0, |i| (i + 1) % count)),
+        );
+        state.charge = 0;
+    }
+
+    pub fn actions_up(&mut self) {
+        let count = self.count_acts();
+        let state = &mut self.actions_state;
+
+        state.list_state.select((count > 0).then(|| {
+            state
+                .list_state
+                .selected()
+                .map_or(count - 1, |i| (i + count - 1) % count)
+        }));
+        state.charge = 0;
+    }
+
+    pub fn charge_more(&mut self) {
+        self.actions_state.charge += 1;
+    }
+
+    pub fn charge_less(&mut self) {
+        self.actions_state.charge = self.actions_state.charge.saturating_sub(1);
+    }
+
+    /// The names of the highlighted act and its discipline.
+    fn get_selected_act(&self) -> Option<(String, String)> {
+        let character = self.character.as_ref()?;
+        let selected = self.actions_state.list_state.selected()?;
+
+        get_acts(character)
+            .into_iter()
+            .nth(selected)
+            .map(|(discipline, act)| (discipline.get_name().to_owned(), act.get_name().to_owned()))
+    }
+
+    /// Performs the selected act with the chosen charge, spending its psi points and rolling
+    /// its damage.
+    pub fn perform_selected_act(&mut self) {
+        let Some((_, act)) = self.get_selected_act() else {
+            return;
+        };
+        let Some(character) = self.character.as_mut() else {
+            return;
+        };
+        let charge = self.actions_state.charge;
+
+        match character.perform_act(&act, charge) {
+            Ok(resolved) => {
+                self.actions_state.charge = 0;
+                self.actions_state.message = Some(format!("Performed {resolved}."));
+
+                if let Some((roll, damage_type)) = resolved.get_damage() {
+                    let rolled = roll.roll(&mut rand::thread_rng());
+                    let source = format!("{act} damage ({damage_type})");
+
+                    self.log_roll(RollEntry::from_rolled(Self::now(), source, &rolled));
+                }
+            }
+            Err(err) => self.actions_state.message = Some(err.to_string()),
+        }
+    }
+
+    /// Switches the psychic focus to the selected act's discipline.
+    pub fn focus_selected_discipline(&mut self) {
+        let Some((discipline, _)) = self.get_selected_act() else {
+            return;
+        };
+        let Some(character) = self.character.as_mut() else {
+            return;
+        };
+
+        self.actions_state.message = Some(character.focus_on(&discipline).map_or_else(
+            |err| err.to_string(),
+            |()| format!("Focused on {discipline}."),
+        ));
+    }
+
+    pub fn finish_long_rest(&mut self) {
+        let Some(character) = self.character.as_mut() else {
+            return;
+        };
+
+        character.finish_rest(Rest::Long);
+        self.actions_state.message = Some(String::from("Finished a long rest."));
+    }
+
     pub fn nav_up(&mut self) {
         if let Some(res) = self
             .nav_menu_state
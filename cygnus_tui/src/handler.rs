@@ -9,10 +9,78 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
             app.quit();
         }
         // Exit application on `Ctrl-C`
-        KeyCode::Char('c') | KeyCode::Char('C') => {
-            if key_event.modifiers == KeyModifiers::CONTROL {
-                app.quit();
-            }
+        KeyCode::Char('c' | 'C') if key_event.modifiers == KeyModifiers::CONTROL => {
+            app.quit();
+        }
+        // Control Inventory
+        KeyCode::Char('j') | KeyCode::Down if app.is_on_inventory_page() => {
+            app.inventory_down();
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.is_on_inventory_page() => {
+            app.inventory_up();
+        }
+        KeyCode::Char('e') if app.is_on_inventory_page() => {
+            app.equip_selected_item();
+        }
+        KeyCode::Char('u') if app.is_on_inventory_page() => {
+            app.unequip_selected_item();
+        }
+        KeyCode::Char('d') if app.is_on_inventory_page() => {
+            app.drop_selected_item();
+        }
+        KeyCode::Char('s') if app.is_on_inventory_page() => {
+            app.cycle_inventory_sort();
+        }
+        KeyCode::Char('m') if app.is_on_inventory_page() => {
+            app.move_selected_item();
+        }
+        KeyCode::Char('g') if app.is_on_inventory_page() => {
+            app.toggle_selected_grip();
+        }
+        KeyCode::Char('r') if app.is_on_inventory_page() => {
+            app.roll_selected_damage();
+        }
+        // Control Actions
+        KeyCode::Char('j') | KeyCode::Down if app.is_on_actions_page() => {
+            app.actions_down();
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.is_on_actions_page() => {
+            app.actions_up();
+        }
+        KeyCode::Char('l' | '+') | KeyCode::Right if app.is_on_actions_page() => {
+            app.charge_more();
+        }
+        KeyCode::Char('h' | '-') | KeyCode::Left if app.is_on_actions_page() => {
+            app.charge_less();
+        }
+        KeyCode::Char('p') if app.is_on_actions_page() => {
+            app.perform_selected_act();
+        }
+        KeyCode::Char('f') if app.is_on_actions_page() => {
+            app.focus_selected_discipline();
+        }
+        KeyCode::Char('L') if app.is_on_actions_page() => {
+            app.finish_long_rest();
+        }
+        // Control Skills
+        KeyCode::Char('j') | KeyCode::Down if app.is_on_skills_page() => {
+            app.skills_down();
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.is_on_skills_page() => {
+            app.skills_up();
+        }
+        KeyCode::Char('r') if app.is_on_skills_page() => {
+            app.roll_selected_skill();
+        }
+        // Control Roll Log
+        KeyCode::PageUp => {
+            app.roll_log_older();
+        }
+        KeyCode::PageDown => {
+            app.roll_log_newer();
+        }
+        KeyCode::Char('x') => {
+            app.export_roll_log();
         }
         // Control Nav Menu
         KeyCode::Char('j') | KeyCode::Down => {
@@ -31,5 +31,8 @@ fn main() -> AppResult<()> {
 
     // Exit the user interface.
     tui.exit()?;
+
+    // Keep the roll log and slot layout for the next session.
+    app.save_session()?;
     Ok(())
 }
@@ -1,23 +1,26 @@
 #![warn(clippy::pedantic)]
 
 use cygnus_models::{
-    ability, background,
+    ability,
     character::Character,
+    item::MAX_ATTUNED_ITEMS,
     modifiers::Proficiency,
-    psionics::discipline::{self, Act, Discipline},
+    psionics::{discipline::Act, Mystic, Psionics, Talent},
+    roll_log::RollLog,
     skills,
-    units::Duration,
 };
 use ratatui::{
     prelude::*,
     widgets::{
         block::{Block, BorderType, Position, Title},
-        Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, Wrap,
+        Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, TableState, Wrap,
     },
 };
 
 use crate::{
-    app::App,
+    app::{
+        get_acts, get_inventory_entries, ActionsState, App, InventoryState, Location, RollLogState,
+    },
     widgets::{
         AbilitiesWidget, BackgroundWidget, CharacteristicsWidget, DisciplineWidget,
         PersonalityWidget,
@@ -132,9 +135,12 @@ fn render_saving_throw_pair(
     );
 
     let saving_throw_mod = character.get_saving_throw_mod(ability);
+    let advantage = character
+        .get_saving_throw_advantage(ability)
+        .map_or(String::new(), |advantage| format!(" {advantage}"));
 
     frame.render_widget(
-        Paragraph::new(format!("{saving_throw_mod:+}")).alignment(Alignment::Center),
+        Paragraph::new(format!("{saving_throw_mod:+}{advantage}")).alignment(Alignment::Center),
         saving_throw_pair_layout[1],
     );
 }
@@ -202,6 +208,47 @@ fn render_armor_class(frame: &mut Frame, character: &Character, rect: Rect) {
     frame.render_widget(armor_class, rect);
 }
 
+fn render_armor_class_breakdown_block(frame: &mut Frame, character: &Character, area: Rect) {
+    let mut lines = vec![text::Line::from(
+        character.get_armor_class_breakdown().to_string(),
+    )];
+
+    let untrained_armor = character.get_untrained_armor();
+    if !untrained_armor.is_empty() {
+        let names = untrained_armor
+            .iter()
+            .map(|item| item.get_name())
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(text::Line::styled(
+            format!("Untrained in {names}: disadvantage on Str/Dex rolls, can't cast spells"),
+            Style::default().red(),
+        ));
+    }
+
+    if character.is_below_armor_strength_requirement() {
+        lines.push(text::Line::styled(
+            "Below armor Strength requirement: -10 ft. speed",
+            Style::default().red(),
+        ));
+    }
+
+    let breakdown = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(
+                    Title::from("Armor Class")
+                        .alignment(Alignment::Center)
+                        .position(Position::Bottom),
+                ),
+        )
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(breakdown, area);
+}
+
 fn render_initiative(frame: &mut Frame, character: &Character, rect: Rect) {
     let initiative = Paragraph::new(format!("{:+}", character.get_initiative()))
         .block(
@@ -263,8 +310,13 @@ fn render_walking_speed(frame: &mut Frame, character: &Character, area: Rect) {
     frame.render_widget(walking_speed, area);
 }
 
-fn render_skills_table(frame: &mut Frame, character: &Character, area: Rect) {
-    let header_cells = ["Prof", "Mod", "Skill", "Bonus"]
+fn render_skills_table(
+    frame: &mut Frame,
+    character: &Character,
+    state: &mut TableState,
+    area: Rect,
+) {
+    let header_cells = ["Prof", "Mod", "Skill", "Bonus", "Adv"]
         .iter()
         .map(|&h| Cell::from(h));
     let header = Row::new(header_cells).height(1).bottom_margin(1);
@@ -279,10 +331,15 @@ fn render_skills_table(frame: &mut Frame, character: &Character, area: Rect) {
             Cell::from(id.get_default_ability().abbr().to_string()),
             Cell::from(format!("{id}")),
             Cell::from(format!("{:+}", character.get_skill_modifier(id))),
+            Cell::from(
+                character
+                    .get_skill_advantage(id)
+                    .map_or(String::new(), |advantage| advantage.to_string()),
+            ),
         ];
         Row::new(cells)
     });
-    let table = Table::new(rows, [Constraint::Ratio(1, 4); 4].as_ref())
+    let table = Table::new(rows, [Constraint::Ratio(1, 5); 5].as_ref())
         .header(header)
         .block(
             Block::default()
@@ -292,10 +349,73 @@ fn render_skills_table(frame: &mut Frame, character: &Character, area: Rect) {
                     Title::from("Skills")
                         .alignment(Alignment::Center)
                         .position(Position::Bottom),
+                )
+                .title(
+                    Title::from("r: roll")
+                        .alignment(Alignment::Right)
+                        .position(Position::Bottom),
                 ),
+        )
+        .highlight_style(Style::default().reversed());
+
+    frame.render_stateful_widget(table, area, state);
+}
+
+fn render_roll_log(frame: &mut Frame, roll_log: &RollLog, state: &RollLogState, area: Rect) {
+    let block = Block::new()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(
+            Title::from("Roll Log")
+                .alignment(Alignment::Center)
+                .position(Position::Top),
+        )
+        .title(
+            Title::from(state.message.clone().unwrap_or_default())
+                .alignment(Alignment::Left)
+                .position(Position::Bottom),
+        )
+        .title(
+            Title::from("PgUp/PgDn: scroll | x: export")
+                .alignment(Alignment::Right)
+                .position(Position::Bottom),
         );
 
-    frame.render_widget(table, area);
+    let visible = usize::from(block.inner(area).height);
+    let end = roll_log.len().saturating_sub(state.scroll);
+    let start = end.saturating_sub(visible);
+
+    let lines: Vec<Line> = roll_log.get_entries()[start..end]
+        .iter()
+        .map(|entry| {
+            let mut spans = vec![
+                Span::from(entry.get_time_of_day()).dim(),
+                Span::from(" "),
+                Span::from(entry.get_source().to_owned()).bold(),
+                Span::from(format!(": {}", entry.fmt_faces())),
+            ];
+
+            if !entry.get_modifiers().is_empty() {
+                spans.push(Span::from(format!(" {}", entry.fmt_modifiers())));
+            }
+
+            spans.push(Span::from(format!(" = {}", entry.get_total())).bold());
+
+            for note in entry.get_notes() {
+                let span = Span::from(format!(" ({note})")).italic();
+
+                spans.push(match note.as_str() {
+                    "natural 20" => span.green(),
+                    "natural 1" | "automatic failure" => span.red(),
+                    _ => span,
+                });
+            }
+
+            Line::from(spans)
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
 }
 
 fn render_second_row(frame: &mut Frame, character: &Character, area: Rect) {
@@ -434,68 +554,65 @@ fn render_proficiencies_and_languages_block(frame: &mut Frame, character: &Chara
     );
 }
 
-fn render_inventory_table(frame: &mut Frame, _character: &Character, area: Rect) {
+fn render_inventory_table(
+    frame: &mut Frame,
+    character: &Character,
+    state: &mut InventoryState,
+    area: Rect,
+) {
     let header_cells = [
-        "Equipped",
-        "Attuned",
-        "Name",
-        "Types",
-        "Weight",
-        "Qty",
-        "Cost (GP)",
+        "Location", "Attuned", "Name", "Types", "Weight", "Qty", "Cost",
     ]
     .iter()
     .map(|&h| Cell::from(h));
     let header = Row::new(header_cells).height(1).bottom_margin(1);
 
-    let rows = [
-        Row::new([
-            Cell::from("*"),
-            Cell::from("*"),
-            Cell::from("Amulet of Health"),
-            Cell::from("Wonderous Item"),
-            Cell::from("--"),
-            Cell::from("--"),
-            Cell::from("--"),
-        ]),
-        Row::new([
-            Cell::from("*"),
-            Cell::from("*"),
-            Cell::from("Cloak of Protection"),
-            Cell::from("Wonderous Item"),
-            Cell::from("--"),
-            Cell::from("--"),
-            Cell::from("--"),
-        ]),
-        Row::new([
-            Cell::from("-"),
-            Cell::from("-"),
-            Cell::from("Clothes, Traveler's"),
-            Cell::from("Gear, Adventuring Gear"),
-            Cell::from("4 lb."),
-            Cell::from("1"),
-            Cell::from("2"),
-        ]),
-        Row::new([
-            Cell::from("*"),
-            Cell::from("-"),
-            Cell::from("Studded Leather"),
-            Cell::from("Light Armor, Studded Leather"),
-            Cell::from("13 lb."),
-            Cell::from("--"),
-            Cell::from("45"),
-        ]),
-    ];
+    let rows = get_inventory_entries(character, state.sort)
+        .into_iter()
+        .map(|entry| {
+            let item = entry.item;
+
+            let location = match &entry.location {
+                Location::Equipped(slot) => character
+                    .get_equipment()
+                    .get_occupied_slots(slot)
+                    .join(" + "),
+                location => location.to_string(),
+            };
+
+            Row::new([
+                Cell::from(location),
+                Cell::from(if item.is_attuned() {
+                    "*"
+                } else if item.requires_attunement() {
+                    "o"
+                } else {
+                    "-"
+                }),
+                Cell::from(item.get_name().to_owned()),
+                Cell::from(item.get_types().join(", ")),
+                Cell::from(match item.get_total_weight() {
+                    0.0 => String::from("--"),
+                    weight => format!("{weight} lb."),
+                }),
+                Cell::from(item.get_quantity().to_string()),
+                Cell::from(
+                    item.get_cost()
+                        .map_or(String::from("--"), |cost| cost.to_string()),
+                ),
+            ])
+        })
+        .collect::<Vec<_>>();
 
     let table = Table::new(
         rows,
         [
-            Constraint::Max(8),
+            Constraint::Max(14),
             Constraint::Max(7),
             Constraint::Ratio(1, 4),
             Constraint::Ratio(1, 4),
-            Constraint::Max(6),
-            Constraint::Max(3),
+            Constraint::Max(7),
+            Constraint::Max(4),
             Constraint::Max(9),
         ]
         .as_ref(),
@@ -508,12 +625,225 @@ fn render_inventory_table(frame: &mut Frame, _character: &Character, area: Rect)
                     .alignment(Alignment::Center)
                     .position(Position::Top),
             )
+            .title(
+                Title::from(character.get_purse().to_string())
+                    .alignment(Alignment::Right)
+                    .position(Position::Top),
+            )
+            .title(
+                Title::from(state.message.clone().unwrap_or_default())
+                    .alignment(Alignment::Left)
+                    .position(Position::Bottom),
+            )
+            .title(
+                Title::from(format!(
+                    "Sort: {} | Attuned {}/{MAX_ATTUNED_ITEMS}",
+                    state.sort,
+                    character.get_attuned_items().len()
+                ))
+                .alignment(Alignment::Right)
+                .position(Position::Bottom),
+            )
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded),
     )
-    .column_spacing(1);
+    .column_spacing(1)
+    .highlight_style(Style::default().reversed());
 
-    frame.render_widget(table, area);
+    frame.render_stateful_widget(table, area, &mut state.table_state);
+}
+
+fn render_features_and_traits_page(frame: &mut Frame, character: &Character, area: Rect) {
+    let mut lines = vec![];
+
+    for (class, feature) in character.get_features() {
+        let mut spans = vec![
+            Span::from(feature.get_name().to_owned()).bold(),
+            Span::from(format!(" ({} {})", class.get_name(), feature.get_level())).dim(),
+        ];
+
+        if let (Some(uses), Some(maximum), Some(remaining)) = (
+            feature.get_uses(),
+            character.get_feature_maximum_uses(class, feature),
+            character.get_feature_remaining_uses(class, feature),
+        ) {
+            spans.push(Span::from(format!(
+                " {remaining}/{maximum} per {}",
+                uses.get_recharge()
+            )));
+        }
+
+        lines.push(Line::from(spans));
+        lines.push(Line::from(feature.get_description().to_owned()));
+        lines.push(Line::default());
+    }
+
+    for (source, feat) in character.get_feats() {
+        lines.push(Line::from(vec![
+            Span::from(feat.get_name().to_owned()).bold(),
+            Span::from(format!(" ({source})")).dim(),
+        ]));
+        lines.push(Line::from(feat.get_description().to_owned()));
+        lines.push(Line::default());
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::new()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .title(Title::from("Features & Traits").alignment(Alignment::Center)),
+            )
+            .wrap(Wrap { trim: true }),
+        area,
+    );
+}
+
+/// The charged cost of `act` and what it leaves, as previewed before performing it.
+fn get_act_cost(character: &Character, act: &Act, charge: usize) -> String {
+    match character.preview_act(act.get_name(), charge) {
+        Ok(preview) if preview.exceeds_psi_limit() => {
+            format!("{} (over psi limit)", preview.get_total_cost())
+        }
+        Ok(preview) => match preview.get_remaining() {
+            Some(remaining) => format!("{}, {remaining} left", preview.get_total_cost()),
+            None => format!("{} (not enough psi points)", preview.get_total_cost()),
+        },
+        Err(err) => format!("-- ({err})"),
+    }
+}
+
+/// Psi points, psi limit, focus, charge and concentration on one line.
+fn get_psionics_status<'a>(
+    character: &Character,
+    mystic: &Mystic,
+    charge: usize,
+    cost: Option<String>,
+) -> Line<'a> {
+    Line::from(vec![
+        Span::from("Psi Points ").bold(),
+        Span::from(format!(
+            "{}/{}",
+            mystic.get_psi_points(),
+            mystic.get_psi_point_max()
+        )),
+        Span::from(" | Psi Limit ").bold(),
+        Span::from(mystic.get_psi_limit().to_string()),
+        Span::from(" | Focus ").bold(),
+        Span::from(
+            mystic
+                .get_focus()
+                .map_or(String::from("--"), |focus| focus.get_name().to_owned()),
+        ),
+        Span::from(" | Charge ").bold(),
+        Span::from(format!("+{charge}")),
+        Span::from(" | Cost ").bold(),
+        Span::from(cost.unwrap_or_else(|| String::from("--"))),
+        Span::from(" | Concentrating ").bold(),
+        Span::from(
+            character
+                .get_concentration()
+                .map_or(String::from("--"), |concentration| {
+                    format!(
+                        "{} ({})",
+                        concentration.get_source(),
+                        concentration.get_duration()
+                    )
+                }),
+        ),
+    ])
+}
+
+fn render_actions_page(
+    frame: &mut Frame,
+    character: &Character,
+    state: &mut ActionsState,
+    area: Rect,
+) {
+    let block = Block::new()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Title::from("Psionics").alignment(Alignment::Center))
+        .title(
+            Title::from(state.message.clone().unwrap_or_default())
+                .alignment(Alignment::Left)
+                .position(Position::Bottom),
+        );
+
+    let Some(mystic) = character.get_psionics() else {
+        frame.render_widget(Paragraph::new("No psionic powers.").block(block), area);
+
+        return;
+    };
+
+    let layout = Layout::new(
+        Direction::Vertical,
+        [Constraint::Max(3), Constraint::Min(0)].as_ref(),
+    )
+    .split(area);
+    let body_layout = Layout::new(
+        Direction::Horizontal,
+        [Constraint::Ratio(1, 3), Constraint::Ratio(2, 3)].as_ref(),
+    )
+    .split(layout[1]);
+
+    let acts = get_acts(character);
+    let cost = state
+        .list_state
+        .selected()
+        .and_then(|i| acts.get(i))
+        .map(|(_, act)| get_act_cost(character, act, state.charge));
+
+    frame.render_widget(
+        Paragraph::new(get_psionics_status(character, mystic, state.charge, cost)).block(block),
+        layout[0],
+    );
+    let items: Vec<ListItem> = acts
+        .iter()
+        .map(|(discipline, act)| {
+            ListItem::new(Line::from(vec![
+                Span::from(act.get_name().to_owned()),
+                Span::from(format!(" ({})", discipline.get_name())).dim(),
+            ]))
+        })
+        .collect();
+    let talents = mystic
+        .get_talents()
+        .iter()
+        .map(Talent::get_name)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(
+                Block::new()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .title(Title::from("Acts").alignment(Alignment::Center))
+                    .title(
+                        Title::from(format!("Talents: {talents}"))
+                            .alignment(Alignment::Left)
+                            .position(Position::Bottom),
+                    ),
+            )
+            .highlight_style(Style::default().reversed()),
+        body_layout[0],
+        &mut state.list_state,
+    );
+
+    if let Some((discipline, _)) = state
+        .list_state
+        .selected()
+        .and_then(|i| acts.get(i))
+        .or_else(|| acts.first())
+    {
+        frame.render_widget(
+            DisciplineWidget::from((*discipline).clone()),
+            body_layout[1],
+        );
+    }
 }
 
 fn render_description_page(frame: &mut Frame, character: &Character, area: Rect) {
@@ -535,26 +865,10 @@ fn render_description_page(frame: &mut Frame, character: &Character, area: Rect)
 
     frame.render_widget(block, area);
 
-    let background: BackgroundWidget = <background::Builder as TryInto<background::Background>>::try_into(background::Builder::new()
-        .name("Urban Bounty Hunter")
-        .description("You did bounty hunter stuff in an urban setting.")
-        .feature(
-            background::Feature::new(
-                "Ear to the Ground",
-                "You are in frequent contact with people in the segment of society that your chosen quarries move through. These people might be associated with the criminal underworld, the rough-and-tumble folk of the streets, or members of high society. This connection comes in the form of a contact in any city you visit, a person who provides information about the people and places of the local area."
-            )
-        )
-        .proficiencies(
-            background::Proficiencies::two_skills_two_tools(
-                skills::Identifier::Deception,
-                skills::Identifier::Persuasion,
-                "Dice Set",
-                "Card Deck"
-            )
-        ))
-        .unwrap()
-        .into();
-    frame.render_widget(background, layout[0]);
+    if let Some(background) = character.get_background() {
+        let background: BackgroundWidget = background.clone().into();
+        frame.render_widget(background, layout[0]);
+    }
 
     let characteristics_block = Block::new()
         .title(Title::from("Characteristics"))
@@ -579,7 +893,8 @@ fn render_description_page(frame: &mut Frame, character: &Character, area: Rect)
     frame.render_widget(appearance_block, layout[2]);
 }
 
-enum PageLink {
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageLink {
     AbilitiesSavesSenses,
     Skills,
     Actions,
@@ -632,13 +947,19 @@ pub fn render(app: &mut App, frame: &mut Frame) {
             [
                 Constraint::Ratio(1, 5),
                 Constraint::Min(0),
-                Constraint::Ratio(1, 10),
+                Constraint::Ratio(1, 5),
             ]
             .as_ref(),
         )
         .split(frame.size());
 
     render_header(frame, character, document_layout[0]);
+    render_roll_log(
+        frame,
+        &app.roll_log,
+        &app.roll_log_state,
+        document_layout[2],
+    );
 
     match app.nav_menu_state.selected.into() {
         PageLink::AbilitiesSavesSenses => {
@@ -648,6 +969,7 @@ pub fn render(app: &mut App, frame: &mut Frame) {
                     Constraint::Max(4),
                     Constraint::Ratio(1, 3),
                     Constraint::Ratio(1, 3),
+                    Constraint::Min(0),
                 ]
                 .as_ref(),
             )
@@ -658,33 +980,26 @@ pub fn render(app: &mut App, frame: &mut Frame) {
 
             render_saving_throws_block(frame, character, body_layout[1]);
             render_senses_block(frame, character, body_layout[2]);
+            render_armor_class_breakdown_block(frame, character, body_layout[3]);
         }
         PageLink::Actions => {
-            let act = Act::new("Phantom Foe", "As an action, choose one creature you can see within 60 feet of you. The target must make an Intelligence saving throw. On a failed save, it perceives a horrid creature adjacent to it until your concentration ends. During this time, the target can’t take reactions, and it takes 1d8 psychic damage at the start of each of its turns. The target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. You can increase the damage by 1d8 for each additional psi point spent on the ability.",3..4,Some(Duration::Minutes(1)));
-
-            let d_w = DisciplineWidget::from(Discipline::try_from(
-                discipline::Builder::new()
-                    .name("Phychic Phantoms")
-                    .order("Awakened")
-                    .description( "Your power reaches into a creature’s mind and causes it false perceptions.",
-                    ).focus("While focused on this discipline, you have advantage on Charisma (Deception) checks.")
-                    .add_act(&act),
-            ).unwrap());
-
-            frame.render_widget(d_w, document_layout[1]);
+            render_actions_page(frame, character, &mut app.actions_state, document_layout[1]);
         }
         PageLink::Skills => {
             let body_layout = Layout::new(Direction::Vertical, [Constraint::Min(0)].as_ref())
                 .split(document_layout[1]);
 
-            render_skills_table(frame, character, body_layout[0]);
+            render_skills_table(frame, character, &mut app.skills_state, body_layout[0]);
         }
         PageLink::Inventory => {
             let body_layout = Layout::default()
                 .constraints([Constraint::Min(0)].as_ref())
                 .split(document_layout[1]);
 
-            render_inventory_table(frame, character, body_layout[0]);
+            render_inventory_table(frame, character, &mut app.inventory_state, body_layout[0]);
+        }
+        PageLink::FeaturesTraits => {
+            render_features_and_traits_page(frame, character, document_layout[1]);
         }
         PageLink::ProficienciesLanguages => {
             let body_layout = Layout::default()
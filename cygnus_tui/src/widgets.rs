@@ -258,9 +258,14 @@ impl Widget for DisciplineWidget {
             .render(l[2], buf);
 
         let acts = self.0.get_acts();
+        let act_layout = Layout::new(
+            Direction::Vertical,
+            vec![Constraint::Ratio(1, acts.len().max(1) as u32); acts.len()],
+        )
+        .split(l[3]);
 
-        for act in acts {
-            Widget::render(ActWidget::from(act.clone()), l[3], buf);
+        for (act, area) in acts.iter().zip(act_layout.iter()) {
+            Widget::render(ActWidget::from(act.clone()), *area, buf);
         }
 
         block.render(area, buf);
@@ -302,7 +307,30 @@ impl Widget for ActWidget {
         );
         duration.render(h[0], buf);
 
-        let cost = Paragraph::new(self.0.get_cost().start.to_string());
+        let damage = self.0.get_damage().map(|(roll, damage_type)| {
+            let save = self
+                .0
+                .get_save()
+                .map_or(String::new(), |save| format!(", {save} save"));
+
+            format!("{roll} {damage_type}{save}")
+        });
+        let scaling = self.0.get_scaling().map(|scaling| scaling.to_string());
+        Paragraph::new(
+            [damage, scaling]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+        .render(h[1], buf);
+
+        let (lowest, highest) = (self.0.get_cost().start(), self.0.get_cost().end());
+        let cost = Paragraph::new(if lowest == highest {
+            format!("{lowest} psi")
+        } else {
+            format!("{lowest}-{highest} psi")
+        });
         cost.render(h[2], buf);
 
         Paragraph::new("Cast")